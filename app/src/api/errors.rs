@@ -0,0 +1,150 @@
+use std::fmt::Display;
+
+use poem_openapi::{payload::Json, Enum, Object};
+use serde::{Deserialize, Serialize};
+
+/// Machine readable reason of a failed request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[serde(rename_all = "snake_case")]
+#[oai(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// The request body or parameters are invalid
+    BadRequest,
+    /// The referenced playlist does not exist
+    PlaylistNotFound,
+    /// The referenced tab does not exist
+    TabNotFound,
+    /// The tab exists but is not part of the playlist
+    TabNotInPlaylist,
+    /// The playlist has no entry with this id
+    EntryNotFound,
+    /// A resource with the same id already exists
+    AlreadyExists,
+    /// Chromium is not running (yet), so the command cannot be executed
+    BrowserNotReady,
+    /// No takeover is currently running
+    TakeoverNotActive,
+    /// A takeover is running, which the command would conflict with
+    TakeoverActive,
+    /// No playlist is active, so there is nothing to rotate
+    NoActivePlaylist,
+    /// The referenced overlay does not exist (anymore)
+    OverlayNotFound,
+    /// The referenced asset does not exist
+    AssetNotFound,
+    /// Storing the upload would exceed the asset quota
+    QuotaExceeded,
+    /// No action is configured for the key
+    KeyNotMapped,
+    /// The browser controller failed to execute the command
+    CommandFailed,
+    /// Unexpected server side error
+    Internal,
+}
+
+/// Error body returned by every failing endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct ApiError {
+    /// Machine readable error code
+    pub code: ErrorCode,
+    /// Human readable description
+    pub message: String,
+    /// Additional context, e.g. the offending ids
+    pub details: Option<serde_json::Value>,
+}
+
+impl ApiError {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Json<Self> {
+        Json(Self {
+            code,
+            message: message.into(),
+            details: None,
+        })
+    }
+
+    pub fn with_details(
+        code: ErrorCode,
+        message: impl Into<String>,
+        details: serde_json::Value,
+    ) -> Json<Self> {
+        Json(Self {
+            code,
+            message: message.into(),
+            details: Some(details),
+        })
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Json<Self> {
+        Self::new(ErrorCode::BadRequest, message)
+    }
+
+    pub fn playlist_not_found(playlist_id: &str) -> Json<Self> {
+        Self::with_details(
+            ErrorCode::PlaylistNotFound,
+            format!("Playlist '{}' not found", playlist_id),
+            serde_json::json!({ "playlist_id": playlist_id }),
+        )
+    }
+
+    pub fn tab_not_found(tab_id: &str) -> Json<Self> {
+        Self::with_details(
+            ErrorCode::TabNotFound,
+            format!("Tab '{}' not found", tab_id),
+            serde_json::json!({ "tab_id": tab_id }),
+        )
+    }
+
+    pub fn tab_not_in_playlist(playlist_id: &str, tab_id: &str) -> Json<Self> {
+        Self::with_details(
+            ErrorCode::TabNotInPlaylist,
+            format!("Tab '{}' is not part of playlist '{}'", tab_id, playlist_id),
+            serde_json::json!({ "playlist_id": playlist_id, "tab_id": tab_id }),
+        )
+    }
+
+    pub fn entry_not_found(playlist_id: &str, entry_id: &str) -> Json<Self> {
+        Self::with_details(
+            ErrorCode::EntryNotFound,
+            format!(
+                "Entry '{}' is not part of playlist '{}'",
+                entry_id, playlist_id
+            ),
+            serde_json::json!({ "playlist_id": playlist_id, "entry_id": entry_id }),
+        )
+    }
+
+    pub fn asset_not_found(asset_id: &str) -> Json<Self> {
+        Self::with_details(
+            ErrorCode::AssetNotFound,
+            format!("Asset '{}' not found", asset_id),
+            serde_json::json!({ "asset_id": asset_id }),
+        )
+    }
+
+    pub fn browser_not_ready() -> Json<Self> {
+        Self::new(ErrorCode::BrowserNotReady, "Chromium is not running")
+    }
+
+    pub fn command_failed(message: impl Into<String>) -> Json<Self> {
+        Self::new(ErrorCode::CommandFailed, message)
+    }
+
+    /// Log an unexpected error and wrap it for the response
+    pub fn internal(error: impl Display) -> Json<Self> {
+        tracing::error!("API: internal error: {}", error);
+        Self::new(ErrorCode::Internal, error.to_string())
+    }
+}
+
+/// Whether a repository error was caused by a UNIQUE/PRIMARY KEY constraint
+pub fn is_unique_violation(error: &anyhow::Error) -> bool {
+    error
+        .downcast_ref::<sqlx::Error>()
+        .and_then(|e| e.as_database_error())
+        .is_some_and(|e| e.is_unique_violation())
+}
+
+/// Turn a request that poem-openapi failed to parse into an error body
+pub fn parse_error(error: poem::Error) -> Json<ApiError> {
+    ApiError::bad_request(error.to_string())
+}
@@ -1,13 +1,44 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
+
+use chrono::DateTime;
 
 use crate::{
-    db::repositories::{PlaylistRepository, PlaylistTabRepository, TabRepository},
+    assets::{self, QuotaExceeded},
+    chrome::{ChromeMessage, ChromeResponse},
+    config::DeviceConfig,
+    db::{
+        models::{
+            AddTabToPlaylistRequest, CreatePlaylistRequest, CreateTabRequest, DeliveryStatus,
+            PlayReportFilter, PlayReportGrouping, ReorderTabsRequest, SetTabWeightRequest, Tab,
+            TabContentType, TabWithOrder, ToggleTabEnabledRequest, UpdatePlaylistRequest,
+            UpdateTabRequest,
+        },
+        repositories::{
+            AssetRepository, PlayLogRepository, PlaylistRepository, PlaylistTabRepository,
+            TabRepository, WebhookDeliveryRepository,
+        },
+    },
+    events::{DomainEvent, PlaylistChange, PlaylistChangedEvent, TabChange, TabChangedEvent},
+    play_log,
     state::AppState,
+    template,
+};
+use futures::{stream::BoxStream, StreamExt};
+use poem::web::sse::Event;
+use poem_openapi::{
+    param::{Path, Query},
+    payload::{EventStream, Json, PlainText},
+    types::ToJSON,
+    OpenApi, OpenApiService,
 };
-use poem_openapi::{payload::Json, OpenApi, OpenApiService};
+use tokio::sync::broadcast::error::RecvError;
 
+pub mod errors;
 pub mod models;
+pub mod responses;
+use errors::{is_unique_violation, ApiError, ErrorCode};
 use models::*;
+use responses::*;
 
 #[derive(Clone)]
 pub struct ManagementApi {
@@ -24,81 +55,233 @@ impl ManagementApi {
 impl ManagementApi {
     /// Get all playlists configured on the device.
     #[oai(path = "/playlists", method = "get")]
-    async fn get_playlists(&self) -> Json<Vec<PlaylistInfo>> {
-        let playlists = self.get_playlists_impl().await.unwrap_or_default();
-        Json(playlists)
+    async fn get_playlists(&self) -> ListPlaylistsResponse {
+        match self.get_playlists_impl().await {
+            Ok(playlists) => ListPlaylistsResponse::Ok(Json(playlists)),
+            Err(e) => ListPlaylistsResponse::InternalError(ApiError::internal(e)),
+        }
     }
 
     /// Get all tabs from a given playlist.
     #[oai(path = "/playlists/:playlist_id/tabs", method = "get")]
-    async fn get_playlist_tabs(
-        &self,
-        playlist_id: poem_openapi::param::Path<String>,
-    ) -> Json<Vec<TabInfo>> {
-        let tabs = self
-            .get_playlist_tabs_impl(&playlist_id.0)
+    async fn get_playlist_tabs(&self, playlist_id: Path<String>) -> PlaylistTabsResponse {
+        match self.get_playlist_tabs_impl(&playlist_id.0).await {
+            Ok(Some(tabs)) => PlaylistTabsResponse::Ok(Json(tabs)),
+            Ok(None) => PlaylistTabsResponse::NotFound(ApiError::playlist_not_found(&playlist_id)),
+            Err(e) => PlaylistTabsResponse::InternalError(ApiError::internal(e)),
+        }
+    }
+
+    /// Get a single playlist with its tabs.
+    #[oai(path = "/playlists/:playlist_id", method = "get")]
+    async fn get_playlist(&self, playlist_id: Path<String>) -> GetPlaylistResponse {
+        let playlist = match self
+            .state
+            .playlist_repository
+            .get_with_tabs(&playlist_id.0)
             .await
-            .unwrap_or_default()
-            .unwrap_or_default();
-        Json(tabs)
+        {
+            Ok(Some(playlist)) => playlist,
+            Ok(None) => {
+                return GetPlaylistResponse::NotFound(ApiError::playlist_not_found(&playlist_id))
+            }
+            Err(e) => return GetPlaylistResponse::InternalError(ApiError::internal(e)),
+        };
+
+        let mut tabs = Vec::new();
+        for tab in playlist.tabs {
+            tabs.push(self.tab_info(tab).await);
+        }
+
+        GetPlaylistResponse::Ok(Json(PlaylistDetails {
+            id: playlist.id,
+            name: playlist.name,
+            interval_seconds: playlist.interval_seconds,
+            is_active: playlist.is_active,
+            rotation_mode: playlist.rotation_mode,
+            tabs,
+        }))
+    }
+
+    /// Get all tabs, including tabs that are not part of any playlist.
+    #[oai(path = "/tabs", method = "get")]
+    async fn get_tabs(&self) -> ListTabsResponse {
+        let tabs = match self.state.tab_repository.get_all().await {
+            Ok(tabs) => tabs,
+            Err(e) => return ListTabsResponse::InternalError(ApiError::internal(e)),
+        };
+
+        let mut details = Vec::new();
+        for tab in tabs {
+            match self.tab_details(tab).await {
+                Ok(tab) => details.push(tab),
+                Err(e) => return ListTabsResponse::InternalError(ApiError::internal(e)),
+            }
+        }
+        ListTabsResponse::Ok(Json(details))
+    }
+
+    /// Get a single tab with its playlist membership and runtime state.
+    #[oai(path = "/tabs/:tab_id", method = "get")]
+    async fn get_tab(&self, tab_id: Path<String>) -> GetTabResponse {
+        let tab = match self.state.tab_repository.get_by_id(&tab_id.0).await {
+            Ok(Some(tab)) => tab,
+            Ok(None) => return GetTabResponse::NotFound(ApiError::tab_not_found(&tab_id)),
+            Err(e) => return GetTabResponse::InternalError(ApiError::internal(e)),
+        };
+
+        match self.tab_details(tab).await {
+            Ok(details) => GetTabResponse::Ok(Json(details)),
+            Err(e) => GetTabResponse::InternalError(ApiError::internal(e)),
+        }
     }
 
     /// Retrieve basic device status information.
     #[oai(path = "/status", method = "get")]
-    async fn get_status(&self) -> Json<DeviceStatus> {
-        let status = self
-            .get_status_impl()
+    async fn get_status(&self) -> StatusResponse {
+        match self.get_status_impl().await {
+            Ok(status) => StatusResponse::Ok(Json(status)),
+            Err(e) => StatusResponse::InternalError(ApiError::internal(e)),
+        }
+    }
+
+    /// Subscribe to live controller events as Server-Sent Events.
+    ///
+    /// Each message is a JSON `DomainEvent`; the SSE event name matches its `type`.
+    #[oai(path = "/events", method = "get")]
+    async fn events(&self) -> EventStream<BoxStream<'static, DomainEvent>> {
+        let mut receiver = self.state.events.subscribe();
+        let stream = async_stream::stream! {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => yield event,
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::warn!("API: event stream lagged, skipped {} events", skipped);
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        };
+        EventStream::new(stream.boxed())
+            .keep_alive(Duration::from_secs(15))
+            .to_event(|event| Event::message(event.to_json_string()).event_type(event.event_type()))
+    }
+
+    /// List the configured webhook targets.
+    #[oai(path = "/webhooks", method = "get")]
+    async fn get_webhooks(&self) -> Json<Vec<WebhookInfo>> {
+        Json(
+            self.state
+                .config
+                .webhooks
+                .iter()
+                .map(|w| WebhookInfo {
+                    url: w.url.clone(),
+                    events: w.events.clone(),
+                    signed: w.secret.is_some(),
+                })
+                .collect(),
+        )
+    }
+
+    /// List recent webhook deliveries, newest first.
+    #[oai(path = "/webhooks/deliveries", method = "get")]
+    async fn get_webhook_deliveries(
+        &self,
+        /// Only return deliveries with this status
+        status: Query<Option<DeliveryStatus>>,
+        /// Maximum number of deliveries to return (default 50)
+        limit: Query<Option<i64>>,
+    ) -> WebhookDeliveriesResponse {
+        match self
+            .state
+            .webhook_delivery_repository
+            .list(status.0, limit.0.unwrap_or(50).clamp(1, 1000))
             .await
-            .unwrap_or_else(|_| DeviceStatus {
-                device_id: "unknown".into(),
-                device_name: "unknown".into(),
-                current_playlist: None,
-                current_tab: None,
-                uptime_seconds: 0,
-                current_tab_opened_at: None,
-            });
-        Json(status)
+        {
+            Ok(deliveries) => WebhookDeliveriesResponse::Ok(Json(
+                deliveries.into_iter().map(Into::into).collect(),
+            )),
+            Err(e) => WebhookDeliveriesResponse::InternalError(ApiError::internal(e)),
+        }
+    }
+
+    /// Number of plays and display time per tab, playlist or day.
+    /// Plays are included if they started within `from` and `to`.
+    #[oai(path = "/reports/plays", method = "get")]
+    async fn get_play_report(
+        &self,
+        /// Dimension to aggregate by
+        group_by: Query<PlayReportGrouping>,
+        /// Start of the period (seconds since epoch, inclusive)
+        from: Query<Option<i64>>,
+        /// End of the period (seconds since epoch, exclusive)
+        to: Query<Option<i64>>,
+        /// Only include plays of this tab
+        tab_id: Query<Option<String>>,
+        /// Only include plays of this playlist
+        playlist_id: Query<Option<String>>,
+    ) -> PlayReportResponse {
+        let filter = match report_period(from.0, to.0) {
+            Ok(period) => PlayReportFilter {
+                tab_id: tab_id.0,
+                playlist_id: playlist_id.0,
+                ..period
+            },
+            Err(message) => return PlayReportResponse::BadRequest(ApiError::bad_request(message)),
+        };
+        match self
+            .state
+            .play_log_repository
+            .report(group_by.0, &filter)
+            .await
+        {
+            Ok(rows) => PlayReportResponse::Ok(Json(rows.into_iter().map(Into::into).collect())),
+            Err(e) => PlayReportResponse::InternalError(ApiError::internal(e)),
+        }
+    }
+
+    /// Export every play that started within `from` and `to` as CSV
+    #[oai(path = "/reports/plays.csv", method = "get")]
+    async fn export_plays(
+        &self,
+        /// Start of the period (seconds since epoch, inclusive)
+        from: Query<Option<i64>>,
+        /// End of the period (seconds since epoch, exclusive)
+        to: Query<Option<i64>>,
+    ) -> PlayExportResponse {
+        let period = match report_period(from.0, to.0) {
+            Ok(period) => period,
+            Err(message) => return PlayExportResponse::BadRequest(ApiError::bad_request(message)),
+        };
+        match self
+            .state
+            .play_log_repository
+            .list(period.from, period.to)
+            .await
+        {
+            Ok(records) => PlayExportResponse::Ok(
+                PlainText(play_log::to_csv(&records)),
+                "attachment; filename=\"plays.csv\"".to_string(),
+            ),
+            Err(e) => PlayExportResponse::InternalError(ApiError::internal(e)),
+        }
     }
 
     /// Activate a playlist
     #[oai(path = "/playlists/:playlist_id/activate", method = "post")]
-    async fn activate_playlist(
-        &self,
-        playlist_id: poem_openapi::param::Path<String>,
-    ) -> poem_openapi::payload::PlainText<String> {
-        let pid = playlist_id.0.clone();
+    async fn activate_playlist(&self, playlist_id: Path<String>) -> CommandResponse {
+        let pid = playlist_id.0;
         tracing::info!("API: Activating playlist {}", pid);
 
-        // Send message to Chrome controller and wait for response
-        match crate::chrome::send_chrome_message_with_response(
-            &self.state.chrome,
-            crate::chrome::ChromeMessage::ActivatePlaylist {
-                playlist_id: pid.clone(),
-            },
-        )
-        .await
-        {
-            Ok(crate::chrome::ChromeResponse::Success) => {
-                tracing::info!("API: Successfully activated playlist {}", pid);
-                poem_openapi::payload::PlainText("ok".into())
-            }
-            Ok(crate::chrome::ChromeResponse::Error { message }) => {
-                tracing::error!("API: Error activating playlist {}: {}", pid, message);
-                poem_openapi::payload::PlainText(format!("Error activating playlist: {}", message))
-            }
-            Ok(response) => {
-                tracing::warn!(
-                    "API: Unexpected response activating playlist {}: {:?}",
-                    pid,
-                    response
-                );
-                poem_openapi::payload::PlainText(format!("Unexpected response: {:?}", response))
-            }
-            Err(e) => {
-                tracing::error!("API: Error activating playlist {}: {}", pid, e);
-                poem_openapi::payload::PlainText(format!("Error activating playlist: {}", e))
-            }
+        match self.state.playlist_repository.get_by_id(&pid).await {
+            Ok(Some(_)) => {}
+            Ok(None) => return CommandResponse::NotFound(ApiError::playlist_not_found(&pid)),
+            Err(e) => return CommandResponse::InternalError(ApiError::internal(e)),
         }
+
+        self.send_command(ChromeMessage::ActivatePlaylist { playlist_id: pid })
+            .await
     }
 
     /// Activate a tab immediately
@@ -108,13 +291,22 @@ impl ManagementApi {
     )]
     async fn activate_tab(
         &self,
-        playlist_id: poem_openapi::param::Path<String>,
-        tab_id: poem_openapi::param::Path<String>,
-    ) -> poem_openapi::payload::PlainText<String> {
-        let pid = playlist_id.0.clone();
-        let tid = tab_id.0.clone();
+        playlist_id: Path<String>,
+        tab_id: Path<String>,
+    ) -> CommandResponse {
+        let pid = playlist_id.0;
+        let tid = tab_id.0;
         tracing::info!("API: Activating tab {} in playlist {}", tid, pid);
 
+        match self.find_playlist_tab(&pid, &tid).await {
+            Ok(Ok(())) => {}
+            Ok(Err(error)) => return CommandResponse::NotFound(error),
+            Err(e) => return CommandResponse::InternalError(ApiError::internal(e)),
+        }
+        if !self.state.chrome.is_ready().await {
+            return CommandResponse::BrowserNotReady(ApiError::browser_not_ready());
+        }
+
         // Update manual activation timestamp
         if let Err(e) = self
             .state
@@ -129,48 +321,44 @@ impl ManagementApi {
             );
         }
 
-        // Send message to Chrome controller to activate tab
-        if let Err(e) = crate::chrome::send_chrome_message(
-            &self.state.chrome,
-            crate::chrome::ChromeMessage::ActivateTab {
-                tab_id: tid.clone(),
-                playlist_id: pid.clone(),
-            },
-        )
+        self.send_command(ChromeMessage::ActivateTab {
+            tab_id: tid,
+            playlist_id: pid,
+        })
         .await
-        {
-            tracing::error!("API: Error activating tab {}: {}", tid, e);
-            return poem_openapi::payload::PlainText(format!("Error activating tab: {}", e));
-        }
-
-        tracing::info!("API: Successfully sent activate tab message for {}", tid);
-        poem_openapi::payload::PlainText("ok".into())
     }
 
     /// Create a new playlist
     #[oai(path = "/playlists", method = "post")]
     async fn create_playlist(
         &self,
-        request: Json<crate::db::models::CreatePlaylistRequest>,
-    ) -> Json<PlaylistInfo> {
+        request: Json<CreatePlaylistRequest>,
+    ) -> CreatePlaylistResponse {
+        if let Err(message) = validate_playlist(
+            Some(&request.id),
+            Some(&request.name),
+            Some(request.interval_seconds),
+        ) {
+            return CreatePlaylistResponse::BadRequest(ApiError::bad_request(message));
+        }
+
         match self.state.playlist_repository.create(request.0).await {
-            Ok(playlist) => Json(PlaylistInfo {
-                id: playlist.id,
-                name: playlist.name,
-                tab_count: 0,
-                interval_seconds: playlist.interval_seconds,
-                is_active: playlist.is_active,
-            }),
-            Err(e) => {
-                // Return error playlist info - in real implementation should return proper error
-                Json(PlaylistInfo {
-                    id: "error".to_string(),
-                    name: format!("Error: {}", e),
+            Ok(playlist) => {
+                self.publish_playlist_change(&playlist.id, PlaylistChange::Created);
+                CreatePlaylistResponse::Ok(Json(PlaylistInfo {
+                    id: playlist.id,
+                    name: playlist.name,
                     tab_count: 0,
-                    interval_seconds: 30,
-                    is_active: false,
-                })
+                    interval_seconds: playlist.interval_seconds,
+                    is_active: playlist.is_active,
+                    rotation_mode: playlist.rotation_mode,
+                }))
             }
+            Err(e) if is_unique_violation(&e) => CreatePlaylistResponse::Conflict(ApiError::new(
+                ErrorCode::AlreadyExists,
+                "A playlist with this id already exists",
+            )),
+            Err(e) => CreatePlaylistResponse::InternalError(ApiError::internal(e)),
         }
     }
 
@@ -178,88 +366,92 @@ impl ManagementApi {
     #[oai(path = "/playlists/:playlist_id", method = "put")]
     async fn update_playlist(
         &self,
-        playlist_id: poem_openapi::param::Path<String>,
-        request: Json<crate::db::models::UpdatePlaylistRequest>,
-    ) -> Json<PlaylistInfo> {
-        match self
+        playlist_id: Path<String>,
+        request: Json<UpdatePlaylistRequest>,
+    ) -> UpdatePlaylistResponse {
+        if let Err(message) =
+            validate_playlist(None, request.name.as_deref(), request.interval_seconds)
+        {
+            return UpdatePlaylistResponse::BadRequest(ApiError::bad_request(message));
+        }
+
+        let playlist = match self
             .state
             .playlist_repository
             .update(&playlist_id.0, request.0)
             .await
         {
-            Ok(Some(playlist)) => {
-                // Get tab count
-                let tabs = self
-                    .state
-                    .playlist_tab_repository
-                    .get_playlist_tabs(&playlist.id)
-                    .await
-                    .unwrap_or_default();
-                Json(PlaylistInfo {
-                    id: playlist.id,
-                    name: playlist.name,
-                    tab_count: tabs.len(),
-                    interval_seconds: playlist.interval_seconds,
-                    is_active: playlist.is_active,
-                })
+            Ok(Some(playlist)) => playlist,
+            Ok(None) => {
+                return UpdatePlaylistResponse::NotFound(ApiError::playlist_not_found(&playlist_id))
             }
-            Ok(None) => Json(PlaylistInfo {
-                id: "not_found".to_string(),
-                name: "Playlist not found".to_string(),
-                tab_count: 0,
-                interval_seconds: 30,
-                is_active: false,
-            }),
-            Err(e) => Json(PlaylistInfo {
-                id: "error".to_string(),
-                name: format!("Error: {}", e),
-                tab_count: 0,
-                interval_seconds: 30,
-                is_active: false,
-            }),
+            Err(e) => return UpdatePlaylistResponse::InternalError(ApiError::internal(e)),
+        };
+        self.playlist_changed(&playlist.id, PlaylistChange::Updated)
+            .await;
+
+        match self
+            .state
+            .playlist_tab_repository
+            .get_playlist_tabs(&playlist.id)
+            .await
+        {
+            Ok(tabs) => UpdatePlaylistResponse::Ok(Json(PlaylistInfo {
+                id: playlist.id,
+                name: playlist.name,
+                tab_count: tabs.len(),
+                interval_seconds: playlist.interval_seconds,
+                is_active: playlist.is_active,
+                rotation_mode: playlist.rotation_mode,
+            })),
+            Err(e) => UpdatePlaylistResponse::InternalError(ApiError::internal(e)),
         }
     }
 
     /// Delete a playlist
     #[oai(path = "/playlists/:playlist_id", method = "delete")]
-    async fn delete_playlist(
-        &self,
-        playlist_id: poem_openapi::param::Path<String>,
-    ) -> poem_openapi::payload::PlainText<String> {
+    async fn delete_playlist(&self, playlist_id: Path<String>) -> DeleteResponse {
         match self.state.playlist_repository.delete(&playlist_id.0).await {
             Ok(true) => {
-                poem_openapi::payload::PlainText("Playlist deleted successfully".to_string())
+                self.playlist_changed(&playlist_id.0, PlaylistChange::Deleted)
+                    .await;
+                DeleteResponse::Ok(PlainText("Playlist deleted successfully".to_string()))
             }
-            Ok(false) => poem_openapi::payload::PlainText("Playlist not found".to_string()),
-            Err(e) => poem_openapi::payload::PlainText(format!("Error: {}", e)),
+            Ok(false) => DeleteResponse::NotFound(ApiError::playlist_not_found(&playlist_id)),
+            Err(e) => DeleteResponse::InternalError(ApiError::internal(e)),
         }
     }
 
     /// Create a new tab
     #[oai(path = "/tabs", method = "post")]
-    async fn create_tab(
-        &self,
-        request: Json<crate::db::models::CreateTabRequest>,
-    ) -> Json<TabInfo> {
+    async fn create_tab(&self, request: Json<CreateTabRequest>) -> CreateTabResponse {
+        if let Err(message) = validate_tab(
+            Some(&request.id),
+            Some(&request.name),
+            request.target_width,
+            request.target_height,
+        )
+        .and_then(|_| {
+            validate_tab_content(
+                request.content_type.unwrap_or_default(),
+                &request.url,
+                request.content.as_deref(),
+                &self.state.config.device,
+            )
+        }) {
+            return CreateTabResponse::BadRequest(ApiError::bad_request(message));
+        }
+
         match self.state.tab_repository.create(request.0).await {
-            Ok(tab) => Json(TabInfo {
-                id: tab.id,
-                name: tab.name,
-                url: tab.url,
-                order_index: 0,
-                persist: tab.persist,
-                viewport_width: tab.viewport_width,
-                viewport_height: tab.viewport_height,
-            }),
-            Err(e) => Json(TabInfo {
-                id: "error".to_string(),
-                name: format!("Error: {}", e),
-                url: "".to_string(),
-                order_index: 0,
-                persist: false,
-                viewport_width: None,
-                viewport_height: None,
-            }),
+            Ok(tab) => {
+                self.publish_tab_change(&tab.id, TabChange::Created);
+                CreateTabResponse::Ok(Json(self.standalone_tab_info(tab).await))
+            }
+            Err(e) if is_unique_violation(&e) => CreateTabResponse::Conflict(ApiError::new(
+                ErrorCode::AlreadyExists,
+                "A tab with this id already exists",
+            )),
+            Err(e) => CreateTabResponse::InternalError(ApiError::internal(e)),
         }
     }
 
@@ -267,189 +459,595 @@ impl ManagementApi {
     #[oai(path = "/tabs/:tab_id", method = "put")]
     async fn update_tab(
         &self,
-        tab_id: poem_openapi::param::Path<String>,
-        request: Json<crate::db::models::UpdateTabRequest>,
-    ) -> Json<TabInfo> {
+        tab_id: Path<String>,
+        request: Json<UpdateTabRequest>,
+    ) -> UpdateTabResponse {
+        let existing = match self.state.tab_repository.get_by_id(&tab_id.0).await {
+            Ok(Some(tab)) => tab,
+            Ok(None) => return UpdateTabResponse::NotFound(ApiError::tab_not_found(&tab_id)),
+            Err(e) => return UpdateTabResponse::InternalError(ApiError::internal(e)),
+        };
+        // Content is validated against the tab as it will be after the update
+        if let Err(message) = validate_tab(
+            None,
+            request.name.as_deref(),
+            request.target_width.value().copied(),
+            request.target_height.value().copied(),
+        )
+        .and_then(|_| {
+            validate_tab_content(
+                request.content_type.unwrap_or(existing.content_type),
+                request.url.as_deref().unwrap_or(&existing.url),
+                request.content.as_deref().or(existing.content.as_deref()),
+                &self.state.config.device,
+            )
+        }) {
+            return UpdateTabResponse::BadRequest(ApiError::bad_request(message));
+        }
+
+        let content_changed =
+            request.url.is_some() || request.content.is_some() || request.content_type.is_some();
+        let viewport_changed = request.changes_viewport();
         match self.state.tab_repository.update(&tab_id.0, request.0).await {
-            Ok(Some(tab)) => Json(TabInfo {
-                id: tab.id,
-                name: tab.name,
-                url: tab.url,
-                order_index: 0,
-                persist: tab.persist,
-                viewport_width: tab.viewport_width,
-                viewport_height: tab.viewport_height,
-            }),
-            Ok(None) => Json(TabInfo {
-                id: "not_found".to_string(),
-                name: "Tab not found".to_string(),
-                url: "".to_string(),
-                order_index: 0,
-                persist: false,
-                viewport_width: None,
-                viewport_height: None,
-            }),
-            Err(e) => Json(TabInfo {
-                id: "error".to_string(),
-                name: format!("Error: {}", e),
-                url: "".to_string(),
-                order_index: 0,
-                persist: false,
-                viewport_width: None,
-                viewport_height: None,
-            }),
+            Ok(Some(tab)) => {
+                self.publish_tab_change(&tab.id, TabChange::Updated);
+                if content_changed || viewport_changed {
+                    self.notify_controller(ChromeMessage::TabUpdated {
+                        tab_id: tab.id.clone(),
+                        viewport_changed,
+                    })
+                    .await;
+                }
+                UpdateTabResponse::Ok(Json(self.standalone_tab_info(tab).await))
+            }
+            Ok(None) => UpdateTabResponse::NotFound(ApiError::tab_not_found(&tab_id)),
+            Err(e) => UpdateTabResponse::InternalError(ApiError::internal(e)),
         }
     }
 
     /// Delete a tab
     #[oai(path = "/tabs/:tab_id", method = "delete")]
-    async fn delete_tab(
-        &self,
-        tab_id: poem_openapi::param::Path<String>,
-    ) -> poem_openapi::payload::PlainText<String> {
+    async fn delete_tab(&self, tab_id: Path<String>) -> DeleteResponse {
         match self.state.tab_repository.delete(&tab_id.0).await {
-            Ok(true) => poem_openapi::payload::PlainText("Tab deleted successfully".to_string()),
-            Ok(false) => poem_openapi::payload::PlainText("Tab not found".to_string()),
-            Err(e) => poem_openapi::payload::PlainText(format!("Error: {}", e)),
+            Ok(true) => {
+                self.publish_tab_change(&tab_id.0, TabChange::Deleted);
+                self.notify_controller(ChromeMessage::TabDeleted { tab_id: tab_id.0 })
+                    .await;
+                DeleteResponse::Ok(PlainText("Tab deleted successfully".to_string()))
+            }
+            Ok(false) => DeleteResponse::NotFound(ApiError::tab_not_found(&tab_id)),
+            Err(e) => DeleteResponse::InternalError(ApiError::internal(e)),
         }
     }
 
-    /// Add a tab to a playlist
+    /// Add a tab to a playlist. Every call adds a new entry, so a tab can
+    /// be listed several times, e.g. with different durations.
     #[oai(path = "/playlists/:playlist_id/tabs", method = "post")]
     async fn add_tab_to_playlist(
         &self,
-        playlist_id: poem_openapi::param::Path<String>,
-        request: Json<crate::db::models::AddTabToPlaylistRequest>,
-    ) -> poem_openapi::payload::PlainText<String> {
+        playlist_id: Path<String>,
+        request: Json<AddTabToPlaylistRequest>,
+    ) -> AddPlaylistEntryResponse {
+        if request.order_index < 0 {
+            return AddPlaylistEntryResponse::BadRequest(ApiError::bad_request(
+                "order_index must not be negative",
+            ));
+        }
+        if request.duration_seconds.is_some_and(|d| d <= 0) {
+            return AddPlaylistEntryResponse::BadRequest(ApiError::bad_request(
+                "duration_seconds must be positive",
+            ));
+        }
+        if request.weight.is_some_and(|w| w < 0) {
+            return AddPlaylistEntryResponse::BadRequest(ApiError::bad_request(
+                "weight must not be negative",
+            ));
+        }
+        match self
+            .state
+            .playlist_repository
+            .get_by_id(&playlist_id.0)
+            .await
+        {
+            Ok(Some(_)) => {}
+            Ok(None) => {
+                return AddPlaylistEntryResponse::NotFound(ApiError::playlist_not_found(
+                    &playlist_id,
+                ))
+            }
+            Err(e) => return AddPlaylistEntryResponse::InternalError(ApiError::internal(e)),
+        }
+        match self.state.tab_repository.get_by_id(&request.tab_id).await {
+            Ok(Some(_)) => {}
+            Ok(None) => {
+                return AddPlaylistEntryResponse::NotFound(ApiError::tab_not_found(&request.tab_id))
+            }
+            Err(e) => return AddPlaylistEntryResponse::InternalError(ApiError::internal(e)),
+        }
+
+        let tab_id = request.tab_id.clone();
         match self
             .state
             .playlist_tab_repository
             .add_tab_to_playlist(&playlist_id.0, request.0)
             .await
         {
-            Ok(()) => {
-                poem_openapi::payload::PlainText("Tab added to playlist successfully".to_string())
+            Ok(entry_id) => {
+                self.playlist_changed(&playlist_id.0, PlaylistChange::EntriesChanged)
+                    .await;
+                AddPlaylistEntryResponse::Ok(Json(PlaylistEntryInfo {
+                    entry_id,
+                    playlist_id: playlist_id.0,
+                    tab_id,
+                }))
             }
-            Err(e) => poem_openapi::payload::PlainText(format!("Error: {}", e)),
+            Err(e) => AddPlaylistEntryResponse::InternalError(ApiError::internal(e)),
         }
     }
 
-    /// Remove a tab from a playlist
-    #[oai(path = "/playlists/:playlist_id/tabs/:tab_id", method = "delete")]
+    /// Remove an entry from a playlist, other entries of the same tab stay
+    #[oai(path = "/playlists/:playlist_id/entries/:entry_id", method = "delete")]
     async fn remove_tab_from_playlist(
         &self,
-        playlist_id: poem_openapi::param::Path<String>,
-        tab_id: poem_openapi::param::Path<String>,
-    ) -> poem_openapi::payload::PlainText<String> {
+        playlist_id: Path<String>,
+        entry_id: Path<String>,
+    ) -> DeleteResponse {
+        match self.find_playlist_entry(&playlist_id, &entry_id).await {
+            Ok(Ok(())) => {}
+            Ok(Err(error)) => return DeleteResponse::NotFound(error),
+            Err(e) => return DeleteResponse::InternalError(ApiError::internal(e)),
+        }
+
         match self
             .state
             .playlist_tab_repository
-            .remove_tab_from_playlist(&playlist_id.0, &tab_id.0)
+            .remove_tab_from_playlist(&playlist_id.0, &entry_id.0)
             .await
         {
-            Ok(true) => poem_openapi::payload::PlainText(
-                "Tab removed from playlist successfully".to_string(),
-            ),
-            Ok(false) => poem_openapi::payload::PlainText("Tab not found in playlist".to_string()),
-            Err(e) => poem_openapi::payload::PlainText(format!("Error: {}", e)),
+            Ok(true) => {
+                self.playlist_changed(&playlist_id.0, PlaylistChange::EntriesChanged)
+                    .await;
+                DeleteResponse::Ok(PlainText(
+                    "Tab removed from playlist successfully".to_string(),
+                ))
+            }
+            Ok(false) => {
+                DeleteResponse::NotFound(ApiError::entry_not_found(&playlist_id, &entry_id))
+            }
+            Err(e) => DeleteResponse::InternalError(ApiError::internal(e)),
         }
     }
 
-    /// Reorder tabs in a playlist
+    /// Reorder the entries of a playlist
     #[oai(path = "/playlists/:playlist_id/reorder", method = "put")]
     async fn reorder_tabs(
         &self,
-        playlist_id: poem_openapi::param::Path<String>,
-        request: Json<crate::db::models::ReorderTabsRequest>,
-    ) -> poem_openapi::payload::PlainText<String> {
+        playlist_id: Path<String>,
+        request: Json<ReorderTabsRequest>,
+    ) -> PlaylistTabsChangeResponse {
+        let tabs = match self.get_playlist_tabs_impl(&playlist_id.0).await {
+            Ok(Some(tabs)) => tabs,
+            Ok(None) => {
+                return PlaylistTabsChangeResponse::NotFound(ApiError::playlist_not_found(
+                    &playlist_id,
+                ))
+            }
+            Err(e) => return PlaylistTabsChangeResponse::InternalError(ApiError::internal(e)),
+        };
+
+        let unknown: Vec<&str> = request
+            .tab_orders
+            .iter()
+            .map(|order| order.entry_id.as_str())
+            .filter(|entry_id| {
+                !tabs
+                    .iter()
+                    .any(|tab| tab.entry_id.as_deref() == Some(entry_id))
+            })
+            .collect();
+        if !unknown.is_empty() {
+            return PlaylistTabsChangeResponse::BadRequest(ApiError::with_details(
+                ErrorCode::EntryNotFound,
+                "Some entries are not part of the playlist",
+                serde_json::json!({ "playlist_id": playlist_id.0, "entry_ids": unknown }),
+            ));
+        }
+        if request.tab_orders.iter().any(|order| order.order_index < 0) {
+            return PlaylistTabsChangeResponse::BadRequest(ApiError::bad_request(
+                "order_index must not be negative",
+            ));
+        }
+
         match self
             .state
             .playlist_tab_repository
             .reorder_tabs(&playlist_id.0, request.0)
             .await
         {
-            Ok(()) => poem_openapi::payload::PlainText("Tabs reordered successfully".to_string()),
-            Err(e) => poem_openapi::payload::PlainText(format!("Error: {}", e)),
+            Ok(()) => {
+                self.playlist_changed(&playlist_id.0, PlaylistChange::EntriesChanged)
+                    .await;
+                PlaylistTabsChangeResponse::Ok(PlainText("Tabs reordered successfully".to_string()))
+            }
+            Err(e) => PlaylistTabsChangeResponse::InternalError(ApiError::internal(e)),
         }
     }
 
     /// Refresh a tab (reload page)
     #[oai(path = "/tabs/:tab_id/refresh", method = "post")]
-    async fn refresh_tab(
-        &self,
-        tab_id: poem_openapi::param::Path<String>,
-    ) -> poem_openapi::payload::PlainText<String> {
-        let tid = tab_id.0.clone();
+    async fn refresh_tab(&self, tab_id: Path<String>) -> CommandResponse {
+        let tid = tab_id.0;
         tracing::info!("API: Refreshing tab {}", tid);
 
-        // Send message to Chrome controller to refresh tab
-        if let Err(e) = crate::chrome::send_chrome_message(
-            &self.state.chrome,
-            crate::chrome::ChromeMessage::RefreshTab {
-                tab_id: tid.clone(),
-            },
-        )
-        .await
-        {
-            tracing::error!("API: Error refreshing tab {}: {}", tid, e);
-            return poem_openapi::payload::PlainText(format!("Error refreshing tab: {}", e));
+        match self.state.tab_repository.get_by_id(&tid).await {
+            Ok(Some(_)) => {}
+            Ok(None) => return CommandResponse::NotFound(ApiError::tab_not_found(&tid)),
+            Err(e) => return CommandResponse::InternalError(ApiError::internal(e)),
         }
 
-        tracing::info!("API: Successfully sent refresh tab message for {}", tid);
-        poem_openapi::payload::PlainText("Tab refresh initiated".to_string())
+        self.send_command(ChromeMessage::RefreshTab { tab_id: tid })
+            .await
     }
 
     /// Recreate a tab (close and reopen)
     #[oai(path = "/tabs/:tab_id/recreate", method = "post")]
-    async fn recreate_tab(
-        &self,
-        tab_id: poem_openapi::param::Path<String>,
-    ) -> poem_openapi::payload::PlainText<String> {
-        let tid = tab_id.0.clone();
+    async fn recreate_tab(&self, tab_id: Path<String>) -> CommandResponse {
+        let tid = tab_id.0;
         tracing::info!("API: Recreating tab {}", tid);
 
-        // Send message to Chrome controller to recreate tab
-        if let Err(e) = crate::chrome::send_chrome_message(
-            &self.state.chrome,
-            crate::chrome::ChromeMessage::RecreateTab {
-                tab_id: tid.clone(),
-            },
-        )
+        match self.state.tab_repository.get_by_id(&tid).await {
+            Ok(Some(_)) => {}
+            Ok(None) => return CommandResponse::NotFound(ApiError::tab_not_found(&tid)),
+            Err(e) => return CommandResponse::InternalError(ApiError::internal(e)),
+        }
+
+        self.send_command(ChromeMessage::RecreateTab { tab_id: tid })
+            .await
+    }
+
+    /// List the overlays currently shown on top of the display.
+    #[oai(path = "/overlays", method = "get")]
+    async fn get_overlays(&self) -> Json<Vec<OverlayInfo>> {
+        let overlays = self.state.chrome.get_overlays().await;
+        Json(overlays.iter().map(Into::into).collect())
+    }
+
+    /// Show a text banner or ticker on top of whatever is displayed.
+    ///
+    /// The overlay stays visible across tab switches until it expires or is removed.
+    #[oai(path = "/overlays", method = "post")]
+    async fn show_overlay(&self, request: Json<OverlayRequest>) -> ShowOverlayResponse {
+        if request.text.trim().is_empty() {
+            return ShowOverlayResponse::BadRequest(ApiError::bad_request(
+                "text must not be empty",
+            ));
+        }
+        if request.duration_seconds == Some(0) {
+            return ShowOverlayResponse::BadRequest(ApiError::bad_request(
+                "duration_seconds must be positive",
+            ));
+        }
+
+        let overlay = request.0.into_overlay();
+        let info = OverlayInfo::from(&overlay);
+        match self
+            .send_command(ChromeMessage::ShowOverlay { overlay })
+            .await
+        {
+            CommandResponse::Ok(_) => ShowOverlayResponse::Ok(Json(info)),
+            CommandResponse::BrowserNotReady(error) => ShowOverlayResponse::BrowserNotReady(error),
+            CommandResponse::NotFound(error) | CommandResponse::InternalError(error) => {
+                ShowOverlayResponse::InternalError(error)
+            }
+        }
+    }
+
+    /// Remove all overlays.
+    #[oai(path = "/overlays", method = "delete")]
+    async fn clear_overlays(&self) -> CommandResponse {
+        self.send_command(ChromeMessage::ClearOverlays).await
+    }
+
+    /// Remove a single overlay.
+    #[oai(path = "/overlays/:overlay_id", method = "delete")]
+    async fn hide_overlay(&self, overlay_id: Path<String>) -> CommandResponse {
+        let exists = self
+            .state
+            .chrome
+            .get_overlays()
+            .await
+            .iter()
+            .any(|o| o.id == overlay_id.0);
+        if !exists {
+            return CommandResponse::NotFound(ApiError::with_details(
+                ErrorCode::OverlayNotFound,
+                format!("Overlay '{}' not found", overlay_id.0),
+                serde_json::json!({ "overlay_id": overlay_id.0 }),
+            ));
+        }
+        self.send_command(ChromeMessage::HideOverlay {
+            overlay_id: overlay_id.0,
+        })
         .await
+    }
+
+    /// Show a URL for a limited time, then restore the previous playlist and tab.
+    ///
+    /// Rotation is suspended during the takeover. Starting a new takeover
+    /// replaces the running one.
+    #[oai(path = "/takeover", method = "post")]
+    async fn start_takeover(&self, request: Json<TakeoverRequest>) -> StartTakeoverResponse {
+        if let Err(message) = validate_takeover(&request) {
+            return StartTakeoverResponse::BadRequest(ApiError::bad_request(message));
+        }
+        if !self.state.chrome.is_ready().await {
+            return StartTakeoverResponse::BrowserNotReady(ApiError::browser_not_ready());
+        }
+        tracing::info!(
+            "API: Starting takeover of {} for {}s",
+            request.url,
+            request.duration_seconds
+        );
+
+        match self
+            .send_command(ChromeMessage::StartTakeover {
+                url: request.0.url,
+                duration_seconds: request.0.duration_seconds,
+            })
+            .await
+        {
+            CommandResponse::Ok(_) => match &self.state.chrome.state.lock().await.takeover {
+                Some(takeover) => StartTakeoverResponse::Ok(Json(takeover.into())),
+                None => StartTakeoverResponse::InternalError(ApiError::command_failed(
+                    "Takeover ended immediately",
+                )),
+            },
+            CommandResponse::BrowserNotReady(error) => {
+                StartTakeoverResponse::BrowserNotReady(error)
+            }
+            CommandResponse::NotFound(error) | CommandResponse::InternalError(error) => {
+                StartTakeoverResponse::InternalError(error)
+            }
+        }
+    }
+
+    /// End the running takeover early and restore the previous playlist and tab.
+    #[oai(path = "/takeover", method = "delete")]
+    async fn cancel_takeover(&self) -> CommandResponse {
+        if self.state.chrome.state.lock().await.takeover.is_none() {
+            return CommandResponse::NotFound(ApiError::new(
+                ErrorCode::TakeoverNotActive,
+                "No takeover is active",
+            ));
+        }
+        tracing::info!("API: Cancelling takeover");
+        self.send_command(ChromeMessage::CancelTakeover).await
+    }
+
+    /// Pause rotation on the current tab until it is resumed.
+    #[oai(path = "/rotation/pause", method = "post")]
+    async fn pause_rotation(&self) -> CommandResponse {
+        tracing::info!("API: Pausing rotation");
+        self.send_command(ChromeMessage::StopPlaylist).await
+    }
+
+    /// Resume rotation of the active playlist, ending a hold.
+    #[oai(path = "/rotation/resume", method = "post")]
+    async fn resume_rotation(&self) -> CommandResponse {
+        tracing::info!("API: Resuming rotation");
+        self.send_command(ChromeMessage::StartPlaylist).await
+    }
+
+    /// Stay on the current tab for a while, then resume rotation.
+    ///
+    /// A new hold replaces the running one; pausing or resuming ends it.
+    #[oai(path = "/rotation/hold", method = "post")]
+    async fn hold_tab(&self, request: Json<HoldRequest>) -> HoldTabResponse {
+        if request.duration_seconds == 0 || request.duration_seconds > MAX_HOLD_SECONDS {
+            return HoldTabResponse::BadRequest(ApiError::bad_request(format!(
+                "duration_seconds must be between 1 and {}",
+                MAX_HOLD_SECONDS
+            )));
+        }
+        if !self.state.chrome.is_ready().await {
+            return HoldTabResponse::BrowserNotReady(ApiError::browser_not_ready());
+        }
+        {
+            let chrome_state = self.state.chrome.state.lock().await;
+            if chrome_state.takeover.is_some() {
+                return HoldTabResponse::Conflict(ApiError::new(
+                    ErrorCode::TakeoverActive,
+                    "A takeover is active",
+                ));
+            }
+            if chrome_state.current_playlist_id.is_none() {
+                return HoldTabResponse::Conflict(ApiError::new(
+                    ErrorCode::NoActivePlaylist,
+                    "No playlist is active",
+                ));
+            }
+        }
+        tracing::info!("API: Holding current tab for {}s", request.duration_seconds);
+
+        match self
+            .send_command(ChromeMessage::HoldTab {
+                duration_seconds: request.duration_seconds,
+            })
+            .await
         {
-            tracing::error!("API: Error recreating tab {}: {}", tid, e);
-            return poem_openapi::payload::PlainText(format!("Error recreating tab: {}", e));
+            CommandResponse::Ok(_) => {
+                HoldTabResponse::Ok(Json((&*self.state.chrome.state.lock().await).into()))
+            }
+            CommandResponse::BrowserNotReady(error) => HoldTabResponse::BrowserNotReady(error),
+            CommandResponse::NotFound(error) | CommandResponse::InternalError(error) => {
+                HoldTabResponse::InternalError(error)
+            }
+        }
+    }
+
+    /// Press a key as if it came from a keypad or remote, running the action
+    /// configured for it under `[input.keys]`.
+    #[oai(path = "/input/keys/:key", method = "post")]
+    async fn press_key(&self, key: Path<String>) -> CommandResponse {
+        tracing::info!("API: Simulating key {}", key.0);
+        if self
+            .state
+            .input
+            .press(&self.state.config.input, &key.0, "api")
+        {
+            CommandResponse::Ok(PlainText("ok".into()))
+        } else {
+            CommandResponse::NotFound(ApiError::with_details(
+                ErrorCode::KeyNotMapped,
+                format!("No action is configured for key '{}'", key.0),
+                serde_json::json!({ "key": key.0 }),
+            ))
+        }
+    }
+
+    /// List uploaded assets and the used quota
+    #[oai(path = "/assets", method = "get")]
+    async fn get_assets(&self) -> ListAssetsResponse {
+        let repository = &self.state.asset_repository;
+        let assets = match repository.get_all().await {
+            Ok(assets) => assets,
+            Err(e) => return ListAssetsResponse::InternalError(ApiError::internal(e)),
+        };
+        ListAssetsResponse::Ok(Json(AssetLibraryInfo {
+            used_bytes: assets.iter().map(|asset| asset.size as u64).sum(),
+            quota_bytes: self.state.config.assets.quota_bytes(),
+            assets: assets.into_iter().map(Into::into).collect(),
+        }))
+    }
+
+    /// Upload a file to the asset library.
+    /// Uploading content that already exists returns the existing asset.
+    #[oai(path = "/assets", method = "post")]
+    async fn upload_asset(&self, request: UploadAssetRequest) -> UploadAssetResponse {
+        let upload = request.file;
+        let filename = match upload.file_name() {
+            Some(name) if !name.trim().is_empty() => name.to_string(),
+            _ => {
+                return UploadAssetResponse::BadRequest(ApiError::bad_request(
+                    "file must have a file name",
+                ))
+            }
+        };
+        let size = upload.size() as u64;
+        if size == 0 {
+            return UploadAssetResponse::BadRequest(ApiError::bad_request(
+                "file must not be empty",
+            ));
         }
 
-        tracing::info!("API: Successfully sent recreate tab message for {}", tid);
-        poem_openapi::payload::PlainText("Tab recreation initiated".to_string())
+        match assets::store(&self.state, &filename, size, upload.into_async_read()).await {
+            Ok(asset) => UploadAssetResponse::Ok(Json(asset.into())),
+            Err(e) => match e.downcast_ref::<QuotaExceeded>() {
+                Some(quota) => UploadAssetResponse::QuotaExceeded(ApiError::with_details(
+                    ErrorCode::QuotaExceeded,
+                    quota.to_string(),
+                    serde_json::json!({
+                        "quota_bytes": quota.quota_bytes,
+                        "used_bytes": quota.used_bytes,
+                        "size": quota.size,
+                    }),
+                )),
+                None => UploadAssetResponse::InternalError(ApiError::internal(e)),
+            },
+        }
     }
 
-    /// Toggle tab enabled state in playlist
-    #[oai(path = "/playlists/:playlist_id/tabs/:tab_id/toggle", method = "put")]
+    /// Delete an asset and its file
+    #[oai(path = "/assets/:asset_id", method = "delete")]
+    async fn delete_asset(&self, asset_id: Path<String>) -> DeleteResponse {
+        match assets::delete(&self.state, &asset_id.0).await {
+            Ok(true) => DeleteResponse::Ok(PlainText("Asset deleted successfully".to_string())),
+            Ok(false) => DeleteResponse::NotFound(ApiError::asset_not_found(&asset_id)),
+            Err(e) => DeleteResponse::InternalError(ApiError::internal(e)),
+        }
+    }
+
+    /// Set how likely a tab is picked when the playlist rotates in weighted mode
+    #[oai(
+        path = "/playlists/:playlist_id/entries/:entry_id/weight",
+        method = "put"
+    )]
+    async fn set_tab_weight(
+        &self,
+        playlist_id: Path<String>,
+        entry_id: Path<String>,
+        request: Json<SetTabWeightRequest>,
+    ) -> PlaylistTabsChangeResponse {
+        if request.weight < 0 {
+            return PlaylistTabsChangeResponse::BadRequest(ApiError::bad_request(
+                "weight must not be negative",
+            ));
+        }
+        match self.find_playlist_entry(&playlist_id, &entry_id).await {
+            Ok(Ok(())) => {}
+            Ok(Err(error)) => return PlaylistTabsChangeResponse::NotFound(error),
+            Err(e) => return PlaylistTabsChangeResponse::InternalError(ApiError::internal(e)),
+        }
+
+        match self
+            .state
+            .playlist_tab_repository
+            .set_tab_weight(&playlist_id.0, &entry_id.0, request.weight)
+            .await
+        {
+            Ok(true) => {
+                self.playlist_changed(&playlist_id.0, PlaylistChange::EntriesChanged)
+                    .await;
+                PlaylistTabsChangeResponse::Ok(PlainText("Tab weight updated successfully".into()))
+            }
+            Ok(false) => PlaylistTabsChangeResponse::NotFound(ApiError::entry_not_found(
+                &playlist_id,
+                &entry_id,
+            )),
+            Err(e) => PlaylistTabsChangeResponse::InternalError(ApiError::internal(e)),
+        }
+    }
+
+    /// Toggle whether a playlist entry takes part in rotation
+    #[oai(
+        path = "/playlists/:playlist_id/entries/:entry_id/toggle",
+        method = "put"
+    )]
     async fn toggle_tab_enabled(
         &self,
-        playlist_id: poem_openapi::param::Path<String>,
-        tab_id: poem_openapi::param::Path<String>,
-        request: Json<crate::db::models::ToggleTabEnabledRequest>,
-    ) -> poem_openapi::payload::PlainText<String> {
+        playlist_id: Path<String>,
+        entry_id: Path<String>,
+        request: Json<ToggleTabEnabledRequest>,
+    ) -> PlaylistTabsChangeResponse {
+        match self.find_playlist_entry(&playlist_id, &entry_id).await {
+            Ok(Ok(())) => {}
+            Ok(Err(error)) => return PlaylistTabsChangeResponse::NotFound(error),
+            Err(e) => return PlaylistTabsChangeResponse::InternalError(ApiError::internal(e)),
+        }
+
         match self
             .state
             .playlist_tab_repository
-            .toggle_tab_enabled(&playlist_id.0, &tab_id.0, request.enabled)
+            .toggle_tab_enabled(&playlist_id.0, &entry_id.0, request.enabled)
             .await
         {
-            Ok(true) => poem_openapi::payload::PlainText(format!(
-                "Tab {} in playlist {}",
-                if request.enabled {
-                    "enabled"
-                } else {
-                    "disabled"
-                },
-                "successfully"
+            Ok(true) => {
+                self.playlist_changed(&playlist_id.0, PlaylistChange::EntriesChanged)
+                    .await;
+                PlaylistTabsChangeResponse::Ok(PlainText(format!(
+                    "Tab {} in playlist successfully",
+                    if request.enabled {
+                        "enabled"
+                    } else {
+                        "disabled"
+                    }
+                )))
+            }
+            Ok(false) => PlaylistTabsChangeResponse::NotFound(ApiError::entry_not_found(
+                &playlist_id,
+                &entry_id,
             )),
-            Ok(false) => poem_openapi::payload::PlainText("Tab not found in playlist".to_string()),
-            Err(e) => poem_openapi::payload::PlainText(format!("Error: {}", e)),
+            Err(e) => PlaylistTabsChangeResponse::InternalError(ApiError::internal(e)),
         }
     }
 }
@@ -466,16 +1064,28 @@ impl ManagementApi {
                 tab_count: playlist.tabs.len(),
                 interval_seconds: playlist.interval_seconds,
                 is_active: playlist.is_active,
+                rotation_mode: playlist.rotation_mode,
             });
         }
 
         Ok(playlists)
     }
 
+    /// Tabs of a playlist, or `None` if the playlist does not exist
     async fn get_playlist_tabs_impl(
         &self,
         playlist_id: &str,
     ) -> anyhow::Result<Option<Vec<TabInfo>>> {
+        if self
+            .state
+            .playlist_repository
+            .get_by_id(playlist_id)
+            .await?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
         let tabs_with_order = self
             .state
             .playlist_tab_repository
@@ -484,20 +1094,223 @@ impl ManagementApi {
 
         let mut tabs = Vec::new();
         for tab in tabs_with_order {
-            tabs.push(TabInfo {
-                id: tab.id,
-                name: tab.name,
-                url: tab.url,
-                order_index: tab.order_index as usize,
-                persist: tab.persist,
-                viewport_width: tab.viewport_width,
-                viewport_height: tab.viewport_height,
-            });
+            tabs.push(self.tab_info(tab).await);
         }
 
         Ok(Some(tabs))
     }
 
+    /// Check that a playlist exists and contains the tab; the inner error
+    /// is the not-found body to return otherwise
+    async fn find_playlist_tab(
+        &self,
+        playlist_id: &str,
+        tab_id: &str,
+    ) -> anyhow::Result<Result<(), Json<ApiError>>> {
+        if self
+            .state
+            .playlist_repository
+            .get_by_id(playlist_id)
+            .await?
+            .is_none()
+        {
+            return Ok(Err(ApiError::playlist_not_found(playlist_id)));
+        }
+        let contains_tab = self
+            .state
+            .playlist_tab_repository
+            .get_playlist_tabs(playlist_id)
+            .await?
+            .iter()
+            .any(|tab| tab.id == tab_id);
+        if !contains_tab {
+            return Ok(Err(ApiError::tab_not_in_playlist(playlist_id, tab_id)));
+        }
+        Ok(Ok(()))
+    }
+
+    /// Check that a playlist exists and has the entry; the inner error is
+    /// the not-found body to return otherwise
+    async fn find_playlist_entry(
+        &self,
+        playlist_id: &str,
+        entry_id: &str,
+    ) -> anyhow::Result<Result<(), Json<ApiError>>> {
+        if self
+            .state
+            .playlist_repository
+            .get_by_id(playlist_id)
+            .await?
+            .is_none()
+        {
+            return Ok(Err(ApiError::playlist_not_found(playlist_id)));
+        }
+        let has_entry = self
+            .state
+            .playlist_tab_repository
+            .get_playlist_tabs(playlist_id)
+            .await?
+            .iter()
+            .any(|tab| tab.entry_id == entry_id);
+        if !has_entry {
+            return Ok(Err(ApiError::entry_not_found(playlist_id, entry_id)));
+        }
+        Ok(Ok(()))
+    }
+
+    /// Announce an edit of a playlist and apply it to the running rotation
+    async fn playlist_changed(&self, playlist_id: &str, change: PlaylistChange) {
+        self.publish_playlist_change(playlist_id, change);
+        self.notify_controller(ChromeMessage::PlaylistUpdated {
+            playlist_id: playlist_id.to_string(),
+        })
+        .await;
+    }
+
+    fn publish_playlist_change(&self, playlist_id: &str, change: PlaylistChange) {
+        self.state
+            .events
+            .publish(DomainEvent::PlaylistChanged(PlaylistChangedEvent {
+                playlist_id: playlist_id.to_string(),
+                change,
+            }));
+    }
+
+    fn publish_tab_change(&self, tab_id: &str, change: TabChange) {
+        self.state
+            .events
+            .publish(DomainEvent::TabChanged(TabChangedEvent {
+                tab_id: tab_id.to_string(),
+                change,
+            }));
+    }
+
+    /// Tell the browser controller about an edit without waiting for it.
+    /// Skipped while the browser is not running, nothing is shown then.
+    async fn notify_controller(&self, message: ChromeMessage) {
+        if !self.state.chrome.is_ready().await {
+            return;
+        }
+        if let Err(e) = crate::chrome::send_chrome_message(&self.state.chrome, message).await {
+            tracing::warn!("API: Failed to notify Chrome controller: {}", e);
+        }
+    }
+
+    /// Forward a command to the browser controller and wait for its outcome
+    async fn send_command(&self, message: ChromeMessage) -> CommandResponse {
+        if !self.state.chrome.is_ready().await {
+            return CommandResponse::BrowserNotReady(ApiError::browser_not_ready());
+        }
+
+        match crate::chrome::send_chrome_message_with_response(&self.state.chrome, message).await {
+            Ok(ChromeResponse::Error { message }) => {
+                tracing::error!("API: Chrome controller error: {}", message);
+                CommandResponse::InternalError(ApiError::command_failed(message))
+            }
+            Ok(_) => CommandResponse::Ok(PlainText("ok".into())),
+            Err(e) => {
+                tracing::error!("API: Error sending command to Chrome controller: {}", e);
+                CommandResponse::InternalError(ApiError::command_failed(e.to_string()))
+            }
+        }
+    }
+
+    async fn tab_info(&self, tab: TabWithOrder) -> TabInfo {
+        let health = self.state.chrome.get_tab_health(&tab.id).await.into();
+        TabInfo {
+            id: tab.id,
+            name: tab.name,
+            url: tab.url,
+            entry_id: Some(tab.entry_id),
+            order_index: tab.order_index as usize,
+            duration_seconds: tab.duration_seconds,
+            weight: tab.weight,
+            persist: tab.persist,
+            viewport_width: tab.viewport_width,
+            viewport_height: tab.viewport_height,
+            target_width: tab.target_width,
+            target_height: tab.target_height,
+            scaling_mode: tab.scaling_mode,
+            content_type: tab.content_type,
+            content: tab.content,
+            health,
+        }
+    }
+
+    /// `TabInfo` of a tab outside of any playlist context
+    async fn standalone_tab_info(&self, tab: Tab) -> TabInfo {
+        let health = self.state.chrome.get_tab_health(&tab.id).await.into();
+        TabInfo {
+            id: tab.id,
+            name: tab.name,
+            url: tab.url,
+            entry_id: None,
+            order_index: 0,
+            duration_seconds: None,
+            weight: 1,
+            persist: tab.persist,
+            viewport_width: tab.viewport_width,
+            viewport_height: tab.viewport_height,
+            target_width: tab.target_width,
+            target_height: tab.target_height,
+            scaling_mode: tab.scaling_mode,
+            content_type: tab.content_type,
+            content: tab.content,
+            health,
+        }
+    }
+
+    async fn tab_details(&self, tab: Tab) -> anyhow::Result<TabDetails> {
+        let chrome = &self.state.chrome;
+        let playlists = self
+            .state
+            .playlist_tab_repository
+            .get_tab_playlists(&tab.id)
+            .await?
+            .into_iter()
+            .map(|entry| TabMembership {
+                entry_id: entry.id,
+                playlist_id: entry.playlist_id,
+                order_index: entry.order_index as usize,
+                enabled: entry.enabled,
+                duration_seconds: entry.duration_seconds,
+                weight: entry.weight,
+            })
+            .collect();
+
+        let visible = chrome.state.lock().await.current_tab_id.as_deref() == Some(tab.id.as_str());
+        let viewport = chrome
+            .get_viewport_dimensions(&tab.id)
+            .await
+            .or(tab.viewport_width.zip(tab.viewport_height));
+        let runtime = TabRuntimeInfo {
+            page_open: chrome.has_page(&tab.id).await,
+            visible,
+            viewport_width: viewport.map(|(width, _)| width),
+            viewport_height: viewport.map(|(_, height)| height),
+            last_frame_at: chrome
+                .get_last_frame_time(&tab.id)
+                .await
+                .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|duration| duration.as_secs()),
+        };
+
+        Ok(TabDetails {
+            health: chrome.get_tab_health(&tab.id).await.into(),
+            id: tab.id,
+            name: tab.name,
+            url: tab.url,
+            persist: tab.persist,
+            target_width: tab.target_width,
+            target_height: tab.target_height,
+            scaling_mode: tab.scaling_mode,
+            content_type: tab.content_type,
+            content: tab.content,
+            playlists,
+            runtime,
+        })
+    }
+
     async fn get_status_impl(&self) -> anyhow::Result<DeviceStatus> {
         let chrome_state = self.state.chrome.state.lock().await;
         let current_playlist = chrome_state.current_playlist_id.clone();
@@ -506,6 +1319,8 @@ impl ManagementApi {
             .current_tab_opened_at
             .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
             .map(|duration| duration.as_secs());
+        let takeover = chrome_state.takeover.as_ref().map(Into::into);
+        let rotation = (&*chrome_state).into();
 
         // Log state for debugging
         tracing::info!(
@@ -520,12 +1335,119 @@ impl ManagementApi {
             device_name: self.state.config.device.name.clone(),
             current_playlist,
             current_tab,
-            uptime_seconds: 0, // TODO: Calculate uptime
+            uptime_seconds: self
+                .state
+                .started_at
+                .elapsed()
+                .map_or(0, |elapsed| elapsed.as_secs()),
             current_tab_opened_at,
+            takeover,
+            rotation,
+            mqtt: self
+                .state
+                .config
+                .homeassistant
+                .as_ref()
+                .map(|_| (&*self.state.hass.connection_status.lock().unwrap()).into()),
         })
     }
 }
 
+/// Validate the fields of a playlist create/update request
+fn validate_playlist(
+    id: Option<&str>,
+    name: Option<&str>,
+    interval_seconds: Option<i64>,
+) -> Result<(), String> {
+    if id.is_some_and(|id| id.trim().is_empty()) {
+        return Err("id must not be empty".into());
+    }
+    if name.is_some_and(|name| name.trim().is_empty()) {
+        return Err("name must not be empty".into());
+    }
+    if interval_seconds.is_some_and(|interval| interval < 0) {
+        return Err("interval_seconds must not be negative".into());
+    }
+    Ok(())
+}
+
+/// Filter for the `from` / `to` query parameters of reports
+fn report_period(from: Option<i64>, to: Option<i64>) -> Result<PlayReportFilter, String> {
+    let timestamp = |secs: i64| {
+        DateTime::from_timestamp(secs, 0).ok_or_else(|| format!("invalid timestamp {}", secs))
+    };
+    let from = from.map(timestamp).transpose()?;
+    let to = to.map(timestamp).transpose()?;
+    if let (Some(from), Some(to)) = (from, to) {
+        if from > to {
+            return Err("from must not be after to".to_string());
+        }
+    }
+    Ok(PlayReportFilter {
+        from,
+        to,
+        ..Default::default()
+    })
+}
+
+/// Validate the fields of a tab create/update request
+fn validate_tab(
+    id: Option<&str>,
+    name: Option<&str>,
+    target_width: Option<i32>,
+    target_height: Option<i32>,
+) -> Result<(), String> {
+    if id.is_some_and(|id| id.trim().is_empty()) {
+        return Err("id must not be empty".into());
+    }
+    if name.is_some_and(|name| name.trim().is_empty()) {
+        return Err("name must not be empty".into());
+    }
+    if target_width.is_some_and(|w| w <= 0) || target_height.is_some_and(|h| h <= 0) {
+        return Err("target_width and target_height must be positive".into());
+    }
+    Ok(())
+}
+
+/// Validate that a tab has what its content type needs to be displayed.
+/// URL variables are resolved for this device, so unknown ones are reported.
+fn validate_tab_content(
+    content_type: TabContentType,
+    url: &str,
+    content: Option<&str>,
+    device: &DeviceConfig,
+) -> Result<(), String> {
+    if content_type.uses_content() {
+        if content.is_none_or(|content| content.trim().is_empty()) {
+            return Err("content must not be empty for markdown and html tabs".into());
+        }
+        return Ok(());
+    }
+    let resolved =
+        template::render(url, device).map_err(|e| format!("invalid url '{}': {}", url, e))?;
+    reqwest::Url::parse(&resolved).map_err(|e| format!("invalid url '{}': {}", url, e))?;
+    Ok(())
+}
+
+/// Longest takeover accepted by the API
+const MAX_TAKEOVER_SECONDS: u64 = 24 * 60 * 60;
+
+/// Longest hold of the current tab
+const MAX_HOLD_SECONDS: u64 = 24 * 60 * 60;
+
+/// Validate a takeover request
+fn validate_takeover(request: &TakeoverRequest) -> Result<(), String> {
+    reqwest::Url::parse(&request.url)
+        .map_err(|e| format!("invalid url '{}': {}", request.url, e))?;
+    if request.duration_seconds == 0 || request.duration_seconds > MAX_TAKEOVER_SECONDS {
+        return Err(format!(
+            "duration_seconds must be between 1 and {}",
+            MAX_TAKEOVER_SECONDS
+        ));
+    }
+    Ok(())
+}
+
 /// Helper to create an `OpenApiService` from the management API
 pub fn create_api_service(state: Arc<AppState>) -> OpenApiService<ManagementApi, ()> {
     OpenApiService::new(ManagementApi::new(state), "Mission Control API", "0.1.0").server("/")
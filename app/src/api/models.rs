@@ -1,6 +1,20 @@
-use poem_openapi::Object;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use poem_openapi::{types::multipart::Upload, Enum, Multipart, Object};
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    chrome::{
+        overlay::{Overlay, OverlayPosition, OverlaySeverity, OverlayStyle},
+        ChromeState, TabHealth, Takeover,
+    },
+    db::models::{
+        Asset, DeliveryStatus, PlayReportRow, RotationMode, ScalingMode, TabContentType,
+        WebhookDelivery,
+    },
+    models::hass::ConnectionStatus,
+};
+
 /// Information about a playlist
 #[derive(Debug, Clone, Serialize, Deserialize, Object)]
 pub struct PlaylistInfo {
@@ -14,6 +28,8 @@ pub struct PlaylistInfo {
     pub interval_seconds: i64,
     /// Whether this playlist is currently active
     pub is_active: bool,
+    /// Order in which the tabs are rotated
+    pub rotation_mode: RotationMode,
 }
 
 /// Information about a tab
@@ -23,16 +39,163 @@ pub struct TabInfo {
     pub id: String,
     /// Display name of the tab
     pub name: String,
-    /// URL the tab displays
+    /// URL the tab displays, or the media source for image, video and pdf tabs
     pub url: String,
+    /// Playlist entry the tab is listed as (none outside of a playlist)
+    pub entry_id: Option<String>,
     /// Order within the playlist (0-based index)
     pub order_index: usize,
+    /// Display duration of this entry overriding the playlist interval (if set)
+    pub duration_seconds: Option<i64>,
+    /// Relative chance of being picked in weighted rotation
+    pub weight: i64,
     /// Whether this tab persists in browser memory
     pub persist: bool,
     /// Viewport width in pixels (if available)
     pub viewport_width: Option<i32>,
     /// Viewport height in pixels (if available)
     pub viewport_height: Option<i32>,
+    /// Emulated viewport width in CSS pixels (if configured)
+    pub target_width: Option<i32>,
+    /// Emulated viewport height in CSS pixels (if configured)
+    pub target_height: Option<i32>,
+    /// How the emulated viewport is scaled onto the display
+    pub scaling_mode: Option<ScalingMode>,
+    /// What the tab displays
+    pub content_type: TabContentType,
+    /// Markdown or HTML body for markdown and html tabs
+    pub content: Option<String>,
+    /// Whether the tab currently loads successfully
+    pub health: TabHealthInfo,
+}
+
+/// A tab with the playlists it belongs to and its live state in the browser
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct TabDetails {
+    /// Unique identifier for the tab
+    pub id: String,
+    /// Display name of the tab
+    pub name: String,
+    /// URL the tab displays, or the media source for image, video and pdf tabs
+    pub url: String,
+    /// Whether this tab persists in browser memory
+    pub persist: bool,
+    /// Emulated viewport width in CSS pixels (if configured)
+    pub target_width: Option<i32>,
+    /// Emulated viewport height in CSS pixels (if configured)
+    pub target_height: Option<i32>,
+    /// How the emulated viewport is scaled onto the display
+    pub scaling_mode: Option<ScalingMode>,
+    /// What the tab displays
+    pub content_type: TabContentType,
+    /// Markdown or HTML body for markdown and html tabs
+    pub content: Option<String>,
+    /// Whether the tab currently loads successfully
+    pub health: TabHealthInfo,
+    /// Playlists containing this tab (empty for orphaned tabs)
+    pub playlists: Vec<TabMembership>,
+    /// Live state of the tab in the browser
+    pub runtime: TabRuntimeInfo,
+}
+
+/// Membership of a tab in a playlist
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct TabMembership {
+    /// Playlist entry, a tab may be listed in a playlist more than once
+    pub entry_id: String,
+    /// Playlist containing the tab
+    pub playlist_id: String,
+    /// Order within the playlist (0-based index)
+    pub order_index: usize,
+    /// Whether the tab takes part in the playlist's rotation
+    pub enabled: bool,
+    /// Display duration overriding the playlist interval (if set)
+    pub duration_seconds: Option<i64>,
+    /// Relative chance of being picked in weighted rotation
+    pub weight: i64,
+}
+
+/// A tab added to a playlist
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct PlaylistEntryInfo {
+    /// Id addressing this entry in reorder, toggle, weight and remove requests
+    pub entry_id: String,
+    /// Playlist the entry belongs to
+    pub playlist_id: String,
+    /// Tab shown by the entry
+    pub tab_id: String,
+}
+
+/// Live state of a tab in the browser
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct TabRuntimeInfo {
+    /// Whether a browser page is currently open for the tab
+    pub page_open: bool,
+    /// Whether the tab is the one currently shown
+    pub visible: bool,
+    /// Measured viewport width in pixels (if the page was opened)
+    pub viewport_width: Option<i32>,
+    /// Measured viewport height in pixels (if the page was opened)
+    pub viewport_height: Option<i32>,
+    /// When the last screencast frame was captured (seconds since epoch)
+    pub last_frame_at: Option<u64>,
+}
+
+/// A playlist with all of its tabs
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct PlaylistDetails {
+    /// Unique identifier for the playlist
+    pub id: String,
+    /// Display name of the playlist
+    pub name: String,
+    /// Interval between tab switches in seconds
+    pub interval_seconds: i64,
+    /// Whether this playlist is currently active
+    pub is_active: bool,
+    /// Order in which the tabs are rotated
+    pub rotation_mode: RotationMode,
+    /// Tabs of the playlist in playlist order
+    pub tabs: Vec<TabInfo>,
+}
+
+/// Load health of a tab
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct TabHealthInfo {
+    /// False if the last attempt to load the tab failed
+    pub healthy: bool,
+    /// Number of failed load attempts in a row
+    pub consecutive_failures: u32,
+    /// Error of the last failed load attempt
+    pub last_error: Option<String>,
+    /// When the tab last failed to load (seconds since epoch)
+    pub last_failure_at: Option<u64>,
+    /// When rotation will retry the tab (seconds since epoch)
+    pub retry_at: Option<u64>,
+}
+
+impl From<Option<TabHealth>> for TabHealthInfo {
+    fn from(health: Option<TabHealth>) -> Self {
+        let to_secs = |time: Option<std::time::SystemTime>| {
+            time.and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|d| d.as_secs())
+        };
+        match health {
+            Some(health) => Self {
+                healthy: health.consecutive_failures == 0,
+                consecutive_failures: health.consecutive_failures,
+                last_error: health.last_error,
+                last_failure_at: to_secs(health.last_failure_at),
+                retry_at: to_secs(health.retry_at),
+            },
+            None => Self {
+                healthy: true,
+                consecutive_failures: 0,
+                last_error: None,
+                last_failure_at: None,
+                retry_at: None,
+            },
+        }
+    }
 }
 
 /// Current device status
@@ -50,6 +213,339 @@ pub struct DeviceStatus {
     pub uptime_seconds: u64,
     /// When the current tab was opened (seconds since epoch, if available)
     pub current_tab_opened_at: Option<u64>,
+    /// Ad-hoc URL currently shown instead of the playlist (if any)
+    pub takeover: Option<TakeoverInfo>,
+    /// Whether the playlist rotates, is paused or held on the current tab
+    pub rotation: RotationInfo,
+    /// Connection to the MQTT broker (if Home Assistant is configured)
+    pub mqtt: Option<MqttStatus>,
+}
+
+/// Connection to the MQTT broker
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct MqttStatus {
+    /// Whether the broker accepted the connection
+    pub connected: bool,
+    /// When the connection was last established or lost (seconds since epoch)
+    pub changed_at: Option<u64>,
+    /// Why the connection was last lost or refused
+    pub last_error: Option<String>,
+    /// Failed attempts since the last successful connection
+    pub failed_attempts: u32,
+}
+
+impl From<&ConnectionStatus> for MqttStatus {
+    fn from(status: &ConnectionStatus) -> Self {
+        Self {
+            connected: status.connected,
+            changed_at: status
+                .changed_at
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map(|duration| duration.as_secs()),
+            last_error: status.last_error.clone(),
+            failed_attempts: status.failed_attempts,
+        }
+    }
+}
+
+/// Show a text overlay on top of the visible page
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct OverlayRequest {
+    /// Reuse an id to replace an existing overlay (generated if omitted)
+    pub id: Option<String>,
+    /// Text to display
+    pub text: String,
+    /// Color scheme (default info)
+    pub severity: Option<OverlaySeverity>,
+    /// Placement on screen (default top)
+    pub position: Option<OverlayPosition>,
+    /// Static banner or scrolling ticker (default banner)
+    pub style: Option<OverlayStyle>,
+    /// Remove the overlay after this many seconds (kept until removed if omitted)
+    pub duration_seconds: Option<u64>,
+}
+
+impl OverlayRequest {
+    pub fn into_overlay(self) -> Overlay {
+        Overlay {
+            id: self.id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string()),
+            text: self.text,
+            severity: self.severity.unwrap_or_default(),
+            position: self.position.unwrap_or_default(),
+            style: self.style.unwrap_or_default(),
+            expires_at: self
+                .duration_seconds
+                .map(|secs| SystemTime::now() + std::time::Duration::from_secs(secs)),
+        }
+    }
+}
+
+/// An overlay currently shown
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct OverlayInfo {
+    pub id: String,
+    pub text: String,
+    pub severity: OverlaySeverity,
+    pub position: OverlayPosition,
+    pub style: OverlayStyle,
+    /// When the overlay is removed (seconds since epoch, if it expires)
+    pub expires_at: Option<u64>,
+}
+
+impl From<&Overlay> for OverlayInfo {
+    fn from(overlay: &Overlay) -> Self {
+        Self {
+            id: overlay.id.clone(),
+            text: overlay.text.clone(),
+            severity: overlay.severity,
+            position: overlay.position,
+            style: overlay.style,
+            expires_at: overlay
+                .expires_at
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map(|d| d.as_secs()),
+        }
+    }
+}
+
+/// Start a takeover of the display
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct TakeoverRequest {
+    /// URL to show
+    pub url: String,
+    /// How long to show the URL before restoring the playlist
+    pub duration_seconds: u64,
+}
+
+/// A running takeover
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct TakeoverInfo {
+    /// URL shown during the takeover
+    pub url: String,
+    /// When the takeover started (seconds since epoch)
+    pub started_at: u64,
+    /// When the playlist will be restored (seconds since epoch)
+    pub expires_at: u64,
+    /// Seconds left until the playlist is restored
+    pub remaining_seconds: u64,
+    /// Playlist restored afterwards
+    pub previous_playlist_id: Option<String>,
+    /// Tab restored afterwards
+    pub previous_tab_id: Option<String>,
+}
+
+impl From<&Takeover> for TakeoverInfo {
+    fn from(takeover: &Takeover) -> Self {
+        let to_secs = |time: SystemTime| {
+            time.duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default()
+        };
+        Self {
+            url: takeover.url.clone(),
+            started_at: to_secs(takeover.started_at),
+            expires_at: to_secs(takeover.expires_at),
+            remaining_seconds: takeover
+                .expires_at
+                .duration_since(SystemTime::now())
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+            previous_playlist_id: takeover.previous_playlist_id.clone(),
+            previous_tab_id: takeover.previous_tab_id.clone(),
+        }
+    }
+}
+
+/// Hold the current tab, pausing rotation until the hold expires
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct HoldRequest {
+    /// How long to stay on the current tab before rotation resumes
+    pub duration_seconds: u64,
+}
+
+/// Whether the playlist rotates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[serde(rename_all = "lowercase")]
+#[oai(rename_all = "lowercase")]
+pub enum RotationState {
+    /// Tabs change automatically
+    Running,
+    /// Rotation is paused until resumed
+    Paused,
+    /// Rotation is paused until the hold expires
+    Held,
+    /// Someone uses the page, rotation resumes once input stops for the idle timeout
+    Interacting,
+    /// A takeover is shown, rotation resumes when it ends
+    Suspended,
+}
+
+/// Rotation state of the active playlist
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct RotationInfo {
+    pub state: RotationState,
+    /// When a hold expires (seconds since epoch)
+    pub hold_until: Option<u64>,
+    /// Seconds left until a hold expires
+    pub hold_remaining_seconds: Option<u64>,
+}
+
+impl From<&ChromeState> for RotationInfo {
+    fn from(state: &ChromeState) -> Self {
+        let rotation_state = if state.takeover.as_ref().is_some_and(|t| t.resume_rotation) {
+            RotationState::Suspended
+        } else if state.auto_rotate {
+            RotationState::Running
+        } else if let Some(hold) = &state.hold {
+            if hold.interaction {
+                RotationState::Interacting
+            } else {
+                RotationState::Held
+            }
+        } else {
+            RotationState::Paused
+        };
+        Self {
+            state: rotation_state,
+            hold_until: state.hold.as_ref().and_then(|hold| {
+                hold.expires_at
+                    .duration_since(UNIX_EPOCH)
+                    .ok()
+                    .map(|d| d.as_secs())
+            }),
+            hold_remaining_seconds: state.hold.as_ref().map(|hold| {
+                hold.expires_at
+                    .duration_since(SystemTime::now())
+                    .map(|d| d.as_secs())
+                    .unwrap_or_default()
+            }),
+        }
+    }
+}
+
+/// A configured webhook target
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct WebhookInfo {
+    /// URL events are POSTed to
+    pub url: String,
+    /// Event types delivered to this target (all events if empty)
+    pub events: Vec<String>,
+    /// Whether payloads are signed
+    pub signed: bool,
+}
+
+/// A single webhook delivery and its outcome
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct WebhookDeliveryInfo {
+    /// Unique identifier of the delivery
+    pub id: String,
+    /// Target URL
+    pub url: String,
+    /// Type of the delivered event
+    pub event_type: String,
+    /// JSON body that was sent
+    pub payload: String,
+    /// Current delivery status
+    pub status: DeliveryStatus,
+    /// Number of attempts made so far
+    pub attempts: i64,
+    /// HTTP status of the last response (if any)
+    pub response_status: Option<i64>,
+    /// Error of the last failed attempt (if any)
+    pub last_error: Option<String>,
+    /// When the delivery was created (seconds since epoch)
+    pub created_at: i64,
+    /// When the delivery was last attempted (seconds since epoch)
+    pub updated_at: i64,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryInfo {
+    fn from(delivery: WebhookDelivery) -> Self {
+        Self {
+            id: delivery.id,
+            url: delivery.url,
+            event_type: delivery.event_type,
+            payload: delivery.payload,
+            status: delivery.status,
+            attempts: delivery.attempts,
+            response_status: delivery.response_status,
+            last_error: delivery.last_error,
+            created_at: delivery.created_at.timestamp(),
+            updated_at: delivery.updated_at.timestamp(),
+        }
+    }
+}
+
+/// Plays and display time of one tab, playlist or day
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct PlayReportEntry {
+    /// Tab id, playlist id or day (YYYY-MM-DD, UTC) depending on the grouping
+    pub key: String,
+    /// Number of times it was shown
+    pub plays: i64,
+    /// Total time it was visible
+    pub total_seconds: f64,
+}
+
+impl From<PlayReportRow> for PlayReportEntry {
+    fn from(row: PlayReportRow) -> Self {
+        Self {
+            key: row.key,
+            plays: row.plays,
+            total_seconds: row.total_seconds,
+        }
+    }
+}
+
+/// File upload for the asset library
+#[derive(Debug, Multipart)]
+pub struct UploadAssetRequest {
+    /// The file, its name determines the stored MIME type
+    pub file: Upload,
+}
+
+/// Uploaded file in the asset library
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct AssetInfo {
+    /// Unique identifier of the asset
+    pub id: String,
+    /// Original name of the uploaded file
+    pub filename: String,
+    /// MIME type derived from the file name
+    pub mime_type: String,
+    /// Size in bytes
+    pub size: u64,
+    /// Hex encoded SHA-256 of the content
+    pub sha256: String,
+    /// URL to use in tabs, served by this device
+    pub url: String,
+    /// When the asset was uploaded (seconds since epoch)
+    pub created_at: i64,
+}
+
+impl From<Asset> for AssetInfo {
+    fn from(asset: Asset) -> Self {
+        Self {
+            url: crate::assets::local_url(&asset.id),
+            id: asset.id,
+            filename: asset.filename,
+            mime_type: asset.mime_type,
+            size: asset.size as u64,
+            sha256: asset.sha256,
+            created_at: asset.created_at.timestamp(),
+        }
+    }
+}
+
+/// Contents and usage of the asset library
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct AssetLibraryInfo {
+    /// Combined size of all assets in bytes
+    pub used_bytes: u64,
+    /// Maximum combined size in bytes
+    pub quota_bytes: u64,
+    /// Assets, newest first
+    pub assets: Vec<AssetInfo>,
 }
 
 /// Authentication request
@@ -0,0 +1,351 @@
+use poem_openapi::{
+    payload::{Json, PlainText},
+    ApiResponse,
+};
+
+use super::{
+    errors::{parse_error, ApiError},
+    models::*,
+};
+
+#[derive(ApiResponse)]
+pub enum ListPlaylistsResponse {
+    #[oai(status = 200)]
+    Ok(Json<Vec<PlaylistInfo>>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+#[derive(ApiResponse)]
+pub enum GetPlaylistResponse {
+    #[oai(status = 200)]
+    Ok(Json<PlaylistDetails>),
+    #[oai(status = 404)]
+    NotFound(Json<ApiError>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+#[derive(ApiResponse)]
+pub enum PlaylistTabsResponse {
+    #[oai(status = 200)]
+    Ok(Json<Vec<TabInfo>>),
+    #[oai(status = 404)]
+    NotFound(Json<ApiError>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+#[derive(ApiResponse)]
+pub enum ListTabsResponse {
+    #[oai(status = 200)]
+    Ok(Json<Vec<TabDetails>>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+#[derive(ApiResponse)]
+pub enum GetTabResponse {
+    #[oai(status = 200)]
+    Ok(Json<TabDetails>),
+    #[oai(status = 404)]
+    NotFound(Json<ApiError>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+#[derive(ApiResponse)]
+#[allow(clippy::large_enum_variant)]
+pub enum StatusResponse {
+    #[oai(status = 200)]
+    Ok(Json<DeviceStatus>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+#[derive(ApiResponse)]
+#[oai(bad_request_handler = "WebhookDeliveriesResponse::parse_error")]
+pub enum WebhookDeliveriesResponse {
+    #[oai(status = 200)]
+    Ok(Json<Vec<WebhookDeliveryInfo>>),
+    #[oai(status = 400)]
+    BadRequest(Json<ApiError>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+impl WebhookDeliveriesResponse {
+    fn parse_error(error: poem::Error) -> Self {
+        Self::BadRequest(parse_error(error))
+    }
+}
+
+/// Response of commands forwarded to the browser controller
+#[derive(ApiResponse)]
+pub enum CommandResponse {
+    #[oai(status = 200)]
+    Ok(PlainText<String>),
+    #[oai(status = 404)]
+    NotFound(Json<ApiError>),
+    /// Chromium is not running
+    #[oai(status = 503)]
+    BrowserNotReady(Json<ApiError>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+#[derive(ApiResponse)]
+#[oai(bad_request_handler = "CreatePlaylistResponse::parse_error")]
+pub enum CreatePlaylistResponse {
+    #[oai(status = 200)]
+    Ok(Json<PlaylistInfo>),
+    #[oai(status = 400)]
+    BadRequest(Json<ApiError>),
+    /// A playlist with this id already exists
+    #[oai(status = 409)]
+    Conflict(Json<ApiError>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+impl CreatePlaylistResponse {
+    fn parse_error(error: poem::Error) -> Self {
+        Self::BadRequest(parse_error(error))
+    }
+}
+
+#[derive(ApiResponse)]
+#[oai(bad_request_handler = "UpdatePlaylistResponse::parse_error")]
+pub enum UpdatePlaylistResponse {
+    #[oai(status = 200)]
+    Ok(Json<PlaylistInfo>),
+    #[oai(status = 400)]
+    BadRequest(Json<ApiError>),
+    #[oai(status = 404)]
+    NotFound(Json<ApiError>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+impl UpdatePlaylistResponse {
+    fn parse_error(error: poem::Error) -> Self {
+        Self::BadRequest(parse_error(error))
+    }
+}
+
+#[derive(ApiResponse)]
+#[oai(bad_request_handler = "CreateTabResponse::parse_error")]
+pub enum CreateTabResponse {
+    #[oai(status = 200)]
+    Ok(Json<TabInfo>),
+    #[oai(status = 400)]
+    BadRequest(Json<ApiError>),
+    /// A tab with this id already exists
+    #[oai(status = 409)]
+    Conflict(Json<ApiError>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+impl CreateTabResponse {
+    fn parse_error(error: poem::Error) -> Self {
+        Self::BadRequest(parse_error(error))
+    }
+}
+
+#[derive(ApiResponse)]
+#[oai(bad_request_handler = "UpdateTabResponse::parse_error")]
+pub enum UpdateTabResponse {
+    #[oai(status = 200)]
+    Ok(Json<TabInfo>),
+    #[oai(status = 400)]
+    BadRequest(Json<ApiError>),
+    #[oai(status = 404)]
+    NotFound(Json<ApiError>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+impl UpdateTabResponse {
+    fn parse_error(error: poem::Error) -> Self {
+        Self::BadRequest(parse_error(error))
+    }
+}
+
+#[derive(ApiResponse)]
+pub enum DeleteResponse {
+    #[oai(status = 200)]
+    Ok(PlainText<String>),
+    #[oai(status = 404)]
+    NotFound(Json<ApiError>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+/// Response of changes to the tabs of a playlist
+#[derive(ApiResponse)]
+#[oai(bad_request_handler = "PlaylistTabsChangeResponse::parse_error")]
+pub enum PlaylistTabsChangeResponse {
+    #[oai(status = 200)]
+    Ok(PlainText<String>),
+    #[oai(status = 400)]
+    BadRequest(Json<ApiError>),
+    #[oai(status = 404)]
+    NotFound(Json<ApiError>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+impl PlaylistTabsChangeResponse {
+    fn parse_error(error: poem::Error) -> Self {
+        Self::BadRequest(parse_error(error))
+    }
+}
+
+#[derive(ApiResponse)]
+#[oai(bad_request_handler = "AddPlaylistEntryResponse::parse_error")]
+pub enum AddPlaylistEntryResponse {
+    #[oai(status = 200)]
+    Ok(Json<PlaylistEntryInfo>),
+    #[oai(status = 400)]
+    BadRequest(Json<ApiError>),
+    #[oai(status = 404)]
+    NotFound(Json<ApiError>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+impl AddPlaylistEntryResponse {
+    fn parse_error(error: poem::Error) -> Self {
+        Self::BadRequest(parse_error(error))
+    }
+}
+
+#[derive(ApiResponse)]
+#[oai(bad_request_handler = "StartTakeoverResponse::parse_error")]
+pub enum StartTakeoverResponse {
+    #[oai(status = 200)]
+    Ok(Json<TakeoverInfo>),
+    #[oai(status = 400)]
+    BadRequest(Json<ApiError>),
+    /// Chromium is not running
+    #[oai(status = 503)]
+    BrowserNotReady(Json<ApiError>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+impl StartTakeoverResponse {
+    fn parse_error(error: poem::Error) -> Self {
+        Self::BadRequest(parse_error(error))
+    }
+}
+
+#[derive(ApiResponse)]
+#[oai(bad_request_handler = "HoldTabResponse::parse_error")]
+pub enum HoldTabResponse {
+    #[oai(status = 200)]
+    Ok(Json<RotationInfo>),
+    #[oai(status = 400)]
+    BadRequest(Json<ApiError>),
+    /// A takeover is running or no playlist is active
+    #[oai(status = 409)]
+    Conflict(Json<ApiError>),
+    /// Chromium is not running
+    #[oai(status = 503)]
+    BrowserNotReady(Json<ApiError>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+impl HoldTabResponse {
+    fn parse_error(error: poem::Error) -> Self {
+        Self::BadRequest(parse_error(error))
+    }
+}
+
+#[derive(ApiResponse)]
+#[oai(bad_request_handler = "ShowOverlayResponse::parse_error")]
+pub enum ShowOverlayResponse {
+    #[oai(status = 200)]
+    Ok(Json<OverlayInfo>),
+    #[oai(status = 400)]
+    BadRequest(Json<ApiError>),
+    /// Chromium is not running
+    #[oai(status = 503)]
+    BrowserNotReady(Json<ApiError>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+impl ShowOverlayResponse {
+    fn parse_error(error: poem::Error) -> Self {
+        Self::BadRequest(parse_error(error))
+    }
+}
+
+#[derive(ApiResponse)]
+pub enum ListAssetsResponse {
+    #[oai(status = 200)]
+    Ok(Json<AssetLibraryInfo>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+#[derive(ApiResponse)]
+#[oai(bad_request_handler = "UploadAssetResponse::parse_error")]
+pub enum UploadAssetResponse {
+    #[oai(status = 200)]
+    Ok(Json<AssetInfo>),
+    #[oai(status = 400)]
+    BadRequest(Json<ApiError>),
+    /// The asset library has no room for the file
+    #[oai(status = 413)]
+    QuotaExceeded(Json<ApiError>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+impl UploadAssetResponse {
+    fn parse_error(error: poem::Error) -> Self {
+        Self::BadRequest(parse_error(error))
+    }
+}
+
+#[derive(ApiResponse)]
+#[oai(bad_request_handler = "PlayReportResponse::parse_error")]
+pub enum PlayReportResponse {
+    #[oai(status = 200)]
+    Ok(Json<Vec<PlayReportEntry>>),
+    #[oai(status = 400)]
+    BadRequest(Json<ApiError>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+impl PlayReportResponse {
+    fn parse_error(error: poem::Error) -> Self {
+        Self::BadRequest(parse_error(error))
+    }
+}
+
+#[derive(ApiResponse)]
+#[oai(bad_request_handler = "PlayExportResponse::parse_error")]
+pub enum PlayExportResponse {
+    /// One row per play
+    #[oai(status = 200, content_type = "text/csv")]
+    Ok(
+        PlainText<String>,
+        #[oai(header = "Content-Disposition")] String,
+    ),
+    #[oai(status = 400)]
+    BadRequest(Json<ApiError>),
+    #[oai(status = 500)]
+    InternalError(Json<ApiError>),
+}
+
+impl PlayExportResponse {
+    fn parse_error(error: poem::Error) -> Self {
+        Self::BadRequest(parse_error(error))
+    }
+}
@@ -0,0 +1,154 @@
+use std::{fmt, path::PathBuf};
+
+use anyhow::Result;
+use async_std::{fs, io::WriteExt};
+use chrono::Utc;
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tracing::{info, warn};
+
+use crate::{
+    config::AssetsConfig,
+    db::{models::Asset, repositories::AssetRepository},
+    http,
+    state::AppState,
+};
+
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// An upload would grow the asset library beyond its configured quota
+#[derive(Debug)]
+pub struct QuotaExceeded {
+    pub quota_bytes: u64,
+    pub used_bytes: u64,
+    pub size: u64,
+}
+
+impl fmt::Display for QuotaExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "asset of {} bytes exceeds the quota ({} of {} bytes used)",
+            self.size, self.used_bytes, self.quota_bytes
+        )
+    }
+}
+
+impl std::error::Error for QuotaExceeded {}
+
+impl AssetsConfig {
+    pub fn quota_bytes(&self) -> u64 {
+        self.quota_mb * 1024 * 1024
+    }
+
+    pub fn file_path(&self, asset: &Asset) -> PathBuf {
+        PathBuf::from(&self.dir).join(&asset.stored_name)
+    }
+}
+
+/// URL under which Chromium (and tabs) can load an asset
+pub fn local_url(asset_id: &str) -> String {
+    http::local_url(&format!("/assets/{}", asset_id))
+}
+
+/// Store an uploaded file, enforcing the quota with its announced `size`.
+/// Uploading content that is already in the library returns the existing asset.
+pub async fn store(
+    state: &AppState,
+    filename: &str,
+    size: u64,
+    mut reader: impl AsyncRead + Unpin,
+) -> Result<Asset> {
+    let config = &state.config.assets;
+    let used_bytes = state.asset_repository.total_size().await? as u64;
+    if used_bytes + size > config.quota_bytes() {
+        return Err(QuotaExceeded {
+            quota_bytes: config.quota_bytes(),
+            used_bytes,
+            size,
+        }
+        .into());
+    }
+
+    let id = uuid::Uuid::new_v4().to_string();
+    // Keep the extension so the file stays recognizable in the directory
+    let stored_name = match std::path::Path::new(filename)
+        .extension()
+        .and_then(|ext| ext.to_str())
+    {
+        Some(ext) => format!("{}.{}", id, ext.to_lowercase()),
+        None => id.clone(),
+    };
+    let path = PathBuf::from(&config.dir).join(&stored_name);
+
+    fs::create_dir_all(&config.dir).await?;
+    let mut file = fs::File::create(&path).await?;
+    let mut hasher = Sha256::new();
+    let mut written = 0u64;
+    let mut buffer = vec![0u8; BUFFER_SIZE];
+    let copied: Result<()> = async {
+        loop {
+            let n = reader.read(&mut buffer).await?;
+            if n == 0 {
+                break;
+            }
+            hasher.update(&buffer[..n]);
+            file.write_all(&buffer[..n]).await?;
+            written += n as u64;
+        }
+        file.flush().await?;
+        Ok(())
+    }
+    .await;
+    if let Err(e) = copied {
+        let _ = fs::remove_file(&path).await;
+        return Err(e);
+    }
+    let sha256 = hex::encode(hasher.finalize());
+
+    if let Some(existing) = state.asset_repository.get_by_hash(&sha256).await? {
+        info!(
+            "asset {} has the same content as {}, keeping the existing one",
+            filename, existing.id
+        );
+        let _ = fs::remove_file(&path).await;
+        return Ok(existing);
+    }
+
+    let asset = Asset {
+        id,
+        filename: filename.to_string(),
+        stored_name,
+        mime_type: mime_guess::from_path(filename)
+            .first_or_octet_stream()
+            .to_string(),
+        size: written as i64,
+        sha256,
+        created_at: Utc::now(),
+    };
+    if let Err(e) = state.asset_repository.create(&asset).await {
+        let _ = fs::remove_file(&path).await;
+        return Err(e);
+    }
+    info!(
+        "stored asset {} ({}, {} bytes) as {}",
+        asset.filename, asset.mime_type, asset.size, asset.id
+    );
+
+    Ok(asset)
+}
+
+/// Remove an asset and its file, returns `false` if it does not exist
+pub async fn delete(state: &AppState, id: &str) -> Result<bool> {
+    let Some(asset) = state.asset_repository.get_by_id(id).await? else {
+        return Ok(false);
+    };
+    state.asset_repository.delete(id).await?;
+
+    let path = state.config.assets.file_path(&asset);
+    if let Err(e) = fs::remove_file(&path).await {
+        warn!("failed to remove asset file {}: {}", path.display(), e);
+    }
+    info!("deleted asset {} ({})", asset.id, asset.filename);
+    Ok(true)
+}
@@ -1,45 +1,96 @@
 use std::{
     collections::HashMap,
     sync::Arc,
-    time::{Duration, Instant},
+    time::{Duration, Instant, SystemTime},
 };
 
 use anyhow::{anyhow, Result};
 use async_std::{sync::Mutex, task};
 use chromiumoxide::{
-    cdp::browser_protocol::page::{
-        AddScriptToEvaluateOnNewDocumentParams, EventScreencastFrame, NavigateParams,
-        ScreencastFrameAckParams, StartScreencastFormat, StartScreencastParams,
+    cdp::browser_protocol::{
+        inspector::{self, EventTargetCrashed},
+        page::{
+            AddScriptToEvaluateOnNewDocumentParams, CaptureScreenshotFormat,
+            CaptureScreenshotParams, EventScreencastFrame, NavigateParams,
+            ScreencastFrameAckParams, StartScreencastFormat, StartScreencastParams,
+        },
+        target::CreateTargetParams,
     },
     Browser, BrowserConfig, Page,
 };
-use futures::channel::mpsc::{channel, Receiver, Sender};
+use futures::channel::{
+    mpsc::{channel, Receiver, Sender},
+    oneshot,
+};
 use futures::{SinkExt, StreamExt};
 use tracing::{error, info, warn};
 
 use crate::{
     config::ChromiumConfig,
-    db::models::TabWithOrder,
-    db::repositories::{PlaylistRepository, PlaylistTabRepository, TabRepository},
+    db::models::{PlayRecord, PlayTrigger, RotationMode, Tab, TabWithOrder},
+    db::repositories::{
+        PlayLogRepository, PlaylistRepository, PlaylistTabRepository, TabRepository,
+    },
+    events::{
+        BrowserCrashedEvent, BrowserStatusChangedEvent, DomainEvent, EventBus, PlaylistChange,
+        PlaylistChangedEvent, TabActivatedEvent, TabCrashedEvent, TabFailedEvent,
+        TakeoverEndedEvent, TakeoverStartedEvent,
+    },
+    http::content::page_url,
     state::AppState,
+    template,
 };
 
-use super::{ChromeMessage, ChromeResponse, ChromeState};
+use super::{
+    emulation::{self, ViewportTarget},
+    interaction,
+    overlay::{self, Overlay},
+    rotation::Rotation,
+    transition::{self, TransitionEffect, WaitUntil},
+    ChromeMessage, ChromeRequest, ChromeResponse, ChromeState, Hold, TabHealth, Takeover,
+};
 
 const SCREENCAST_MAX_FPS: u64 = 4;
 const SCREENCAST_MAX_BYTES: usize = 5_000_000;
+/// First retry delay for a tab that failed to load, doubled on every failure
+const TAB_RETRY_BASE_SECS: u64 = 30;
+const TAB_RETRY_MAX_SECS: u64 = 15 * 60;
+
+/// Where rotation continues from the visible tab
+struct UpcomingTab {
+    playlist_id: String,
+    mode: RotationMode,
+    /// Enabled tabs of the playlist in playlist order
+    tabs: Vec<TabWithOrder>,
+    current: usize,
+    next: usize,
+}
 
 pub struct ChromeController {
     pub state: Arc<Mutex<ChromeState>>,
     browser: Arc<Mutex<Option<Arc<Browser>>>>,
     pages: Arc<Mutex<HashMap<String, Arc<Page>>>>,
     pub last_frame: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+    last_frame_at: Arc<Mutex<HashMap<String, SystemTime>>>,
     viewport: Arc<Mutex<HashMap<String, (i32, i32)>>>,
+    /// Outcome of the last preload per tab (true if it loaded in time)
+    preloaded: Arc<Mutex<HashMap<String, bool>>>,
+    health: Arc<Mutex<HashMap<String, TabHealth>>>,
     should_screen_capture: Arc<Mutex<bool>>,
     auto_task: Arc<Mutex<Option<task::JoinHandle<()>>>>,
-    message_sender: Sender<ChromeMessage>,
-    message_receiver: Arc<Mutex<Receiver<ChromeMessage>>>,
-    response_sender: Arc<Mutex<Option<Sender<ChromeResponse>>>>,
+    takeover_page: Arc<Mutex<Option<Arc<Page>>>>,
+    takeover_task: Arc<Mutex<Option<task::JoinHandle<()>>>>,
+    hold_task: Arc<Mutex<Option<task::JoinHandle<()>>>>,
+    overlays: Arc<Mutex<Vec<Overlay>>>,
+    rotation: Arc<Mutex<Rotation>>,
+    /// Trigger of the message being handled
+    trigger: Arc<Mutex<PlayTrigger>>,
+    /// Play log entry of the visible tab
+    current_play: Arc<Mutex<Option<PlayRecord>>>,
+    /// State JSON last published over MQTT
+    published_state: Arc<Mutex<String>>,
+    message_sender: Sender<ChromeRequest>,
+    message_receiver: Arc<Mutex<Receiver<ChromeRequest>>>,
 }
 
 impl Default for ChromeController {
@@ -56,26 +107,41 @@ impl ChromeController {
             browser: Arc::new(Mutex::new(None)),
             pages: Arc::new(Mutex::new(HashMap::new())),
             last_frame: Arc::new(Mutex::new(HashMap::new())),
+            last_frame_at: Arc::new(Mutex::new(HashMap::new())),
             viewport: Arc::new(Mutex::new(HashMap::new())),
+            preloaded: Arc::new(Mutex::new(HashMap::new())),
+            health: Arc::new(Mutex::new(HashMap::new())),
             should_screen_capture: Arc::new(Mutex::new(true)),
             auto_task: Arc::new(Mutex::new(None)),
+            takeover_page: Arc::new(Mutex::new(None)),
+            takeover_task: Arc::new(Mutex::new(None)),
+            hold_task: Arc::new(Mutex::new(None)),
+            overlays: Arc::new(Mutex::new(Vec::new())),
+            rotation: Arc::new(Mutex::new(Rotation::default())),
+            trigger: Arc::new(Mutex::new(PlayTrigger::Rotation)),
+            current_play: Arc::new(Mutex::new(None)),
+            published_state: Arc::new(Mutex::new(String::new())),
             message_sender,
             message_receiver: Arc::new(Mutex::new(message_receiver)),
-            response_sender: Arc::new(Mutex::new(None)),
         }
     }
 
-    pub fn get_message_sender(&self) -> Sender<ChromeMessage> {
+    pub fn get_message_sender(&self) -> Sender<ChromeRequest> {
         self.message_sender.clone()
     }
 
+    /// Whether Chromium is running and messages are being processed
+    pub async fn is_ready(&self) -> bool {
+        self.browser.lock().await.is_some()
+    }
+
     pub async fn start(
         self: &Arc<Self>,
         config: &ChromiumConfig,
         app_state: &Arc<AppState>,
     ) -> Result<()> {
         if self.browser.lock().await.is_none() {
-            self.launch_browser(config).await?;
+            self.launch_browser(config, &app_state.events).await?;
         }
         let controller = Arc::clone(self);
         let app_state_clone = app_state.clone();
@@ -137,10 +203,12 @@ impl ChromeController {
             .map_err(|e| anyhow!("Failed to build browser config: {}", e))
     }
 
-    async fn launch_browser(&self, config: &ChromiumConfig) -> Result<()> {
+    async fn launch_browser(&self, config: &ChromiumConfig, events: &EventBus) -> Result<()> {
         let cfg = Self::build_browser_config(config)?;
         let (browser, mut handler) = Browser::launch(cfg).await?;
         // Keep the handler alive in a background task
+        let handler_events = events.clone();
+        let mut sender = self.message_sender.clone();
         task::spawn(async move {
             while let Some(evt) = handler.next().await {
                 if let Err(e) = evt {
@@ -149,36 +217,65 @@ impl ChromeController {
                 }
             }
             warn!("Chromium handler loop ended");
+            handler_events.publish(DomainEvent::BrowserStatusChanged(
+                BrowserStatusChangedEvent { running: false },
+            ));
+            let _ = sender.send(ChromeMessage::BrowserExited.into()).await;
         });
         *self.browser.lock().await = Some(Arc::new(browser));
         info!("Chromium launched");
+        events.publish(DomainEvent::BrowserStatusChanged(
+            BrowserStatusChangedEvent { running: true },
+        ));
         Ok(())
     }
 
     async fn run_message_loop(self: Arc<Self>, app_state: Arc<AppState>, config: ChromiumConfig) {
-        while let Some(msg) = { self.message_receiver.lock().await.next().await } {
-            info!("chrome message loop received: {:?}", msg);
-            let resp = match self.handle_message(msg, &app_state, &config).await {
+        while let Some(request) = { self.message_receiver.lock().await.next().await } {
+            info!("chrome message loop received: {:?}", request.message);
+            *self.trigger.lock().await = request.trigger;
+            let resp = match self
+                .handle_message(request.message, &app_state, &config)
+                .await
+            {
                 Ok(r) => r,
                 Err(e) => ChromeResponse::Error {
                     message: e.to_string(),
                 },
             };
-            if let Some(sender) = &mut *self.response_sender.lock().await {
-                let _ = sender.send(resp).await;
+            if let Some(reply) = request.reply {
+                let _ = reply.send(resp);
             }
+            self.publish_state(&app_state).await;
         }
         error!("Chrome message loop exited");
     }
 
+    /// Publish the state over MQTT if a message changed it
+    async fn publish_state(&self, app_state: &Arc<AppState>) {
+        let json = match serde_json::to_string(&*self.state.lock().await) {
+            Ok(json) => json,
+            Err(e) => {
+                warn!("failed to serialize state: {}", e);
+                return;
+            }
+        };
+        let mut published = self.published_state.lock().await;
+        if *published != json {
+            app_state.hass.publish_device_state(&json);
+            *published = json;
+        }
+    }
+
     async fn handle_message(
         &self,
         msg: ChromeMessage,
         app_state: &Arc<AppState>,
-        _config: &ChromiumConfig,
+        config: &ChromiumConfig,
     ) -> Result<ChromeResponse> {
         match msg {
             ChromeMessage::ActivatePlaylist { playlist_id } => {
+                self.override_takeover(app_state).await;
                 self.activate_playlist(playlist_id, app_state).await?;
                 Ok(ChromeResponse::Success)
             }
@@ -186,17 +283,32 @@ impl ChromeController {
                 tab_id,
                 playlist_id,
             } => {
-                self.activate_tab(tab_id, playlist_id, app_state).await?;
+                self.override_takeover(app_state).await;
+                self.activate_tab(tab_id, playlist_id, None, app_state)
+                    .await?;
                 Ok(ChromeResponse::Success)
             }
             ChromeMessage::NextTab => {
+                if self.has_takeover().await {
+                    return Ok(ChromeResponse::Success);
+                }
                 self.next_tab(app_state).await?;
                 Ok(ChromeResponse::Success)
             }
             ChromeMessage::PreviousTab => {
+                if self.has_takeover().await {
+                    return Ok(ChromeResponse::Success);
+                }
                 self.previous_tab(app_state).await?;
                 Ok(ChromeResponse::Success)
             }
+            ChromeMessage::PreloadNextTab => {
+                if self.has_takeover().await {
+                    return Ok(ChromeResponse::Success);
+                }
+                self.preload_next_tab(app_state).await?;
+                Ok(ChromeResponse::Success)
+            }
             ChromeMessage::UpdateTabUrl { tab_id, url } => {
                 self.update_tab_url(tab_id, url, app_state).await?;
                 Ok(ChromeResponse::Success)
@@ -213,6 +325,22 @@ impl ChromeController {
                 self.recreate_tab(tab_id, app_state).await?;
                 Ok(ChromeResponse::Success)
             }
+            ChromeMessage::PlaylistUpdated { playlist_id } => {
+                self.playlist_updated(&playlist_id, app_state).await?;
+                Ok(ChromeResponse::Success)
+            }
+            ChromeMessage::TabUpdated {
+                tab_id,
+                viewport_changed,
+            } => {
+                self.tab_updated(&tab_id, viewport_changed, app_state)
+                    .await?;
+                Ok(ChromeResponse::Success)
+            }
+            ChromeMessage::TabDeleted { tab_id } => {
+                self.tab_deleted(tab_id, app_state).await?;
+                Ok(ChromeResponse::Success)
+            }
             ChromeMessage::UpdateInterval {
                 playlist_id,
                 interval_seconds,
@@ -221,12 +349,30 @@ impl ChromeController {
                     .playlist_repository
                     .update_interval(&playlist_id, interval_seconds)
                     .await?;
+                self.playlist_updated(&playlist_id, app_state).await?;
                 Ok(ChromeResponse::Success)
             }
             ChromeMessage::ReloadTab => {
                 self.reload_current_tab().await?;
                 Ok(ChromeResponse::Success)
             }
+            ChromeMessage::ShowOverlay { overlay } => {
+                self.show_overlay(overlay).await;
+                Ok(ChromeResponse::Success)
+            }
+            ChromeMessage::HideOverlay { overlay_id } => {
+                self.hide_overlay(&overlay_id).await?;
+                Ok(ChromeResponse::Success)
+            }
+            ChromeMessage::ClearOverlays => {
+                self.overlays.lock().await.clear();
+                self.refresh_visible_overlays().await;
+                Ok(ChromeResponse::Success)
+            }
+            ChromeMessage::ExpireOverlays => {
+                self.refresh_visible_overlays().await;
+                Ok(ChromeResponse::Success)
+            }
             ChromeMessage::GetStatus => {
                 let st = self.state.lock().await;
                 Ok(ChromeResponse::Status {
@@ -237,26 +383,116 @@ impl ChromeController {
                 })
             }
             ChromeMessage::StopPlaylist => {
+                self.clear_hold().await;
                 self.stop_auto_rotation().await;
-                let mut st = self.state.lock().await;
-                st.is_running = false;
+                {
+                    let mut st = self.state.lock().await;
+                    st.is_running = false;
+                    // Stay paused once the takeover ends
+                    if let Some(takeover) = &mut st.takeover {
+                        takeover.resume_rotation = false;
+                    }
+                }
+                self.publish_rotation_state(app_state).await;
                 Ok(ChromeResponse::Success)
             }
             ChromeMessage::StartPlaylist => {
-                if let Some(pid) = self.state.lock().await.current_playlist_id.clone() {
-                    if let Some(pl) = app_state.playlist_repository.get_by_id(&pid).await? {
-                        self.start_auto_rotation(pl.interval_seconds).await?;
-                    }
+                self.clear_hold().await;
+                self.resume_rotation(app_state).await?;
+                Ok(ChromeResponse::Success)
+            }
+            ChromeMessage::HoldTab { duration_seconds } => {
+                self.hold_tab(duration_seconds, app_state).await?;
+                Ok(ChromeResponse::Success)
+            }
+            ChromeMessage::UserInteraction { tab_id } => {
+                self.user_interaction(&tab_id, app_state).await;
+                Ok(ChromeResponse::Success)
+            }
+            ChromeMessage::ExpireHold => {
+                let due = self
+                    .state
+                    .lock()
+                    .await
+                    .hold
+                    .as_ref()
+                    .is_some_and(|hold| hold.expires_at <= SystemTime::now());
+                if due {
+                    info!("hold ended, resuming rotation");
+                    self.clear_hold().await;
+                    self.resume_rotation(app_state).await?;
+                }
+                Ok(ChromeResponse::Success)
+            }
+            ChromeMessage::StartTakeover {
+                url,
+                duration_seconds,
+            } => {
+                self.start_takeover(url, duration_seconds, app_state)
+                    .await?;
+                Ok(ChromeResponse::Success)
+            }
+            ChromeMessage::CancelTakeover => {
+                if !self.has_takeover().await {
+                    return Err(anyhow!("No takeover is active"));
                 }
+                self.end_takeover(false, app_state).await?;
+                Ok(ChromeResponse::Success)
+            }
+            ChromeMessage::ExpireTakeover { takeover_id } => {
+                let current = self
+                    .state
+                    .lock()
+                    .await
+                    .takeover
+                    .as_ref()
+                    .map(|t| t.id.clone());
+                if current.as_deref() == Some(takeover_id.as_str()) {
+                    self.end_takeover(true, app_state).await?;
+                }
+                Ok(ChromeResponse::Success)
+            }
+            ChromeMessage::BrowserExited => {
+                self.restart_browser(config, app_state).await;
                 Ok(ChromeResponse::Success)
             }
             ChromeMessage::Shutdown => {
+                self.end_play(app_state).await;
                 self.shutdown().await?;
                 Ok(ChromeResponse::Success)
             }
         }
     }
 
+    /// Relaunch Chromium after it exited on its own and show the active playlist again
+    async fn restart_browser(&self, config: &ChromiumConfig, app_state: &Arc<AppState>) {
+        // Shutdown clears the browser before it exits
+        if self.browser.lock().await.is_none() {
+            return;
+        }
+        error!("Chromium exited unexpectedly, relaunching");
+        self.end_play(app_state).await;
+        // The pages died with the browser
+        self.shutdown().await.ok();
+        self.last_frame.lock().await.clear();
+        self.last_frame_at.lock().await.clear();
+        self.viewport.lock().await.clear();
+
+        let result = match self.launch_browser(config, &app_state.events).await {
+            Ok(()) => self.ensure_active_playlist(app_state).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = &result {
+            error!("failed to restart Chromium: {}", e);
+        }
+        app_state
+            .events
+            .publish(DomainEvent::BrowserCrashed(BrowserCrashedEvent {
+                restarted: self.browser.lock().await.is_some(),
+                error: result.err().map(|e| e.to_string()),
+            }));
+    }
+
     async fn ensure_active_playlist(&self, app_state: &Arc<AppState>) -> Result<()> {
         let playlists = app_state.playlist_repository.get_all().await?;
         if playlists.is_empty() {
@@ -293,7 +529,7 @@ impl ChromeController {
         app_state: &Arc<AppState>,
     ) -> Result<()> {
         info!("activate_playlist: {}", &playlist_id);
-        let _playlist = app_state
+        let playlist = app_state
             .playlist_repository
             .get_by_id(&playlist_id)
             .await?
@@ -326,7 +562,13 @@ impl ChromeController {
         app_state
             .hass
             .publish_playlist_options(playlist_ids, Some(&playlist_id));
+        app_state.hass.interval_entity.update_state(
+            &app_state.hass.mqtt_client,
+            &playlist.interval_seconds.to_string(),
+        );
 
+        self.preloaded.lock().await.clear();
+        self.rotation.lock().await.reset();
         {
             let mut st = self.state.lock().await;
             st.current_playlist_id = Some(playlist_id.clone());
@@ -334,11 +576,20 @@ impl ChromeController {
             st.is_running = true;
             st.auto_rotate = false;
         }
+        app_state
+            .events
+            .publish(DomainEvent::PlaylistChanged(PlaylistChangedEvent {
+                playlist_id: playlist_id.clone(),
+                change: PlaylistChange::Activated,
+            }));
 
         for tab in &tabs {
             if tab.persist && !self.pages.lock().await.contains_key(&tab.id) {
                 info!("preloading persistent tab {}", tab.id);
-                if let Err(e) = self.create_tab_page(&tab.id, &tab.url, app_state).await {
+                let Some(full_tab) = app_state.tab_repository.get_by_id(&tab.id).await? else {
+                    continue;
+                };
+                if let Err(e) = self.create_tab_page(&full_tab, app_state).await {
                     warn!("failed to preload tab {}: {}", tab.id, e);
                 }
             }
@@ -346,16 +597,19 @@ impl ChromeController {
 
         app_state.hass.publish_tab_options(&tabs, Some(&tabs[0].id));
 
+        // Rotation starts once the first tab is shown, so it knows its duration
+        let first = self
+            .activate_first_available(&playlist_id, &tabs, 0, app_state)
+            .await;
         if let Some(pl) = app_state
             .playlist_repository
             .get_by_id(&playlist_id)
             .await?
         {
-            self.start_auto_rotation(pl.interval_seconds).await?;
+            self.start_auto_rotation(pl.interval_seconds, app_state)
+                .await?;
         }
-
-        self.activate_tab(tabs[0].id.clone(), playlist_id, app_state)
-            .await?;
+        self.state.lock().await.current_tab_index = first?;
         Ok(())
     }
 
@@ -363,6 +617,109 @@ impl ChromeController {
         &self,
         tab_id: String,
         playlist_id: String,
+        entry_id: Option<String>,
+        app_state: &Arc<AppState>,
+    ) -> Result<()> {
+        let result = self
+            .show_tab(tab_id.clone(), playlist_id, entry_id, app_state)
+            .await;
+        let error = result.as_ref().err().map(|e| e.to_string());
+        let failures = self.record_tab_health(&tab_id, error.clone()).await;
+        if let Some(error) = error {
+            app_state
+                .events
+                .publish(DomainEvent::TabFailed(TabFailedEvent {
+                    tab_id,
+                    error,
+                    consecutive_failures: failures,
+                }));
+        }
+        result
+    }
+
+    /// Mark a tab healthy, or record a failure and schedule its next retry.
+    /// Returns the number of consecutive failures.
+    async fn record_tab_health(&self, tab_id: &str, error: Option<String>) -> u32 {
+        let mut health = self.health.lock().await;
+        match error {
+            None => {
+                health.remove(tab_id);
+                0
+            }
+            Some(error) => {
+                let entry = health.entry(tab_id.to_string()).or_default();
+                entry.consecutive_failures += 1;
+                let backoff = TAB_RETRY_BASE_SECS
+                    .saturating_mul(1 << (entry.consecutive_failures - 1).min(16))
+                    .min(TAB_RETRY_MAX_SECS);
+                let now = SystemTime::now();
+                warn!(
+                    "tab {} failed ({} in a row), retrying in {}s: {}",
+                    tab_id, entry.consecutive_failures, backoff, error
+                );
+                entry.last_error = Some(error);
+                entry.last_failure_at = Some(now);
+                entry.retry_at = Some(now + Duration::from_secs(backoff));
+                entry.consecutive_failures
+            }
+        }
+    }
+
+    /// Whether a tab failed recently and is still waiting for its retry
+    async fn is_backing_off(&self, tab_id: &str) -> bool {
+        self.health
+            .lock()
+            .await
+            .get(tab_id)
+            .and_then(|h| h.retry_at)
+            .is_some_and(|retry_at| retry_at > SystemTime::now())
+    }
+
+    /// Activate the first tab, starting at `start`, that is not backing off
+    /// and activates without error. Returns the index of the activated tab.
+    async fn activate_first_available(
+        &self,
+        playlist_id: &str,
+        tabs: &[TabWithOrder],
+        start: usize,
+        app_state: &Arc<AppState>,
+    ) -> Result<usize> {
+        let mut last_error = None;
+        for offset in 0..tabs.len() {
+            let idx = (start + offset) % tabs.len();
+            let tab = &tabs[idx];
+            if tabs.len() > 1 && self.is_backing_off(&tab.id).await {
+                info!("skipping unhealthy tab {}", tab.id);
+                continue;
+            }
+            match self
+                .activate_tab(
+                    tab.id.clone(),
+                    playlist_id.to_string(),
+                    Some(tab.entry_id.clone()),
+                    app_state,
+                )
+                .await
+            {
+                Ok(()) => return Ok(idx),
+                Err(e) => last_error = Some(e),
+            }
+        }
+        Err(last_error
+            .unwrap_or_else(|| anyhow!("No healthy tabs available in playlist {}", playlist_id)))
+    }
+
+    pub async fn get_tab_health(&self, tab_id: &str) -> Option<TabHealth> {
+        self.health.lock().await.get(tab_id).cloned()
+    }
+
+    /// Show a tab of a playlist. Without `entry_id` the visible entry is kept
+    /// if it shows the same tab, otherwise the first entry of the tab is used.
+    async fn show_tab(
+        &self,
+        tab_id: String,
+        playlist_id: String,
+        entry_id: Option<String>,
         app_state: &Arc<AppState>,
     ) -> Result<()> {
         let tab = app_state
@@ -378,7 +735,7 @@ impl ChromeController {
             tab_id, playlist_id, has_page, tab.url
         );
         if !has_page {
-            self.create_tab_page(&tab_id, &tab.url, app_state).await?;
+            self.create_tab_page(&tab, app_state).await?;
         }
 
         let page = {
@@ -388,14 +745,29 @@ impl ChromeController {
                 .cloned()
                 .ok_or_else(|| anyhow!("No page for tab {}", tab_id))?
         };
-        page.bring_to_front().await?;
+        self.show_page(&page, &tab_id, app_state).await?;
 
-        {
+        let entry = {
             let mut st = self.state.lock().await;
+            let entry_id = entry_id.or_else(|| st.current_entry_id.clone());
+            let entry = entry_id
+                .and_then(|id| tabs_for_playlist.iter().find(|t| t.entry_id == id))
+                .filter(|t| t.id == tab_id)
+                .or_else(|| tabs_for_playlist.iter().find(|t| t.id == tab_id));
             st.current_tab_id = Some(tab_id.clone());
+            st.current_entry_id = entry.map(|t| t.entry_id.clone());
+            st.current_duration_seconds = entry
+                .and_then(|t| t.duration_seconds)
+                .filter(|d| *d > 0)
+                .map(|d| d as u64);
             st.current_playlist_id = Some(playlist_id.clone());
             st.current_tab_opened_at = Some(std::time::SystemTime::now());
+            entry
+        };
+        if let Some(entry) = entry {
+            self.rotation.lock().await.record(&entry.entry_id);
         }
+        self.start_play(&tab_id, &playlist_id, app_state).await;
 
         app_state
             .hass
@@ -404,21 +776,155 @@ impl ChromeController {
             .hass
             .url_entity
             .update_state(&app_state.hass.mqtt_client, &tab.url);
+        app_state
+            .events
+            .publish(DomainEvent::TabActivated(TabActivatedEvent {
+                tab_id,
+                playlist_id,
+                url: tab.url,
+            }));
 
         Ok(())
     }
 
-    async fn create_tab_page(
-        &self,
-        tab_id: &str,
-        url: &str,
-        app_state: &Arc<AppState>,
-    ) -> Result<()> {
+    /// Log that a tab became visible, ending the play of the previous one.
+    /// Showing the visible tab again continues its play.
+    async fn start_play(&self, tab_id: &str, playlist_id: &str, app_state: &Arc<AppState>) {
+        let mut current = self.current_play.lock().await;
+        if let Some(play) = current.as_ref() {
+            if play.tab_id == tab_id && play.playlist_id == playlist_id {
+                return;
+            }
+        }
+        let now = chrono::Utc::now();
+        if let Some(play) = current.take() {
+            if let Err(e) = app_state.play_log_repository.end(&play.id, now).await {
+                warn!("failed to end play of {}: {}", play.tab_id, e);
+            }
+        }
+
+        let play = PlayRecord {
+            id: uuid::Uuid::new_v4().to_string(),
+            tab_id: tab_id.to_string(),
+            playlist_id: playlist_id.to_string(),
+            trigger: *self.trigger.lock().await,
+            started_at: now,
+            ended_at: None,
+        };
+        match app_state.play_log_repository.start(&play).await {
+            Ok(()) => *current = Some(play),
+            Err(e) => warn!("failed to log play of {}: {}", tab_id, e),
+        }
+    }
+
+    /// Log that the visible tab is no longer shown
+    async fn end_play(&self, app_state: &Arc<AppState>) {
+        let Some(play) = self.current_play.lock().await.take() else {
+            return;
+        };
+        if let Err(e) = app_state
+            .play_log_repository
+            .end(&play.id, chrono::Utc::now())
+            .await
+        {
+            warn!("failed to end play of {}: {}", play.tab_id, e);
+        }
+    }
+
+    /// Bring a prepared page to front, waiting for it to be ready and
+    /// transitioning from the currently visible tab if configured.
+    async fn show_page(&self, page: &Page, tab_id: &str, app_state: &Arc<AppState>) -> Result<()> {
+        let transition = app_state
+            .config
+            .chromium
+            .as_ref()
+            .map(|c| c.transition.clone())
+            .unwrap_or_default();
+
+        let timeout = Duration::from_millis(transition.wait_timeout_ms);
+        if !transition::wait_until_ready(page, transition.wait_until, timeout).await {
+            warn!(
+                "tab {} not ready after {}ms, showing anyway",
+                tab_id, transition.wait_timeout_ms
+            );
+        }
+
+        let previous = self.state.lock().await.current_tab_id.clone();
+        let frame = match previous {
+            Some(prev) if prev != tab_id && transition.effect != TransitionEffect::None => {
+                self.outgoing_frame(&prev).await
+            }
+            _ => None,
+        };
+        let prepared = match &frame {
+            Some(frame) => match transition::prepare(page, &transition, frame).await {
+                Ok(()) => true,
+                Err(e) => {
+                    warn!("failed to prepare transition for {}: {}", tab_id, e);
+                    false
+                }
+            },
+            None => false,
+        };
+
+        page.bring_to_front().await?;
+
+        if prepared {
+            if let Err(e) = transition::run(page).await {
+                warn!("failed to run transition for {}: {}", tab_id, e);
+            }
+        }
+        self.render_overlays(page).await;
+        Ok(())
+    }
+
+    /// Base64 JPEG of the visible tab for a transition: the latest screencast
+    /// frame, or a screenshot taken now if screen capture is off
+    async fn outgoing_frame(&self, tab_id: &str) -> Option<Vec<u8>> {
+        if let Some(frame) = self.last_frame.lock().await.get(tab_id).cloned() {
+            return Some(frame);
+        }
+        let page = self.pages.lock().await.get(tab_id).cloned()?;
+        match Self::capture_jpeg(&page).await {
+            Ok(frame) => Some(frame),
+            Err(e) => {
+                warn!(
+                    "failed to capture {} for the transition, cutting: {}",
+                    tab_id, e
+                );
+                None
+            }
+        }
+    }
+
+    /// Base64 JPEG screenshot of a page. Not `Page::screenshot`, which would
+    /// bring the page to front.
+    async fn capture_jpeg(page: &Page) -> Result<Vec<u8>> {
+        let params = CaptureScreenshotParams::builder()
+            .format(CaptureScreenshotFormat::Jpeg)
+            .quality(80)
+            .build();
+        let screenshot = page.execute(params).await?;
+        let data: &[u8] = screenshot.result.data.as_ref();
+        Ok(data.to_vec())
+    }
+
+    async fn create_tab_page(&self, tab: &Tab, app_state: &Arc<AppState>) -> Result<()> {
+        let tab_id = tab.id.as_str();
         let browser = {
             let b = self.browser.lock().await;
             b.clone().ok_or_else(|| anyhow!("Browser not ready"))?
         };
-        let page = browser.new_page(url).await?;
+        // Created in the background, `show_page` brings it to front when its turn comes
+        let page = browser
+            .new_page(
+                CreateTargetParams::builder()
+                    .url(page_url(tab, &app_state.config.device)?)
+                    .background(true)
+                    .build()
+                    .map_err(|e| anyhow!(e))?,
+            )
+            .await?;
         // Suppress automation banner / webdriver detection
         let _ = page
             .execute(
@@ -437,19 +943,44 @@ impl ChromeController {
             .insert(tab_id.to_string(), page_arc.clone());
         info!("create_tab_page: stored page for {}", tab_id);
 
+        self.watch_for_crash(&page_arc, tab_id, app_state).await;
+        if app_state
+            .config
+            .chromium
+            .as_ref()
+            .is_some_and(|c| c.interaction.enabled)
+        {
+            self.watch_for_input(&page_arc, tab_id).await;
+        }
+
+        // Emulate the tab's target viewport before capturing, so previews match
+        let window = emulation::measure_window(&page_arc).await;
+        let target = ViewportTarget::from_tab(tab);
+        if let Some(target) = &target {
+            let screen = window.unwrap_or((0, 0));
+            info!(
+                "create_tab_page: emulating {}x{} ({:?}) on {}x{} for {}",
+                target.width, target.height, target.mode, screen.0, screen.1, tab_id
+            );
+            if let Err(e) = emulation::apply(&page_arc, target, screen).await {
+                warn!("viewport emulation failed for {}: {}", tab_id, e);
+            }
+        }
+
         if *self.should_screen_capture.lock().await {
             let page_ref = page_arc.clone();
             let frames = self.last_frame.clone();
+            let frames_at = self.last_frame_at.clone();
             let tab_key = tab_id.to_string();
+            let mut params = StartScreencastParams::builder()
+                .format(StartScreencastFormat::Jpeg)
+                .quality(80);
+            if let Some(target) = &target {
+                params = params.max_width(target.width).max_height(target.height);
+            }
+            let params = params.build();
             task::spawn(async move {
-                let started = page_ref
-                    .execute(
-                        StartScreencastParams::builder()
-                            .format(StartScreencastFormat::Jpeg)
-                            .quality(80)
-                            .build(),
-                    )
-                    .await;
+                let started = page_ref.execute(params).await;
                 if let Err(e) = started {
                     warn!("screencast start failed for {}: {:?}", tab_key, e);
                     return;
@@ -475,6 +1006,10 @@ impl ChromeController {
                         }
                         last = now;
                         frames.lock().await.insert(tab_key.clone(), buf.to_vec());
+                        frames_at
+                            .lock()
+                            .await
+                            .insert(tab_key.clone(), SystemTime::now());
                     }
                 } else {
                     warn!("screencast listener failed for {}", tab_key);
@@ -482,57 +1017,148 @@ impl ChromeController {
             });
         }
 
-        if let (Ok(w), Ok(h)) = (
-            page_arc.evaluate("window.innerWidth").await,
-            page_arc.evaluate("window.innerHeight").await,
-        ) {
-            if let (Some(wv), Some(hv)) = (w.value(), h.value()) {
-                if let (Some(wi), Some(hi)) = (wv.as_u64(), hv.as_u64()) {
-                    self.viewport
-                        .lock()
-                        .await
-                        .insert(tab_id.to_string(), (wi as i32, hi as i32));
-                    let app = app_state.clone();
-                    let tab = tab_id.to_string();
-                    task::spawn(async move {
-                        let _ = app
-                            .tab_repository
-                            .update_viewport_dimensions(&tab, wi as i32, hi as i32)
-                            .await;
-                    });
-                }
-            }
+        if let Some((wi, hi)) = target.map(|t| (t.width, t.height)).or(window) {
+            self.viewport
+                .lock()
+                .await
+                .insert(tab_id.to_string(), (wi, hi));
+            let app = app_state.clone();
+            let tab = tab_id.to_string();
+            task::spawn(async move {
+                let _ = app
+                    .tab_repository
+                    .update_viewport_dimensions(&tab, wi, hi)
+                    .await;
+            });
         }
 
         Ok(())
     }
 
-    async fn next_tab(&self, app_state: &Arc<AppState>) -> Result<()> {
-        let (playlist_id, current_tab_id, current_index) = {
+    /// Drop the page of a tab when its renderer crashes, so it is recreated
+    /// on its next activation, and publish the crash
+    async fn watch_for_crash(&self, page: &Arc<Page>, tab_id: &str, app_state: &Arc<AppState>) {
+        if let Err(e) = page.execute(inspector::EnableParams::default()).await {
+            warn!("failed to enable inspector for {}: {}", tab_id, e);
+            return;
+        }
+        let Ok(mut crashes) = page.event_listener::<EventTargetCrashed>().await else {
+            warn!("crash listener failed for {}", tab_id);
+            return;
+        };
+        let pages = self.pages.clone();
+        let events = app_state.events.clone();
+        let tab_id = tab_id.to_string();
+        task::spawn(async move {
+            if crashes.next().await.is_some() {
+                error!("tab {} crashed", tab_id);
+                pages.lock().await.remove(&tab_id);
+                events.publish(DomainEvent::TabCrashed(TabCrashedEvent { tab_id }));
+            }
+        });
+    }
+
+    /// Report input in the page of a tab to the controller
+    async fn watch_for_input(&self, page: &Arc<Page>, tab_id: &str) {
+        let mut inputs = match interaction::watch(page).await {
+            Ok(inputs) => Box::pin(inputs),
+            Err(e) => {
+                warn!("input listener failed for {}: {}", tab_id, e);
+                return;
+            }
+        };
+        let sender = self.message_sender.clone();
+        let tab_id = tab_id.to_string();
+        task::spawn(async move {
+            while inputs.next().await.is_some() {
+                let message = ChromeMessage::UserInteraction {
+                    tab_id: tab_id.clone(),
+                };
+                if sender.clone().send(message.into()).await.is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    /// Returns the active playlist, its enabled tabs and the index of the
+    /// current and upcoming tab in rotation order
+    async fn upcoming_tab(&self, app_state: &Arc<AppState>) -> Result<Option<UpcomingTab>> {
+        let (playlist_id, current_entry_id, current_tab_id, current_index) = {
             let st = self.state.lock().await;
             (
                 st.current_playlist_id.clone(),
+                st.current_entry_id.clone(),
                 st.current_tab_id.clone(),
                 st.current_tab_index,
             )
         };
         let Some(pid) = playlist_id else {
-            return Ok(());
+            return Ok(None);
         };
         let tabs = Self::get_enabled_tabs(app_state, &pid).await?;
         if tabs.is_empty() {
-            return Ok(());
+            return Ok(None);
         }
+        let mode = app_state
+            .playlist_repository
+            .get_by_id(&pid)
+            .await?
+            .map(|p| p.rotation_mode)
+            .unwrap_or_default();
 
-        let idx = current_tab_id
+        let current = current_entry_id
             .as_ref()
-            .and_then(|tid| tabs.iter().position(|t| &t.id == tid))
+            .and_then(|eid| tabs.iter().position(|t| &t.entry_id == eid))
+            .or_else(|| {
+                current_tab_id
+                    .as_ref()
+                    .and_then(|tid| tabs.iter().position(|t| &t.id == tid))
+            })
             .unwrap_or(current_index)
             % tabs.len();
-        let next = (idx + 1) % tabs.len();
-        info!("next_tab: playlist {}, idx {} -> {}", pid, idx, next);
-        self.activate_tab(tabs[next].id.clone(), pid, app_state)
+        let next = self.rotation.lock().await.upcoming(mode, &tabs, current);
+        Ok(Some(UpcomingTab {
+            playlist_id: pid,
+            mode,
+            tabs,
+            current,
+            next,
+        }))
+    }
+
+    async fn next_tab(&self, app_state: &Arc<AppState>) -> Result<()> {
+        let Some(UpcomingTab {
+            playlist_id: pid,
+            mode,
+            tabs,
+            current: idx,
+            mut next,
+        }) = self.upcoming_tab(app_state).await?
+        else {
+            return Ok(());
+        };
+
+        // Skip a tab that was preloaded but did not finish loading in time
+        if tabs.len() > 1 && self.preloaded.lock().await.remove(&tabs[next].id) == Some(false) {
+            warn!(
+                "next_tab: tab {} failed to preload, skipping",
+                tabs[next].id
+            );
+            self.record_tab_health(
+                &tabs[next].id,
+                Some("Did not finish loading before its turn".to_string()),
+            )
+            .await;
+            let mut rotation = self.rotation.lock().await;
+            rotation.skip_upcoming();
+            next = rotation.upcoming(mode, &tabs, next);
+        }
+
+        let next = self
+            .activate_first_available(&pid, &tabs, next, app_state)
             .await?;
+        info!("next_tab: playlist {}, idx {} -> {}", pid, idx, next);
         {
             let mut st = self.state.lock().await;
             st.current_tab_index = next;
@@ -540,6 +1166,77 @@ impl ChromeController {
         Ok(())
     }
 
+    /// Create or refresh the tab that comes up next in rotation, so it is
+    /// fully loaded by the time it is shown
+    async fn preload_next_tab(&self, app_state: &Arc<AppState>) -> Result<()> {
+        let Some(upcoming) = self.upcoming_tab(app_state).await? else {
+            return Ok(());
+        };
+        let tab_id = upcoming.tabs[upcoming.next].id.clone();
+        let timeout = Duration::from_secs(Self::preload_seconds(app_state).max(1));
+        info!("preload_next_tab: preparing {}", tab_id);
+
+        let ready =
+            match async_std::future::timeout(timeout, self.prepare_tab_page(&tab_id, app_state))
+                .await
+            {
+                Ok(Ok(ready)) => ready,
+                Ok(Err(e)) => {
+                    warn!("preload_next_tab: failed to prepare {}: {}", tab_id, e);
+                    false
+                }
+                Err(_) => {
+                    warn!(
+                        "preload_next_tab: {} did not load within {:?}",
+                        tab_id, timeout
+                    );
+                    false
+                }
+            };
+        self.preloaded.lock().await.insert(tab_id, ready);
+        Ok(())
+    }
+
+    /// Make sure a page exists for the tab and has (re)loaded its content
+    async fn prepare_tab_page(&self, tab_id: &str, app_state: &Arc<AppState>) -> Result<bool> {
+        let tab = app_state
+            .tab_repository
+            .get_by_id(tab_id)
+            .await?
+            .ok_or_else(|| anyhow!("Tab {} not found", tab_id))?;
+
+        let existing = self.pages.lock().await.get(tab_id).cloned();
+        let page = match existing {
+            Some(page) => {
+                if !tab.persist {
+                    page.reload().await?;
+                }
+                page
+            }
+            None => {
+                self.create_tab_page(&tab, app_state).await?;
+                self.pages
+                    .lock()
+                    .await
+                    .get(tab_id)
+                    .cloned()
+                    .ok_or_else(|| anyhow!("No page for tab {}", tab_id))?
+            }
+        };
+
+        let timeout = Duration::from_secs(Self::preload_seconds(app_state).max(1));
+        Ok(transition::wait_until_ready(&page, WaitUntil::Load, timeout).await)
+    }
+
+    fn preload_seconds(app_state: &Arc<AppState>) -> u64 {
+        app_state
+            .config
+            .chromium
+            .as_ref()
+            .and_then(|c| c.preload_seconds)
+            .unwrap_or(0)
+    }
+
     async fn previous_tab(&self, app_state: &Arc<AppState>) -> Result<()> {
         let (playlist_id, current_index) = {
             let st = self.state.lock().await;
@@ -553,17 +1250,24 @@ impl ChromeController {
             return Ok(());
         }
 
-        let prev = if current_index == 0 {
-            tabs.len() - 1
-        } else {
-            current_index - 1
+        // Walk back through the tabs actually shown, the playlist order is
+        // only used when there is no history (yet)
+        let prev = match self.rotation.lock().await.previous(&tabs) {
+            Some(prev) => prev,
+            None if current_index == 0 => tabs.len() - 1,
+            None => (current_index - 1).min(tabs.len() - 1),
         };
         info!(
             "prev_tab: playlist {}, idx {} -> {}",
             pid, current_index, prev
         );
-        self.activate_tab(tabs[prev].id.clone(), pid, app_state)
-            .await?;
+        self.activate_tab(
+            tabs[prev].id.clone(),
+            pid,
+            Some(tabs[prev].entry_id.clone()),
+            app_state,
+        )
+        .await?;
         {
             let mut st = self.state.lock().await;
             st.current_tab_index = prev;
@@ -571,24 +1275,61 @@ impl ChromeController {
         Ok(())
     }
 
-    async fn start_auto_rotation(&self, interval_seconds: i64) -> Result<()> {
+    async fn start_auto_rotation(
+        &self,
+        interval_seconds: i64,
+        app_state: &Arc<AppState>,
+    ) -> Result<()> {
         if interval_seconds <= 0 {
             return Ok(());
         }
         self.stop_auto_rotation().await;
+        self.clear_hold().await;
         {
             let mut st = self.state.lock().await;
             st.auto_rotate = true;
         }
+        self.publish_rotation_state(app_state).await;
+        let preload_seconds = Self::preload_seconds(app_state);
         let sender = self.message_sender.clone();
         let state = self.state.clone();
         let handle = task::spawn(async move {
             loop {
-                task::sleep(Duration::from_secs(interval_seconds as u64)).await;
+                // Entries may override how long they are shown
+                let interval = state
+                    .lock()
+                    .await
+                    .current_duration_seconds
+                    .unwrap_or(interval_seconds as u64);
+                // Leave at least one second of display time before preloading
+                let preload = preload_seconds.min(interval.saturating_sub(1));
+                task::sleep(Duration::from_secs(interval - preload)).await;
                 if !state.lock().await.auto_rotate {
                     break;
                 }
-                let _ = sender.clone().send(ChromeMessage::NextTab).await;
+                if preload > 0 {
+                    let _ = sender
+                        .clone()
+                        .send(ChromeRequest::with_trigger(
+                            ChromeMessage::PreloadNextTab,
+                            PlayTrigger::Rotation,
+                        ))
+                        .await;
+                    task::sleep(Duration::from_secs(preload)).await;
+                    if !state.lock().await.auto_rotate {
+                        break;
+                    }
+                }
+                // Wait until the next tab is shown before timing it
+                let (reply, shown) = oneshot::channel();
+                let request = ChromeRequest {
+                    message: ChromeMessage::NextTab,
+                    reply: Some(reply),
+                    trigger: PlayTrigger::Rotation,
+                };
+                if sender.clone().send(request).await.is_ok() {
+                    let _ = shown.await;
+                }
             }
         });
         *self.auto_task.lock().await = Some(handle);
@@ -605,25 +1346,482 @@ impl ChromeController {
         }
     }
 
+    /// Start rotation of the current playlist again, or let the running
+    /// takeover resume it once it ends
+    async fn resume_rotation(&self, app_state: &Arc<AppState>) -> Result<()> {
+        if let Some(takeover) = &mut self.state.lock().await.takeover {
+            takeover.resume_rotation = true;
+        }
+        if self.has_takeover().await {
+            self.publish_rotation_state(app_state).await;
+            return Ok(());
+        }
+        if let Some(pid) = self.state.lock().await.current_playlist_id.clone() {
+            if let Some(pl) = app_state.playlist_repository.get_by_id(&pid).await? {
+                self.start_auto_rotation(pl.interval_seconds, app_state)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Pause rotation on the current tab and resume it after `duration_seconds`.
+    /// A new hold replaces the running one.
+    async fn hold_tab(&self, duration_seconds: u64, app_state: &Arc<AppState>) -> Result<()> {
+        if duration_seconds == 0 {
+            return Err(anyhow!("Hold duration must be positive"));
+        }
+        if self.has_takeover().await {
+            return Err(anyhow!("A takeover is active"));
+        }
+        if self.state.lock().await.current_playlist_id.is_none() {
+            return Err(anyhow!("No playlist is active"));
+        }
+        info!("holding the current tab for {}s", duration_seconds);
+        self.start_hold(duration_seconds, false).await;
+        self.publish_rotation_state(app_state).await;
+        Ok(())
+    }
+
+    /// Hold the visible tab while someone uses it. Input only pauses a
+    /// running rotation and never shortens a hold set through the API.
+    async fn user_interaction(&self, tab_id: &str, app_state: &Arc<AppState>) {
+        let Some(config) = app_state
+            .config
+            .chromium
+            .as_ref()
+            .map(|c| &c.interaction)
+            .filter(|c| c.enabled)
+        else {
+            return;
+        };
+        let interacting = {
+            let st = self.state.lock().await;
+            if st.current_tab_id.as_deref() != Some(tab_id) || st.takeover.is_some() {
+                return;
+            }
+            match &st.hold {
+                Some(hold) if hold.interaction => true,
+                Some(_) => return,
+                None if st.auto_rotate => false,
+                None => return,
+            }
+        };
+        if !interacting {
+            info!("input on {}, pausing rotation", tab_id);
+        }
+        self.start_hold(config.idle_timeout_seconds.max(1), true)
+            .await;
+    }
+
+    /// Stop rotation and schedule its resume, replacing a running hold
+    async fn start_hold(&self, duration_seconds: u64, interaction: bool) {
+        self.stop_auto_rotation().await;
+        self.clear_hold().await;
+
+        let now = SystemTime::now();
+        let sender = self.message_sender.clone();
+        let handle = task::spawn(async move {
+            task::sleep(Duration::from_secs(duration_seconds)).await;
+            let _ = sender
+                .clone()
+                .send(ChromeRequest::with_trigger(
+                    ChromeMessage::ExpireHold,
+                    PlayTrigger::Rotation,
+                ))
+                .await;
+        });
+        *self.hold_task.lock().await = Some(handle);
+        self.state.lock().await.hold = Some(Hold {
+            started_at: now,
+            expires_at: now + Duration::from_secs(duration_seconds),
+            interaction,
+        });
+    }
+
+    /// Drop the hold and cancel its timer, without resuming rotation
+    async fn clear_hold(&self) {
+        if self.state.lock().await.hold.take().is_none() {
+            return;
+        }
+        if let Some(handle) = self.hold_task.lock().await.take() {
+            let _ = handle.cancel().await;
+        }
+    }
+
+    /// Report on the Home Assistant switch whether the playlist rotates,
+    /// counting a takeover that resumes rotation when it ends
+    async fn publish_rotation_state(&self, app_state: &Arc<AppState>) {
+        let rotating = {
+            let st = self.state.lock().await;
+            st.auto_rotate
+                || st.takeover.as_ref().is_some_and(|t| t.resume_rotation)
+                || st.hold.as_ref().is_some_and(|h| h.interaction)
+        };
+        app_state.hass.rotation_entity.update_state(
+            &app_state.hass.mqtt_client,
+            if rotating { "ON" } else { "OFF" },
+        );
+    }
+
+    async fn has_takeover(&self) -> bool {
+        self.state.lock().await.takeover.is_some()
+    }
+
+    /// Open `url` on top of everything, suspend rotation and schedule the
+    /// restore of the current playlist and tab. A running takeover is replaced
+    /// but keeps the playlist state it interrupted.
+    async fn start_takeover(
+        &self,
+        url: String,
+        duration_seconds: u64,
+        app_state: &Arc<AppState>,
+    ) -> Result<()> {
+        let browser = self
+            .browser
+            .lock()
+            .await
+            .clone()
+            .ok_or_else(|| anyhow!("Browser not running"))?;
+        let replaced = self.clear_takeover().await;
+
+        let now = SystemTime::now();
+        let takeover = {
+            let st = self.state.lock().await;
+            Takeover {
+                id: uuid::Uuid::new_v4().to_string(),
+                url: url.clone(),
+                started_at: now,
+                expires_at: now + Duration::from_secs(duration_seconds),
+                previous_playlist_id: match &replaced {
+                    Some(t) => t.previous_playlist_id.clone(),
+                    None => st.current_playlist_id.clone(),
+                },
+                previous_tab_id: match &replaced {
+                    Some(t) => t.previous_tab_id.clone(),
+                    None => st.current_tab_id.clone(),
+                },
+                resume_rotation: replaced
+                    .as_ref()
+                    .map_or(st.auto_rotate, |t| t.resume_rotation),
+            }
+        };
+        self.stop_auto_rotation().await;
+
+        info!("takeover: showing {} for {}s", url, duration_seconds);
+        let page = browser.new_page(url.as_str()).await?;
+        page.bring_to_front().await?;
+        self.render_overlays(&page).await;
+        *self.takeover_page.lock().await = Some(Arc::new(page));
+        self.end_play(app_state).await;
+
+        let sender = self.message_sender.clone();
+        let takeover_id = takeover.id.clone();
+        let handle = task::spawn(async move {
+            task::sleep(Duration::from_secs(duration_seconds)).await;
+            let _ = sender
+                .clone()
+                .send(ChromeRequest::with_trigger(
+                    ChromeMessage::ExpireTakeover { takeover_id },
+                    PlayTrigger::Rotation,
+                ))
+                .await;
+        });
+        *self.takeover_task.lock().await = Some(handle);
+        self.state.lock().await.takeover = Some(takeover);
+
+        app_state
+            .hass
+            .url_entity
+            .update_state(&app_state.hass.mqtt_client, &url);
+        app_state
+            .events
+            .publish(DomainEvent::TakeoverStarted(TakeoverStartedEvent {
+                url,
+                duration_seconds,
+            }));
+        Ok(())
+    }
+
+    /// Close the takeover page and cancel its timer, without restoring anything
+    async fn clear_takeover(&self) -> Option<Takeover> {
+        let takeover = self.state.lock().await.takeover.take()?;
+        if let Some(handle) = self.takeover_task.lock().await.take() {
+            let _ = handle.cancel().await;
+        }
+        if let Some(page) = self.takeover_page.lock().await.take() {
+            if let Err(e) = (*page).clone().close().await {
+                warn!("failed to close takeover page: {}", e);
+            }
+        }
+        Some(takeover)
+    }
+
+    /// End the takeover and return to the playlist and tab it interrupted
+    async fn end_takeover(&self, expired: bool, app_state: &Arc<AppState>) -> Result<()> {
+        let Some(takeover) = self.clear_takeover().await else {
+            return Ok(());
+        };
+        info!("takeover of {} ended (expired: {})", takeover.url, expired);
+        app_state
+            .events
+            .publish(DomainEvent::TakeoverEnded(TakeoverEndedEvent {
+                url: takeover.url,
+                expired,
+            }));
+
+        let Some(playlist_id) = takeover.previous_playlist_id else {
+            return Ok(());
+        };
+        if let Some(tab_id) = takeover.previous_tab_id {
+            if let Err(e) = self
+                .activate_tab(tab_id, playlist_id.clone(), None, app_state)
+                .await
+            {
+                warn!("failed to restore tab after takeover: {}", e);
+            }
+        }
+        if takeover.resume_rotation {
+            if let Some(pl) = app_state
+                .playlist_repository
+                .get_by_id(&playlist_id)
+                .await?
+            {
+                self.start_auto_rotation(pl.interval_seconds, app_state)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Explicit navigation replaces a running takeover without restoring
+    async fn override_takeover(&self, app_state: &Arc<AppState>) {
+        if let Some(takeover) = self.clear_takeover().await {
+            info!("takeover of {} overridden", takeover.url);
+            app_state
+                .events
+                .publish(DomainEvent::TakeoverEnded(TakeoverEndedEvent {
+                    url: takeover.url,
+                    expired: false,
+                }));
+        }
+    }
+
     async fn reload_current_tab(&self) -> Result<()> {
         if let Some(tab) = self.state.lock().await.current_tab_id.clone() {
-            if let Some(page) = self.pages.lock().await.get(&tab) {
+            let page = self.pages.lock().await.get(&tab).cloned();
+            if let Some(page) = page {
                 page.reload().await?;
+                self.render_overlays(&page).await;
             }
         }
         Ok(())
     }
 
+    /// Page currently on screen: the takeover if one is running, else the current tab
+    async fn visible_page(&self) -> Option<Arc<Page>> {
+        if let Some(page) = self.takeover_page.lock().await.clone() {
+            return Some(page);
+        }
+        let tab_id = self.state.lock().await.current_tab_id.clone()?;
+        self.pages.lock().await.get(&tab_id).cloned()
+    }
+
+    /// Draw the active overlays onto a page, dropping expired ones first
+    async fn render_overlays(&self, page: &Page) {
+        let overlays = {
+            let mut overlays = self.overlays.lock().await;
+            overlays.retain(|o| !o.is_expired());
+            overlays.clone()
+        };
+        if let Err(e) = overlay::render(page, &overlays).await {
+            warn!("failed to render overlays: {}", e);
+        }
+    }
+
+    async fn refresh_visible_overlays(&self) {
+        if let Some(page) = self.visible_page().await {
+            self.render_overlays(&page).await;
+        }
+    }
+
+    /// Add an overlay, replacing any overlay with the same id
+    async fn show_overlay(&self, overlay: Overlay) {
+        if let Some(expires_at) = overlay.expires_at {
+            let delay = expires_at
+                .duration_since(SystemTime::now())
+                .unwrap_or_default();
+            let sender = self.message_sender.clone();
+            task::spawn(async move {
+                task::sleep(delay).await;
+                let _ = sender
+                    .clone()
+                    .send(ChromeMessage::ExpireOverlays.into())
+                    .await;
+            });
+        }
+        {
+            let mut overlays = self.overlays.lock().await;
+            overlays.retain(|o| o.id != overlay.id);
+            overlays.push(overlay);
+        }
+        self.refresh_visible_overlays().await;
+    }
+
+    async fn hide_overlay(&self, overlay_id: &str) -> Result<()> {
+        {
+            let mut overlays = self.overlays.lock().await;
+            let count = overlays.len();
+            overlays.retain(|o| o.id != overlay_id);
+            if overlays.len() == count {
+                return Err(anyhow!("Overlay {} not found", overlay_id));
+            }
+        }
+        self.refresh_visible_overlays().await;
+        Ok(())
+    }
+
+    pub async fn get_overlays(&self) -> Vec<Overlay> {
+        let mut overlays = self.overlays.lock().await.clone();
+        overlays.retain(|o| !o.is_expired());
+        overlays
+    }
+
     async fn update_tab_url(
         &self,
         tab_id: String,
         url: String,
         app_state: &Arc<AppState>,
     ) -> Result<()> {
+        // Reject URLs whose variables cannot be resolved before storing them
+        template::render(&url, &app_state.config.device)?;
         app_state.tab_repository.update_url(&tab_id, &url).await?;
-        if let Some(page) = self.pages.lock().await.get(&tab_id) {
-            page.execute(NavigateParams::builder().url(url.clone()).build().unwrap())
+        self.tab_updated(&tab_id, false, app_state).await
+    }
+
+    /// Navigate the open page of an edited tab to its current URL or content
+    async fn tab_updated(
+        &self,
+        tab_id: &str,
+        viewport_changed: bool,
+        app_state: &Arc<AppState>,
+    ) -> Result<()> {
+        let Some(tab) = app_state.tab_repository.get_by_id(tab_id).await? else {
+            return Ok(());
+        };
+        let page = self.pages.lock().await.get(tab_id).cloned();
+        if page.is_some() && viewport_changed {
+            // The emulation and the screencast size are set up with the page
+            info!("tab {} viewport changed, recreating its page", tab_id);
+            self.recreate_tab(tab_id.to_string(), app_state).await?;
+        } else if let Some(page) = page {
+            // Media tabs keep loading the internal content route, which picks up the new URL
+            let url = page_url(&tab, &app_state.config.device)?;
+            info!("tab {} changed, navigating its page to {}", tab_id, url);
+            page.execute(NavigateParams::builder().url(url).build().unwrap())
                 .await?;
+            // Navigating drops the injected overlays
+            if let Some(visible) = self.visible_page().await {
+                if Arc::ptr_eq(&visible, &page) {
+                    page.wait_for_navigation().await?;
+                    self.render_overlays(&page).await;
+                }
+            }
+        }
+        if self.state.lock().await.current_tab_id.as_deref() == Some(tab_id) {
+            app_state
+                .hass
+                .url_entity
+                .update_state(&app_state.hass.mqtt_client, &tab.url);
+        }
+        Ok(())
+    }
+
+    /// Bring the running rotation in line with an edited playlist: follow
+    /// the new order, move off a visible tab that was removed or disabled
+    /// and pick up a changed interval
+    async fn playlist_updated(&self, playlist_id: &str, app_state: &Arc<AppState>) -> Result<()> {
+        let (current_playlist_id, current_tab_id, current_entry_id, current_index, auto_rotate) = {
+            let st = self.state.lock().await;
+            (
+                st.current_playlist_id.clone(),
+                st.current_tab_id.clone(),
+                st.current_entry_id.clone(),
+                st.current_tab_index,
+                st.auto_rotate,
+            )
+        };
+        if current_playlist_id.as_deref() != Some(playlist_id) {
+            return Ok(());
+        }
+        let Some(playlist) = app_state.playlist_repository.get_by_id(playlist_id).await? else {
+            info!("active playlist {} was deleted", playlist_id);
+            self.stop_auto_rotation().await;
+            self.state.lock().await.current_playlist_id = None;
+            return self.ensure_active_playlist(app_state).await;
+        };
+
+        let tabs = Self::get_enabled_tabs(app_state, playlist_id).await?;
+        app_state
+            .hass
+            .publish_tab_options(&tabs, current_tab_id.as_deref());
+        app_state.hass.interval_entity.update_state(
+            &app_state.hass.mqtt_client,
+            &playlist.interval_seconds.to_string(),
+        );
+        self.rotation.lock().await.skip_upcoming();
+        self.preloaded.lock().await.clear();
+        if tabs.is_empty() {
+            warn!("Playlist {} has no enabled tabs left", playlist_id);
+            self.stop_auto_rotation().await;
+            return Ok(());
+        }
+        // The takeover restores the playlist once it ends
+        if self.has_takeover().await {
+            return Ok(());
+        }
+
+        let position = match &current_entry_id {
+            Some(entry_id) => tabs.iter().position(|t| &t.entry_id == entry_id),
+            None => current_tab_id
+                .as_ref()
+                .and_then(|tab_id| tabs.iter().position(|t| &t.id == tab_id)),
+        };
+        let index = match position {
+            Some(index) => index,
+            None => {
+                info!(
+                    "visible tab {:?} left playlist {}, moving on",
+                    current_tab_id, playlist_id
+                );
+                let start = current_index.min(tabs.len() - 1);
+                self.activate_first_available(playlist_id, &tabs, start, app_state)
+                    .await?
+            }
+        };
+        self.state.lock().await.current_tab_index = index;
+
+        if auto_rotate {
+            self.start_auto_rotation(playlist.interval_seconds, app_state)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Move rotation off a deleted tab, then close its page
+    async fn tab_deleted(&self, tab_id: String, app_state: &Arc<AppState>) -> Result<()> {
+        let playlist_id = self.state.lock().await.current_playlist_id.clone();
+        if let Some(playlist_id) = playlist_id {
+            self.playlist_updated(&playlist_id, app_state).await?;
+        }
+        self.health.lock().await.remove(&tab_id);
+        self.preloaded.lock().await.remove(&tab_id);
+        let page = self.pages.lock().await.get(&tab_id).cloned();
+        self.close_tab(tab_id.clone()).await?;
+        if let Some(page) = page {
+            if let Err(e) = (*page).clone().close().await {
+                warn!("failed to close page of deleted tab {}: {}", tab_id, e);
+            }
         }
         Ok(())
     }
@@ -633,6 +1831,7 @@ impl ChromeController {
             let _ = page.clone(); // drop to close later when browser exits
         }
         self.last_frame.lock().await.remove(&tab_id);
+        self.last_frame_at.lock().await.remove(&tab_id);
         self.viewport.lock().await.remove(&tab_id);
         Ok(())
     }
@@ -651,11 +1850,24 @@ impl ChromeController {
             .get_by_id(&tab_id)
             .await?
             .ok_or_else(|| anyhow!("Tab {} missing", tab_id))?;
-        self.create_tab_page(&tab.id, &tab.url, app_state).await
+        self.create_tab_page(&tab, app_state).await?;
+
+        // Pages are created in the background, so show the visible tab again
+        let visible = {
+            let st = self.state.lock().await;
+            st.takeover.is_none() && st.current_tab_id.as_deref() == Some(tab_id.as_str())
+        };
+        let page = self.pages.lock().await.get(&tab_id).cloned();
+        if let (true, Some(page)) = (visible, page) {
+            self.show_page(&page, &tab_id, app_state).await?;
+        }
+        Ok(())
     }
 
     async fn shutdown(&self) -> Result<()> {
         self.stop_auto_rotation().await;
+        self.clear_takeover().await;
+        self.clear_hold().await;
         let pages = self.pages.lock().await.drain().collect::<Vec<_>>();
         for (_, page) in pages {
             let _ = page.clone();
@@ -668,7 +1880,27 @@ impl ChromeController {
         self.last_frame.lock().await.get(tab_id).cloned()
     }
 
+    /// Base64 JPEG of the running takeover, which has no screencast
+    pub async fn get_takeover_screenshot(&self) -> Option<Vec<u8>> {
+        let page = self.takeover_page.lock().await.clone()?;
+        match Self::capture_jpeg(&page).await {
+            Ok(frame) => Some(frame),
+            Err(e) => {
+                warn!("failed to capture the takeover: {}", e);
+                None
+            }
+        }
+    }
+
     pub async fn get_viewport_dimensions(&self, tab_id: &str) -> Option<(i32, i32)> {
         self.viewport.lock().await.get(tab_id).cloned()
     }
+
+    pub async fn get_last_frame_time(&self, tab_id: &str) -> Option<SystemTime> {
+        self.last_frame_at.lock().await.get(tab_id).cloned()
+    }
+
+    pub async fn has_page(&self, tab_id: &str) -> bool {
+        self.pages.lock().await.contains_key(tab_id)
+    }
 }
@@ -0,0 +1,114 @@
+use anyhow::Result;
+use chromiumoxide::{cdp::browser_protocol::emulation::SetDeviceMetricsOverrideParams, Page};
+
+use crate::db::models::{ScalingMode, Tab};
+
+/// Viewport a tab was designed for, and how to map it onto the display
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ViewportTarget {
+    pub width: i32,
+    pub height: i32,
+    pub mode: ScalingMode,
+}
+
+impl ViewportTarget {
+    /// Returns the target viewport of a tab, if both dimensions are configured
+    pub fn from_tab(tab: &Tab) -> Option<Self> {
+        match (tab.target_width, tab.target_height) {
+            (Some(width), Some(height)) if width > 0 && height > 0 => Some(Self {
+                width,
+                height,
+                mode: tab.scaling_mode.unwrap_or_default(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Scale factor applied to the emulated viewport for a given screen size
+    pub fn scale_for(&self, screen: (i32, i32)) -> f64 {
+        let (screen_width, screen_height) = screen;
+        if screen_width <= 0 || screen_height <= 0 {
+            return 1.0;
+        }
+        let sx = screen_width as f64 / self.width as f64;
+        let sy = screen_height as f64 / self.height as f64;
+        match self.mode {
+            ScalingMode::Fit => sx.min(sy),
+            ScalingMode::Fill => sx.max(sy),
+            ScalingMode::Native => 1.0,
+        }
+    }
+
+    fn device_metrics(&self, screen: (i32, i32)) -> SetDeviceMetricsOverrideParams {
+        SetDeviceMetricsOverrideParams::builder()
+            .width(self.width)
+            .height(self.height)
+            // 0 keeps the display's own device scale factor
+            .device_scale_factor(0.0)
+            .mobile(false)
+            .scale(self.scale_for(screen))
+            .screen_width(self.width)
+            .screen_height(self.height)
+            .build()
+            .expect("all required device metrics fields are set")
+    }
+}
+
+/// Measure the physical window size of a page (before any override)
+pub async fn measure_window(page: &Page) -> Option<(i32, i32)> {
+    let (w, h) = (
+        page.evaluate("window.innerWidth").await.ok()?,
+        page.evaluate("window.innerHeight").await.ok()?,
+    );
+    let wi = w.value()?.as_u64()?;
+    let hi = h.value()?.as_u64()?;
+    Some((wi as i32, hi as i32))
+}
+
+/// Apply the target viewport to a page via `Emulation.setDeviceMetricsOverride`
+pub async fn apply(page: &Page, target: &ViewportTarget, screen: (i32, i32)) -> Result<()> {
+    page.execute(target.device_metrics(screen)).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(mode: ScalingMode) -> ViewportTarget {
+        ViewportTarget {
+            width: 1920,
+            height: 1080,
+            mode,
+        }
+    }
+
+    #[test]
+    fn fit_uses_the_smaller_scale() {
+        assert_eq!(
+            target(ScalingMode::Fit).scale_for((1280, 1024)),
+            1280.0 / 1920.0
+        );
+        assert_eq!(target(ScalingMode::Fit).scale_for((3840, 2160)), 2.0);
+    }
+
+    #[test]
+    fn fill_uses_the_larger_scale() {
+        assert_eq!(
+            target(ScalingMode::Fill).scale_for((1280, 1024)),
+            1024.0 / 1080.0
+        );
+        assert_eq!(target(ScalingMode::Fill).scale_for((960, 540)), 0.5);
+    }
+
+    #[test]
+    fn native_does_not_scale() {
+        assert_eq!(target(ScalingMode::Native).scale_for((1280, 1024)), 1.0);
+    }
+
+    #[test]
+    fn unknown_screen_does_not_scale() {
+        assert_eq!(target(ScalingMode::Fit).scale_for((0, 0)), 1.0);
+        assert_eq!(target(ScalingMode::Fill).scale_for((1280, -1)), 1.0);
+    }
+}
@@ -0,0 +1,70 @@
+use anyhow::Result;
+use chromiumoxide::{
+    cdp::{
+        browser_protocol::page::AddScriptToEvaluateOnNewDocumentParams,
+        js_protocol::runtime::{AddBindingParams, EventBindingCalled},
+    },
+    Page,
+};
+use futures::{Stream, StreamExt};
+use serde::Deserialize;
+
+/// Pausing rotation while someone uses the visible page
+#[derive(Debug, Clone, Deserialize)]
+pub struct InteractionConfig {
+    /// Listen for touch, mouse and keyboard input in the visible page
+    #[serde(default)]
+    pub enabled: bool,
+    /// Resume rotation once there was no input for this long
+    #[serde(default = "default_idle_timeout_seconds")]
+    pub idle_timeout_seconds: u64,
+}
+
+impl Default for InteractionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            idle_timeout_seconds: default_idle_timeout_seconds(),
+        }
+    }
+}
+
+fn default_idle_timeout_seconds() -> u64 {
+    60
+}
+
+const BINDING_NAME: &str = "__missionControlInput";
+
+/// Reports input through the binding, at most once per second so a moving
+/// pointer does not flood the controller
+const LISTENER_SCRIPT: &str = r#"(() => {
+    if (window.__missionControlInputListener) return;
+    window.__missionControlInputListener = true;
+    let last = 0;
+    const report = () => {
+        const now = Date.now();
+        if (now - last < 1000 || typeof window.__missionControlInput !== 'function') return;
+        last = now;
+        window.__missionControlInput('');
+    };
+    for (const type of ['pointerdown', 'pointermove', 'touchstart', 'wheel', 'keydown']) {
+        window.addEventListener(type, report, { capture: true, passive: true });
+    }
+})()"#;
+
+/// Install the input listener in the page, including documents it navigates
+/// to later. The stream yields once per reported input.
+pub async fn watch(page: &Page) -> Result<impl Stream<Item = ()>> {
+    page.execute(AddBindingParams::new(BINDING_NAME)).await?;
+    page.execute(
+        AddScriptToEvaluateOnNewDocumentParams::builder()
+            .source(LISTENER_SCRIPT)
+            .build()
+            .unwrap(),
+    )
+    .await?;
+    let events = page.event_listener::<EventBindingCalled>().await?;
+    // The current document was loaded before the script was registered
+    page.evaluate(LISTENER_SCRIPT).await?;
+    Ok(events.filter_map(|event| async move { (event.name == BINDING_NAME).then_some(()) }))
+}
@@ -1,6 +1,14 @@
-use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
 
+use futures::channel::oneshot;
+use serde::{Deserialize, Serialize, Serializer};
+
+use super::overlay::Overlay;
+use crate::db::models::PlayTrigger;
+
+/// Serialized tagged by `type`, e.g. `{"type": "activate_tab", "tab_id": "news", "playlist_id": "lobby"}`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum ChromeMessage {
     /// Activate a specific playlist
     ActivatePlaylist { playlist_id: String },
@@ -10,6 +18,12 @@ pub enum ChromeMessage {
     StopPlaylist,
     /// Start automatic playlist rotation
     StartPlaylist,
+    /// Keep the current tab for a while, then resume rotation
+    HoldTab { duration_seconds: u64 },
+    /// Sent by the hold timer; ignored unless the hold is due
+    ExpireHold,
+    /// Someone touched, clicked or typed in the page of a tab
+    UserInteraction { tab_id: String },
     /// Update playlist interval
     UpdateInterval {
         playlist_id: String,
@@ -21,6 +35,8 @@ pub enum ChromeMessage {
     NextTab,
     /// Navigate to previous tab in playlist
     PreviousTab,
+    /// Create or refresh the upcoming tab ahead of its turn in rotation
+    PreloadNextTab,
     /// Update tab URL
     UpdateTabUrl { tab_id: String, url: String },
     /// Close tab
@@ -29,6 +45,32 @@ pub enum ChromeMessage {
     RefreshTab { tab_id: String },
     /// Recreate tab (close and reopen)
     RecreateTab { tab_id: String },
+    /// A playlist or its entries were edited; the running rotation follows
+    PlaylistUpdated { playlist_id: String },
+    /// The URL, content or viewport of a tab was edited; its open page
+    /// navigates to it, or is recreated to emulate the new viewport
+    TabUpdated {
+        tab_id: String,
+        viewport_changed: bool,
+    },
+    /// A tab was deleted; rotation moves on and its page is closed
+    TabDeleted { tab_id: String },
+    /// Show a URL on top of the playlist for a limited time
+    StartTakeover { url: String, duration_seconds: u64 },
+    /// End the current takeover and restore the previous playlist and tab
+    CancelTakeover,
+    /// Sent by the takeover timer; ignored if that takeover already ended
+    ExpireTakeover { takeover_id: String },
+    /// Show an overlay on top of the visible page, replacing one with the same id
+    ShowOverlay { overlay: Overlay },
+    /// Remove an overlay
+    HideOverlay { overlay_id: String },
+    /// Remove all overlays
+    ClearOverlays,
+    /// Sent by overlay timers to drop expired overlays from the page
+    ExpireOverlays,
+    /// Sent when the Chromium handler ends; relaunches it unless it was shut down
+    BrowserExited,
     /// Check Chrome status
     GetStatus,
     /// Shutdown Chrome controller
@@ -36,6 +78,7 @@ pub enum ChromeMessage {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum ChromeResponse {
     /// Operation completed successfully
     Success,
@@ -50,12 +93,103 @@ pub enum ChromeResponse {
     },
 }
 
-#[derive(Debug, Clone, Default)]
+/// A message queued for the controller, optionally awaiting its response
+#[derive(Debug)]
+pub struct ChromeRequest {
+    pub message: ChromeMessage,
+    pub reply: Option<oneshot::Sender<ChromeResponse>>,
+    /// What caused the message, recorded in the play log
+    pub trigger: PlayTrigger,
+}
+
+impl ChromeRequest {
+    pub fn with_trigger(message: ChromeMessage, trigger: PlayTrigger) -> Self {
+        Self {
+            message,
+            reply: None,
+            trigger,
+        }
+    }
+}
+
+impl From<ChromeMessage> for ChromeRequest {
+    fn from(message: ChromeMessage) -> Self {
+        Self::with_trigger(message, PlayTrigger::Manual)
+    }
+}
+
+/// Times are serialized as seconds since epoch
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct ChromeState {
     pub current_playlist_id: Option<String>,
     pub current_tab_id: Option<String>,
+    /// Playlist entry the visible tab is shown as
+    pub current_entry_id: Option<String>,
+    /// Display duration of the visible entry if it overrides the interval
+    pub current_duration_seconds: Option<u64>,
     pub is_running: bool,
     pub auto_rotate: bool,
     pub current_tab_index: usize,
-    pub current_tab_opened_at: Option<std::time::SystemTime>,
+    #[serde(serialize_with = "serialize_optional_epoch_seconds")]
+    pub current_tab_opened_at: Option<SystemTime>,
+    pub takeover: Option<Takeover>,
+    pub hold: Option<Hold>,
+}
+
+/// Rotation paused on the current tab until it expires
+#[derive(Debug, Clone, Serialize)]
+pub struct Hold {
+    #[serde(serialize_with = "serialize_epoch_seconds")]
+    pub started_at: SystemTime,
+    #[serde(serialize_with = "serialize_epoch_seconds")]
+    pub expires_at: SystemTime,
+    /// Caused by local input, extended on every further input
+    pub interaction: bool,
+}
+
+/// An ad-hoc URL shown instead of the playlist until it expires
+#[derive(Debug, Clone, Serialize)]
+pub struct Takeover {
+    pub id: String,
+    pub url: String,
+    #[serde(serialize_with = "serialize_epoch_seconds")]
+    pub started_at: SystemTime,
+    #[serde(serialize_with = "serialize_epoch_seconds")]
+    pub expires_at: SystemTime,
+    /// Playlist and tab to restore once the takeover ends
+    pub previous_playlist_id: Option<String>,
+    pub previous_tab_id: Option<String>,
+    /// Whether rotation was running before the takeover
+    pub resume_rotation: bool,
+}
+
+/// Load health of a tab, tracked while it keeps failing
+#[derive(Debug, Clone, Default)]
+pub struct TabHealth {
+    pub consecutive_failures: u32,
+    pub last_error: Option<String>,
+    pub last_failure_at: Option<std::time::SystemTime>,
+    /// The tab is skipped in rotation until this time
+    pub retry_at: Option<std::time::SystemTime>,
+}
+
+fn serialize_epoch_seconds<S: Serializer>(
+    time: &SystemTime,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    serializer.serialize_u64(secs)
+}
+
+fn serialize_optional_epoch_seconds<S: Serializer>(
+    time: &Option<SystemTime>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match time {
+        Some(time) => serialize_epoch_seconds(time, serializer),
+        None => serializer.serialize_none(),
+    }
 }
@@ -1,14 +1,19 @@
 pub mod controller;
+pub mod emulation;
+pub mod interaction;
 pub mod messages;
+pub mod overlay;
+pub mod rotation;
+pub mod transition;
 
 pub use controller::ChromeController;
 pub use messages::*;
 
 use anyhow::Result;
-use futures::SinkExt;
-use std::sync::Arc;
+use futures::{channel::oneshot, SinkExt};
+use std::{sync::Arc, time::Duration};
 
-use crate::{config::ChromiumConfig, state::AppState};
+use crate::{config::ChromiumConfig, db::models::PlayTrigger, state::AppState};
 
 /// Legacy interface for backward compatibility
 pub async fn start_chrome_controller(
@@ -20,42 +25,71 @@ pub async fn start_chrome_controller(
     Ok(controller)
 }
 
+/// Longest time to wait for the controller to answer a message
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(60);
+
 /// Send a message to the Chrome controller
 pub async fn send_chrome_message(
     controller: &ChromeController,
     message: ChromeMessage,
+) -> Result<()> {
+    send_chrome_message_with_trigger(controller, message, PlayTrigger::Manual).await
+}
+
+/// Send a message to the Chrome controller on behalf of `trigger`
+pub async fn send_chrome_message_with_trigger(
+    controller: &ChromeController,
+    message: ChromeMessage,
+    trigger: PlayTrigger,
 ) -> Result<()> {
     tracing::info!("Sending message to Chrome controller: {:?}", message);
     let mut sender = controller.get_message_sender();
     sender
-        .send(message)
+        .send(ChromeRequest::with_trigger(message, trigger))
         .await
         .map_err(|e| anyhow::anyhow!("Failed to send message: {}", e))?;
     tracing::info!("Message sent successfully to Chrome controller");
     Ok(())
 }
 
-/// Send a message to the Chrome controller and wait for response
-/// This function sends the message and waits for the controller to process it
+/// Send a message to the Chrome controller and wait until it was handled
 pub async fn send_chrome_message_with_response(
     controller: &ChromeController,
     message: ChromeMessage,
+) -> Result<ChromeResponse> {
+    send_chrome_message_with_response_and_trigger(controller, message, PlayTrigger::Manual).await
+}
+
+/// Send a message on behalf of `trigger` and wait until it was handled
+pub async fn send_chrome_message_with_response_and_trigger(
+    controller: &ChromeController,
+    message: ChromeMessage,
+    trigger: PlayTrigger,
 ) -> Result<ChromeResponse> {
     tracing::info!(
         "Sending message to Chrome controller with response: {:?}",
         message
     );
 
-    // Send the message normally
-    send_chrome_message(controller, message).await?;
-
-    // Wait a bit to ensure the message is processed
-    // This is a simple fix - we could implement proper response channels later
-    async_std::task::sleep(std::time::Duration::from_millis(100)).await;
+    let (reply, response) = oneshot::channel();
+    let mut sender = controller.get_message_sender();
+    sender
+        .send(ChromeRequest {
+            message,
+            reply: Some(reply),
+            trigger,
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to send message: {}", e))?;
 
-    // For now, return success as we know the message was sent and likely processed
-    tracing::info!("Chrome controller message sent and processed");
-    Ok(ChromeResponse::Success)
+    match async_std::future::timeout(RESPONSE_TIMEOUT, response).await {
+        Ok(Ok(response)) => Ok(response),
+        Ok(Err(_)) => Err(anyhow::anyhow!("Chrome controller dropped the message")),
+        Err(_) => Err(anyhow::anyhow!(
+            "Chrome controller did not respond within {}s",
+            RESPONSE_TIMEOUT.as_secs()
+        )),
+    }
 }
 
 /// Activate a playlist by ID
@@ -0,0 +1,121 @@
+use std::time::SystemTime;
+
+use anyhow::Result;
+use chromiumoxide::Page;
+use poem_openapi::Enum;
+use serde::{Deserialize, Serialize};
+
+/// How urgent an overlay is, determines its colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Enum)]
+#[serde(rename_all = "lowercase")]
+#[oai(rename_all = "lowercase")]
+pub enum OverlaySeverity {
+    #[default]
+    Info,
+    Warning,
+    Critical,
+}
+
+/// Where on the screen an overlay is placed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Enum)]
+#[serde(rename_all = "lowercase")]
+#[oai(rename_all = "lowercase")]
+pub enum OverlayPosition {
+    #[default]
+    Top,
+    Bottom,
+    Center,
+}
+
+/// How the text of an overlay is presented
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Enum)]
+#[serde(rename_all = "lowercase")]
+#[oai(rename_all = "lowercase")]
+pub enum OverlayStyle {
+    /// Static text
+    #[default]
+    Banner,
+    /// Text scrolling from right to left
+    Ticker,
+}
+
+/// Text shown on top of whatever page is visible
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Overlay {
+    pub id: String,
+    pub text: String,
+    pub severity: OverlaySeverity,
+    pub position: OverlayPosition,
+    pub style: OverlayStyle,
+    /// The overlay is removed after this time, kept until cleared if `None`
+    pub expires_at: Option<SystemTime>,
+}
+
+impl Overlay {
+    pub fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= SystemTime::now())
+    }
+}
+
+const CONTAINER_ID: &str = "__mission_control_overlays";
+
+/// Replace the overlay layer of a page with the given overlays.
+/// An empty slice removes the layer.
+pub async fn render(page: &Page, overlays: &[Overlay]) -> Result<()> {
+    // Passed as JSON and assigned via textContent, so text is never parsed as HTML
+    let overlays = serde_json::to_string(overlays)?;
+    let script = format!(
+        r#"(() => {{
+            const overlays = {overlays};
+            document.getElementById('{id}')?.remove();
+            if (overlays.length === 0) return;
+
+            const colors = {{
+                info: ['#1565c0', '#fff'],
+                warning: ['#f9a825', '#000'],
+                critical: ['#c62828', '#fff'],
+            }};
+            const root = document.createElement('div');
+            root.id = '{id}';
+            root.style.cssText = 'position:fixed;inset:0;z-index:2147483646;pointer-events:none;'
+                + 'font-family:sans-serif;';
+            const style = document.createElement('style');
+            style.textContent = '@keyframes __mc_ticker {{ from {{ transform: translateX(100vw); }} to {{ transform: translateX(-100%); }} }}'
+                + '@keyframes __mc_pulse {{ 50% {{ opacity: .75; }} }}';
+            root.appendChild(style);
+
+            for (const position of ['top', 'center', 'bottom']) {{
+                const group = overlays.filter((o) => o.position === position);
+                if (group.length === 0) continue;
+                const stack = document.createElement('div');
+                stack.style.cssText = 'position:absolute;left:0;right:0;display:flex;flex-direction:column;gap:4px;'
+                    + (position === 'top' ? 'top:0;' : position === 'bottom' ? 'bottom:0;' : 'top:50%;transform:translateY(-50%);');
+                for (const overlay of group) {{
+                    const [background, color] = colors[overlay.severity] || colors.info;
+                    const el = document.createElement('div');
+                    el.dataset.overlayId = overlay.id;
+                    el.style.cssText = `background:${{background}};color:${{color}};font-size:3vh;font-weight:600;`
+                        + 'padding:1.2vh 2vw;overflow:hidden;white-space:nowrap;box-shadow:0 0 1vh rgba(0,0,0,.4);'
+                        + (position === 'center' ? 'margin:0 10vw;text-align:center;white-space:normal;' : '')
+                        + (overlay.severity === 'critical' ? 'animation:__mc_pulse 1.5s ease-in-out infinite;' : '');
+                    const text = document.createElement('span');
+                    text.textContent = overlay.text;
+                    if (overlay.style === 'ticker') {{
+                        const duration = Math.max(10, overlay.text.length / 5);
+                        text.style.cssText = `display:inline-block;animation:__mc_ticker ${{duration}}s linear infinite;`;
+                        el.style.whiteSpace = 'nowrap';
+                    }}
+                    el.appendChild(text);
+                    stack.appendChild(el);
+                }}
+                root.appendChild(stack);
+            }}
+            document.documentElement.appendChild(root);
+        }})()"#,
+        overlays = overlays,
+        id = CONTAINER_ID,
+    );
+    page.evaluate_expression(script).await?;
+    Ok(())
+}
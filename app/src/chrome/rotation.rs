@@ -0,0 +1,272 @@
+use std::collections::VecDeque;
+
+use rand::{distributions::WeightedIndex, prelude::*};
+
+use crate::db::models::{RotationMode, TabWithOrder};
+
+/// Tabs remembered for walking back with "previous"
+const HISTORY_LIMIT: usize = 100;
+
+/// Rotation order and history of the active playlist, tracked by playlist
+/// entry since a tab may be listed more than once
+#[derive(Debug, Default)]
+pub struct Rotation {
+    /// Entries that were shown, most recent last
+    history: VecDeque<String>,
+    /// Entries not shown yet in the current shuffle round
+    shuffle_bag: Vec<String>,
+    /// Entry picked to be shown next, so preloading and switching agree
+    upcoming: Option<String>,
+}
+
+impl Rotation {
+    /// Forget history and picks, e.g. when another playlist is activated
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Remember that an entry is now visible
+    pub fn record(&mut self, entry_id: &str) {
+        if self.upcoming.as_deref() == Some(entry_id) {
+            self.upcoming = None;
+        }
+        if self.history.back().map(String::as_str) == Some(entry_id) {
+            return;
+        }
+        self.history.push_back(entry_id.to_string());
+        if self.history.len() > HISTORY_LIMIT {
+            self.history.pop_front();
+        }
+    }
+
+    /// Index of the tab that follows `current`. The pick is kept until it is
+    /// shown or skipped, so repeated calls return the same tab.
+    pub fn upcoming(&mut self, mode: RotationMode, tabs: &[TabWithOrder], current: usize) -> usize {
+        if let Some(idx) = self
+            .upcoming
+            .as_ref()
+            .and_then(|id| tabs.iter().position(|t| &t.entry_id == id))
+        {
+            if idx != current || tabs.len() == 1 {
+                return idx;
+            }
+        }
+
+        let next = match mode {
+            RotationMode::Sequential => (current + 1) % tabs.len(),
+            RotationMode::Shuffle => self.draw_shuffled(tabs, current),
+            RotationMode::Weighted => Self::draw_weighted(tabs, current),
+        };
+        self.upcoming = Some(tabs[next].entry_id.clone());
+        next
+    }
+
+    /// Drop the upcoming pick, e.g. because it did not load in time
+    pub fn skip_upcoming(&mut self) {
+        self.upcoming = None;
+    }
+
+    /// Index of the tab shown before the visible one. Both are removed from
+    /// the history, showing the returned tab records it again.
+    pub fn previous(&mut self, tabs: &[TabWithOrder]) -> Option<usize> {
+        self.history.pop_back();
+        while let Some(id) = self.history.pop_back() {
+            if let Some(idx) = tabs.iter().position(|t| t.entry_id == id) {
+                return Some(idx);
+            }
+        }
+        None
+    }
+
+    fn draw_shuffled(&mut self, tabs: &[TabWithOrder], current: usize) -> usize {
+        let current_id = &tabs[current].entry_id;
+        self.shuffle_bag
+            .retain(|id| id != current_id && tabs.iter().any(|t| &t.entry_id == id));
+        if self.shuffle_bag.is_empty() {
+            let mut bag: Vec<String> = tabs.iter().map(|t| t.entry_id.clone()).collect();
+            bag.shuffle(&mut thread_rng());
+            // Don't start the new round with the tab that is visible
+            if bag.len() > 1 && bag.last() == Some(current_id) {
+                let last = bag.len() - 1;
+                bag.swap(0, last);
+            }
+            self.shuffle_bag = bag;
+        }
+
+        let id = self.shuffle_bag.pop().unwrap_or_default();
+        tabs.iter().position(|t| t.entry_id == id).unwrap_or(0)
+    }
+
+    fn draw_weighted(tabs: &[TabWithOrder], current: usize) -> usize {
+        let weights = tabs.iter().enumerate().map(|(idx, tab)| {
+            if idx == current && tabs.len() > 1 {
+                0
+            } else {
+                tab.weight.max(0)
+            }
+        });
+        match WeightedIndex::new(weights) {
+            Ok(distribution) => distribution.sample(&mut thread_rng()),
+            // All other tabs have a weight of 0
+            Err(_) => (current + 1) % tabs.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use chrono::Utc;
+
+    use super::*;
+    use crate::db::models::TabContentType;
+
+    fn tabs(weights: &[i64]) -> Vec<TabWithOrder> {
+        weights
+            .iter()
+            .enumerate()
+            .map(|(idx, weight)| TabWithOrder {
+                id: format!("tab-{}", idx),
+                entry_id: format!("entry-{}", idx),
+                name: format!("Tab {}", idx),
+                url: "https://example.com".to_string(),
+                persist: false,
+                viewport_width: None,
+                viewport_height: None,
+                target_width: None,
+                target_height: None,
+                scaling_mode: None,
+                content_type: TabContentType::Url,
+                content: None,
+                order_index: idx as i64,
+                duration_seconds: None,
+                enabled: true,
+                weight: *weight,
+                last_manual_activation: None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            })
+            .collect()
+    }
+
+    /// Show the upcoming tab the way the controller does
+    fn advance(
+        rotation: &mut Rotation,
+        mode: RotationMode,
+        tabs: &[TabWithOrder],
+        current: usize,
+    ) -> usize {
+        let next = rotation.upcoming(mode, tabs, current);
+        rotation.record(&tabs[next].entry_id);
+        next
+    }
+
+    #[test]
+    fn sequential_wraps_around() {
+        let tabs = tabs(&[1, 1, 1]);
+        let mut rotation = Rotation::default();
+        let shown: Vec<_> = (0..4)
+            .scan(0, |current, _| {
+                *current = advance(&mut rotation, RotationMode::Sequential, &tabs, *current);
+                Some(*current)
+            })
+            .collect();
+        assert_eq!(shown, [1, 2, 0, 1]);
+    }
+
+    #[test]
+    fn upcoming_is_stable_until_shown_or_skipped() {
+        let tabs = tabs(&[1, 1, 1, 1]);
+        let mut rotation = Rotation::default();
+        let next = rotation.upcoming(RotationMode::Shuffle, &tabs, 0);
+        for _ in 0..10 {
+            assert_eq!(rotation.upcoming(RotationMode::Shuffle, &tabs, 0), next);
+        }
+        rotation.skip_upcoming();
+        assert_eq!(rotation.upcoming(RotationMode::Sequential, &tabs, 0), 1);
+    }
+
+    #[test]
+    fn shuffle_shows_every_tab_once_per_round() {
+        let tabs = tabs(&[1, 1, 1, 1, 1]);
+        let mut rotation = Rotation::default();
+        rotation.record(&tabs[0].entry_id);
+        let mut current = 0;
+        let round: HashSet<_> = (0..tabs.len())
+            .map(|_| {
+                current = advance(&mut rotation, RotationMode::Shuffle, &tabs, current);
+                current
+            })
+            .collect();
+        assert_eq!(round, HashSet::from([0, 1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn shuffle_never_repeats_the_visible_tab() {
+        let tabs = tabs(&[1, 1, 1]);
+        let mut rotation = Rotation::default();
+        let mut current = 0;
+        for _ in 0..100 {
+            let next = advance(&mut rotation, RotationMode::Shuffle, &tabs, current);
+            assert_ne!(next, current);
+            current = next;
+        }
+    }
+
+    #[test]
+    fn weighted_skips_zero_weights_and_the_visible_tab() {
+        let weighted = tabs(&[5, 0, 3]);
+        let mut rotation = Rotation::default();
+        for _ in 0..50 {
+            assert_eq!(rotation.upcoming(RotationMode::Weighted, &weighted, 0), 2);
+            rotation.skip_upcoming();
+        }
+    }
+
+    #[test]
+    fn weighted_falls_back_to_the_next_tab() {
+        // Only the visible tab has a weight
+        let tabs = tabs(&[5, 0, 0]);
+        let mut rotation = Rotation::default();
+        assert_eq!(rotation.upcoming(RotationMode::Weighted, &tabs, 0), 1);
+    }
+
+    #[test]
+    fn previous_walks_back_through_history() {
+        let tabs = tabs(&[1, 1, 1]);
+        let mut rotation = Rotation::default();
+        for idx in [0, 1, 2] {
+            rotation.record(&tabs[idx].entry_id);
+        }
+        // Recording the visible entry again is not a new step
+        rotation.record(&tabs[2].entry_id);
+
+        assert_eq!(rotation.previous(&tabs), Some(1));
+        rotation.record(&tabs[1].entry_id);
+        assert_eq!(rotation.previous(&tabs), Some(0));
+        rotation.record(&tabs[0].entry_id);
+        assert_eq!(rotation.previous(&tabs), None);
+    }
+
+    #[test]
+    fn previous_skips_removed_entries() {
+        let all = tabs(&[1, 1, 1]);
+        let mut rotation = Rotation::default();
+        for tab in &all {
+            rotation.record(&tab.entry_id);
+        }
+        let remaining = vec![all[0].clone(), all[2].clone()];
+        assert_eq!(rotation.previous(&remaining), Some(0));
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let tabs = tabs(&[1, 1]);
+        let mut rotation = Rotation::default();
+        for idx in 0..HISTORY_LIMIT * 2 {
+            rotation.record(&tabs[idx % 2].entry_id);
+        }
+        assert_eq!(rotation.history.len(), HISTORY_LIMIT);
+    }
+}
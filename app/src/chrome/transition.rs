@@ -0,0 +1,158 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use chromiumoxide::Page;
+use serde::Deserialize;
+use tracing::warn;
+
+/// Visual effect used when switching between tabs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransitionEffect {
+    /// Hard cut to the next tab
+    #[default]
+    None,
+    /// Fade the previous tab out over the next one
+    Crossfade,
+    /// Slide the previous tab out to the left
+    Slide,
+}
+
+/// How long to wait for the next tab before showing it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WaitUntil {
+    /// Show the tab immediately
+    #[default]
+    None,
+    /// Wait for `document.readyState` to be `complete`
+    Load,
+    /// Wait for the load event and no new network activity for a short while
+    NetworkIdle,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TransitionConfig {
+    #[serde(default)]
+    pub effect: TransitionEffect,
+    #[serde(default = "default_duration_ms")]
+    pub duration_ms: u64,
+    #[serde(default)]
+    pub wait_until: WaitUntil,
+    /// Give up waiting and show the tab anyway after this long
+    #[serde(default = "default_wait_timeout_ms")]
+    pub wait_timeout_ms: u64,
+}
+
+impl Default for TransitionConfig {
+    fn default() -> Self {
+        Self {
+            effect: TransitionEffect::default(),
+            duration_ms: default_duration_ms(),
+            wait_until: WaitUntil::default(),
+            wait_timeout_ms: default_wait_timeout_ms(),
+        }
+    }
+}
+
+fn default_duration_ms() -> u64 {
+    500
+}
+
+fn default_wait_timeout_ms() -> u64 {
+    10_000
+}
+
+/// Quiet period without new resource entries that counts as network idle
+const NETWORK_IDLE_QUIET_MS: u64 = 500;
+
+const OVERLAY_ID: &str = "__mission_control_transition";
+
+/// Wait for a (hidden) page to become ready according to `wait_until`.
+/// Returns false if the page did not become ready within `timeout`.
+pub async fn wait_until_ready(page: &Page, wait_until: WaitUntil, timeout: Duration) -> bool {
+    let script = match wait_until {
+        WaitUntil::None => return true,
+        WaitUntil::Load => r#"new Promise((resolve) => {
+                if (document.readyState === 'complete') return resolve(true);
+                window.addEventListener('load', () => resolve(true), { once: true });
+            })"#
+        .to_string(),
+        WaitUntil::NetworkIdle => format!(
+            r#"new Promise((resolve) => {{
+                let last = -1;
+                let quiet = 0;
+                const timer = setInterval(() => {{
+                    const count = performance.getEntriesByType('resource').length;
+                    if (document.readyState === 'complete' && count === last) {{
+                        quiet += 100;
+                        if (quiet >= {quiet}) {{
+                            clearInterval(timer);
+                            resolve(true);
+                        }}
+                    }} else {{
+                        quiet = 0;
+                        last = count;
+                    }}
+                }}, 100);
+            }})"#,
+            quiet = NETWORK_IDLE_QUIET_MS
+        ),
+    };
+
+    match async_std::future::timeout(timeout, page.evaluate_expression(script)).await {
+        Ok(Ok(_)) => true,
+        Ok(Err(e)) => {
+            warn!("wait_until_ready: evaluation failed: {}", e);
+            false
+        }
+        Err(_) => false,
+    }
+}
+
+/// Cover the (still hidden) next page with a snapshot of the previous tab,
+/// so that once it is brought to front the snapshot can be animated away.
+/// `frame` is the base64 encoded JPEG of the previous tab.
+pub async fn prepare(page: &Page, config: &TransitionConfig, frame: &[u8]) -> Result<()> {
+    if config.effect == TransitionEffect::None {
+        return Ok(());
+    }
+    let frame = String::from_utf8_lossy(frame);
+    let (property, end) = match config.effect {
+        TransitionEffect::Slide => ("transform", "translateX(-100%)"),
+        _ => ("opacity", "0"),
+    };
+    let script = format!(
+        r#"(() => {{
+            document.getElementById('{id}')?.remove();
+            const el = document.createElement('div');
+            el.id = '{id}';
+            el.style.cssText = 'position:fixed;inset:0;z-index:2147483647;pointer-events:none;'
+                + 'background:#000 url(data:image/jpeg;base64,{frame}) center/cover no-repeat;'
+                + 'transition:{property} {duration}ms ease-in-out;';
+            document.documentElement.appendChild(el);
+            window.__missionControlTransition = () => {{
+                requestAnimationFrame(() => {{
+                    el.style.{property} = '{end}';
+                    setTimeout(() => el.remove(), {duration} + 50);
+                }});
+            }};
+        }})()"#,
+        id = OVERLAY_ID,
+        frame = frame,
+        property = property,
+        end = end,
+        duration = config.duration_ms,
+    );
+    page.evaluate_expression(script).await?;
+    Ok(())
+}
+
+/// Start the transition prepared by `prepare` on the now visible page
+pub async fn run(page: &Page) -> Result<()> {
+    page.evaluate_expression(
+        "window.__missionControlTransition && window.__missionControlTransition()",
+    )
+    .await?;
+    Ok(())
+}
@@ -7,30 +7,91 @@ use figment::{
 };
 use serde::Deserialize;
 
+use crate::{
+    chrome::{interaction::InteractionConfig, transition::TransitionConfig},
+    db::models::{RotationMode, ScalingMode, TabContentType},
+    input::InputConfig,
+    models::hass::preview::PreviewConfig,
+};
+
 #[derive(Debug, Deserialize)]
 pub struct Config {
     pub homeassistant: Option<HomeAssistantConfig>,
     pub device: DeviceConfig,
     pub display: DisplayConfig,
     pub chromium: Option<ChromiumConfig>,
+    #[serde(default)]
+    pub webhooks: Vec<WebhookConfig>,
+    #[serde(default)]
+    pub webhook_deliveries: WebhookDeliveriesConfig,
+    #[serde(default)]
+    pub assets: AssetsConfig,
+    #[serde(default)]
+    pub play_log: PlayLogConfig,
+    #[serde(default)]
+    pub input: InputConfig,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct HomeAssistantConfig {
     pub mqtt: HASSMQTTConfig,
+    #[serde(default)]
+    pub preview: PreviewConfig,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Deserialize)]
 pub struct HASSMQTTConfig {
+    /// `mqtt://`, `mqtts://`, `ws://` or `wss://`; websocket URLs keep their path
     pub url: String,
     pub username: Option<String>,
     pub password: Option<String>,
+    /// Defaults to `mission-control-<device id>`
+    pub client_id: Option<String>,
+    #[serde(default = "default_keep_alive_seconds")]
+    pub keep_alive_seconds: u64,
+    /// QoS of publishes and subscriptions, 0 to 2
+    #[serde(default = "default_qos")]
+    pub qos: u8,
+    /// PEM CA certificates to trust instead of the system roots
+    pub ca_file: Option<String>,
+    /// PEM client certificate chain, requires `client_key_file`
+    pub client_cert_file: Option<String>,
+    /// PEM private key of the client certificate
+    pub client_key_file: Option<String>,
+}
+
+/// The config is logged at startup, keep the password out of it
+impl std::fmt::Debug for HASSMQTTConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HASSMQTTConfig")
+            .field("url", &self.url)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "<redacted>"))
+            .field("client_id", &self.client_id)
+            .field("keep_alive_seconds", &self.keep_alive_seconds)
+            .field("qos", &self.qos)
+            .field("ca_file", &self.ca_file)
+            .field("client_cert_file", &self.client_cert_file)
+            .field("client_key_file", &self.client_key_file)
+            .finish()
+    }
+}
+
+fn default_keep_alive_seconds() -> u64 {
+    15
+}
+
+fn default_qos() -> u8 {
+    1
 }
 
 #[derive(Debug, Deserialize)]
 pub struct DeviceConfig {
     pub name: String,
     pub id: String,
+    /// Custom values available in tab URLs as `{device.<name>}`
+    #[serde(default)]
+    pub variables: HashMap<String, String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -53,13 +114,40 @@ pub struct ChromiumConfig {
     pub theme: Option<String>,
     pub tabs: Option<HashMap<String, ChromiumTabConfig>>,
     pub playlists: Option<HashMap<String, ChromiumPlaylistConfig>>,
+    /// How to switch between tabs
+    #[serde(default)]
+    pub transition: TransitionConfig,
+    /// Seconds ahead of its turn the upcoming tab is loaded; tabs that fail
+    /// to load in this time are skipped
+    #[serde(default)]
+    pub preload_seconds: Option<u64>,
+    /// Pause rotation while someone touches or clicks the visible page
+    #[serde(default)]
+    pub interaction: InteractionConfig,
 }
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct ChromiumTabConfig {
+    /// Page or media URL, may be omitted for markdown and html tabs
+    #[serde(default)]
     pub url: String,
     #[serde(default)]
     pub persist: bool,
+    /// Target viewport width the page is designed for (e.g. 1920)
+    #[serde(default)]
+    pub width: Option<i32>,
+    /// Target viewport height the page is designed for (e.g. 1080)
+    #[serde(default)]
+    pub height: Option<i32>,
+    /// How the target viewport is scaled onto the display (fit, fill, native)
+    #[serde(default)]
+    pub scaling: Option<ScalingMode>,
+    /// What the tab displays (url, image, video, pdf, markdown, html)
+    #[serde(default, rename = "type")]
+    pub content_type: TabContentType,
+    /// Markdown or HTML body for markdown and html tabs
+    #[serde(default)]
+    pub content: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -68,6 +156,103 @@ pub struct ChromiumPlaylistConfig {
     pub interval: u32,
     #[serde(default)]
     pub is_active: bool,
+    /// Order of rotation (sequential, shuffle, weighted)
+    #[serde(default)]
+    pub rotation: RotationMode,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct WebhookConfig {
+    pub url: String,
+    /// Event types to deliver (e.g. "tab_failed"); all events if empty
+    #[serde(default)]
+    pub events: Vec<String>,
+    /// Shared secret used to sign payloads with HMAC-SHA256
+    pub secret: Option<String>,
+    /// Delivery attempts before giving up
+    #[serde(default = "default_webhook_max_attempts")]
+    pub max_attempts: u32,
+}
+
+/// The config is logged at startup, keep the secret out of it
+impl std::fmt::Debug for WebhookConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookConfig")
+            .field("url", &self.url)
+            .field("events", &self.events)
+            .field("secret", &self.secret.as_ref().map(|_| "<redacted>"))
+            .field("max_attempts", &self.max_attempts)
+            .finish()
+    }
+}
+
+fn default_webhook_max_attempts() -> u32 {
+    5
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WebhookDeliveriesConfig {
+    /// Days finished deliveries are kept before they are pruned, 0 keeps them forever
+    #[serde(default = "default_webhook_delivery_retention_days")]
+    pub retention_days: u32,
+}
+
+impl Default for WebhookDeliveriesConfig {
+    fn default() -> Self {
+        Self {
+            retention_days: default_webhook_delivery_retention_days(),
+        }
+    }
+}
+
+fn default_webhook_delivery_retention_days() -> u32 {
+    30
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AssetsConfig {
+    /// Directory uploaded files are stored in
+    #[serde(default = "default_assets_dir")]
+    pub dir: String,
+    /// Total size of all assets in megabytes
+    #[serde(default = "default_assets_quota_mb")]
+    pub quota_mb: u64,
+}
+
+impl Default for AssetsConfig {
+    fn default() -> Self {
+        Self {
+            dir: default_assets_dir(),
+            quota_mb: default_assets_quota_mb(),
+        }
+    }
+}
+
+fn default_assets_dir() -> String {
+    "./assets".to_string()
+}
+
+fn default_assets_quota_mb() -> u64 {
+    1024
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PlayLogConfig {
+    /// Days plays are kept before they are pruned, 0 keeps them forever
+    #[serde(default = "default_play_log_retention_days")]
+    pub retention_days: u32,
+}
+
+impl Default for PlayLogConfig {
+    fn default() -> Self {
+        Self {
+            retention_days: default_play_log_retention_days(),
+        }
+    }
+}
+
+fn default_play_log_retention_days() -> u32 {
+    90
 }
 
 pub fn load_config() -> Result<Config> {
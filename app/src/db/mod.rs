@@ -1,5 +1,5 @@
 use anyhow::Result;
-use sqlx::{migrate::MigrateDatabase, Sqlite, SqlitePool};
+use sqlx::{migrate::MigrateDatabase, Row, Sqlite, SqlitePool};
 use tracing::info;
 
 pub mod models;
@@ -33,7 +33,7 @@ pub async fn init_database() -> Result<SqlitePool> {
 }
 
 /// Run database migrations
-async fn run_migrations(pool: &SqlitePool) -> Result<()> {
+pub(crate) async fn run_migrations(pool: &SqlitePool) -> Result<()> {
     // Create tables
     sqlx::query(
         r#"
@@ -70,11 +70,11 @@ async fn run_migrations(pool: &SqlitePool) -> Result<()> {
     sqlx::query(
         r#"
         CREATE TABLE IF NOT EXISTS playlist_tabs (
+            id TEXT PRIMARY KEY,
             playlist_id TEXT NOT NULL,
             tab_id TEXT NOT NULL,
             order_index INTEGER NOT NULL,
             duration_seconds INTEGER,
-            PRIMARY KEY (playlist_id, tab_id),
             FOREIGN KEY (playlist_id) REFERENCES playlists(id) ON DELETE CASCADE,
             FOREIGN KEY (tab_id) REFERENCES tabs(id) ON DELETE CASCADE
         )
@@ -95,6 +95,25 @@ async fn run_migrations(pool: &SqlitePool) -> Result<()> {
     .execute(pool)
     .await?;
 
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS webhook_deliveries (
+            id TEXT PRIMARY KEY,
+            url TEXT NOT NULL,
+            event_type TEXT NOT NULL,
+            payload TEXT NOT NULL,
+            status TEXT NOT NULL,
+            attempts INTEGER NOT NULL DEFAULT 0,
+            response_status INTEGER,
+            last_error TEXT,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
     // Create indexes for performance
     sqlx::query(
         "CREATE INDEX IF NOT EXISTS idx_playlist_tabs_playlist_id ON playlist_tabs(playlist_id)",
@@ -105,6 +124,51 @@ async fn run_migrations(pool: &SqlitePool) -> Result<()> {
     sqlx::query("CREATE INDEX IF NOT EXISTS idx_playlist_tabs_order ON playlist_tabs(playlist_id, order_index)")
         .execute(pool).await?;
 
+    sqlx::query(
+        "CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_created ON webhook_deliveries(created_at)",
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS assets (
+            id TEXT PRIMARY KEY,
+            filename TEXT NOT NULL,
+            stored_name TEXT NOT NULL,
+            mime_type TEXT NOT NULL,
+            size INTEGER NOT NULL,
+            sha256 TEXT NOT NULL,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_assets_sha256 ON assets(sha256)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS play_log (
+            id TEXT PRIMARY KEY,
+            tab_id TEXT NOT NULL,
+            playlist_id TEXT NOT NULL,
+            trigger TEXT NOT NULL,
+            started_at DATETIME NOT NULL,
+            ended_at DATETIME
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_play_log_started ON play_log(started_at)")
+        .execute(pool)
+        .await?;
+
     // Add viewport dimensions columns if they don't exist (for existing databases)
     let _ = sqlx::query("ALTER TABLE tabs ADD COLUMN viewport_width INTEGER")
         .execute(pool)
@@ -114,6 +178,28 @@ async fn run_migrations(pool: &SqlitePool) -> Result<()> {
         .execute(pool)
         .await; // Ignore errors if column already exists
 
+    // Add target viewport emulation columns
+    let _ = sqlx::query("ALTER TABLE tabs ADD COLUMN target_width INTEGER")
+        .execute(pool)
+        .await; // Ignore errors if column already exists
+
+    let _ = sqlx::query("ALTER TABLE tabs ADD COLUMN target_height INTEGER")
+        .execute(pool)
+        .await; // Ignore errors if column already exists
+
+    let _ = sqlx::query("ALTER TABLE tabs ADD COLUMN scaling_mode TEXT")
+        .execute(pool)
+        .await; // Ignore errors if column already exists
+
+    // Add typed content columns, existing tabs keep showing their URL
+    let _ = sqlx::query("ALTER TABLE tabs ADD COLUMN content_type TEXT NOT NULL DEFAULT 'url'")
+        .execute(pool)
+        .await; // Ignore errors if column already exists
+
+    let _ = sqlx::query("ALTER TABLE tabs ADD COLUMN content TEXT")
+        .execute(pool)
+        .await; // Ignore errors if column already exists
+
     // Add new fields to playlist_tabs table
     let _ =
         sqlx::query("ALTER TABLE playlist_tabs ADD COLUMN enabled BOOLEAN NOT NULL DEFAULT TRUE")
@@ -124,10 +210,96 @@ async fn run_migrations(pool: &SqlitePool) -> Result<()> {
         .execute(pool)
         .await; // Ignore errors if column already exists
 
+    // Add rotation modes
+    let _ = sqlx::query(
+        "ALTER TABLE playlists ADD COLUMN rotation_mode TEXT NOT NULL DEFAULT 'sequential'",
+    )
+    .execute(pool)
+    .await; // Ignore errors if column already exists
+
+    let _ = sqlx::query("ALTER TABLE playlist_tabs ADD COLUMN weight INTEGER NOT NULL DEFAULT 1")
+        .execute(pool)
+        .await; // Ignore errors if column already exists
+
+    migrate_playlist_entries(pool).await?;
+
+    Ok(())
+}
+
+/// Give every playlist entry its own id, so a tab can be part of a playlist
+/// more than once. Older databases keyed entries by (playlist_id, tab_id).
+async fn migrate_playlist_entries(pool: &SqlitePool) -> Result<()> {
+    let has_id: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM pragma_table_info('playlist_tabs') WHERE name = 'id'",
+    )
+    .fetch_one(pool)
+    .await?;
+    if has_id > 0 {
+        return Ok(());
+    }
+
+    info!("Migrating playlist_tabs to playlist entries with their own id");
+    let mut tx = pool.begin().await?;
+    sqlx::query(
+        r#"
+        CREATE TABLE playlist_tabs_new (
+            id TEXT PRIMARY KEY,
+            playlist_id TEXT NOT NULL,
+            tab_id TEXT NOT NULL,
+            order_index INTEGER NOT NULL,
+            duration_seconds INTEGER,
+            enabled BOOLEAN NOT NULL DEFAULT TRUE,
+            last_manual_activation DATETIME,
+            weight INTEGER NOT NULL DEFAULT 1,
+            FOREIGN KEY (playlist_id) REFERENCES playlists(id) ON DELETE CASCADE,
+            FOREIGN KEY (tab_id) REFERENCES tabs(id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let rows = sqlx::query("SELECT playlist_id, tab_id FROM playlist_tabs")
+        .fetch_all(&mut *tx)
+        .await?;
+    for row in rows {
+        let playlist_id: String = row.get("playlist_id");
+        let tab_id: String = row.get("tab_id");
+        sqlx::query(
+            "INSERT INTO playlist_tabs_new (id, playlist_id, tab_id, order_index, duration_seconds,
+                                            enabled, last_manual_activation, weight)
+             SELECT ?, playlist_id, tab_id, order_index, duration_seconds,
+                    enabled, last_manual_activation, weight
+             FROM playlist_tabs WHERE playlist_id = ? AND tab_id = ?",
+        )
+        .bind(uuid::Uuid::new_v4().to_string())
+        .bind(&playlist_id)
+        .bind(&tab_id)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    sqlx::query("DROP TABLE playlist_tabs")
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("ALTER TABLE playlist_tabs_new RENAME TO playlist_tabs")
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query(
+        "CREATE INDEX IF NOT EXISTS idx_playlist_tabs_playlist_id ON playlist_tabs(playlist_id)",
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_playlist_tabs_order ON playlist_tabs(playlist_id, order_index)")
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+
     Ok(())
 }
 
 /// Seed initial data if the database is empty
+#[allow(dead_code)]
 async fn seed_initial_data(pool: &SqlitePool) -> Result<()> {
     // Check if we have any playlists
     let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM playlists")
@@ -168,8 +340,9 @@ async fn seed_initial_data(pool: &SqlitePool) -> Result<()> {
 
         // Add tabs to playlist
         sqlx::query(
-            "INSERT INTO playlist_tabs (playlist_id, tab_id, order_index) VALUES (?, ?, ?)",
+            "INSERT INTO playlist_tabs (id, playlist_id, tab_id, order_index) VALUES (?, ?, ?, ?)",
         )
+        .bind(uuid::Uuid::new_v4().to_string())
         .bind("hello_world")
         .bind("welcome")
         .bind(0)
@@ -177,8 +350,9 @@ async fn seed_initial_data(pool: &SqlitePool) -> Result<()> {
         .await?;
 
         sqlx::query(
-            "INSERT INTO playlist_tabs (playlist_id, tab_id, order_index) VALUES (?, ?, ?)",
+            "INSERT INTO playlist_tabs (id, playlist_id, tab_id, order_index) VALUES (?, ?, ?, ?)",
         )
+        .bind(uuid::Uuid::new_v4().to_string())
         .bind("hello_world")
         .bind("dashboard")
         .bind(1)
@@ -202,13 +376,22 @@ pub async fn import_config_data(
     if let Some(tabs) = &chromium_config.tabs {
         for (tab_id, tab_config) in tabs {
             // Insert tab if it doesn't exist
-            sqlx::query("INSERT OR IGNORE INTO tabs (id, name, url, persist) VALUES (?, ?, ?, ?)")
-                .bind(tab_id)
-                .bind(tab_id) // Use ID as name for now
-                .bind(&tab_config.url)
-                .bind(tab_config.persist)
-                .execute(pool)
-                .await?;
+            sqlx::query(
+                "INSERT OR IGNORE INTO tabs (id, name, url, persist, target_width, target_height, scaling_mode,
+                                             content_type, content) 
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(tab_id)
+            .bind(tab_id) // Use ID as name for now
+            .bind(&tab_config.url)
+            .bind(tab_config.persist)
+            .bind(tab_config.width)
+            .bind(tab_config.height)
+            .bind(tab_config.scaling)
+            .bind(tab_config.content_type)
+            .bind(&tab_config.content)
+            .execute(pool)
+            .await?;
         }
     }
 
@@ -217,20 +400,37 @@ pub async fn import_config_data(
         for (playlist_id, playlist_config) in playlists {
             // Insert playlist if it doesn't exist
             sqlx::query(
-                "INSERT OR IGNORE INTO playlists (id, name, interval_seconds, is_active) VALUES (?, ?, ?, ?)"
+                "INSERT OR IGNORE INTO playlists (id, name, interval_seconds, is_active, rotation_mode) VALUES (?, ?, ?, ?, ?)"
             )
             .bind(playlist_id)
             .bind(playlist_id) // Use ID as name for now
             .bind(playlist_config.interval as i64)
             .bind(playlist_config.is_active) // Use the is_active field from config
+            .bind(playlist_config.rotation)
             .execute(pool)
             .await?;
 
-            // Insert playlist-tab relationships
+            // Insert playlist entries, a tab listed n times gets at least n entries
             for (index, tab_id) in playlist_config.tabs.iter().enumerate() {
+                let occurrence = playlist_config.tabs[..index]
+                    .iter()
+                    .filter(|t| *t == tab_id)
+                    .count() as i64;
+                let existing: i64 = sqlx::query_scalar(
+                    "SELECT COUNT(*) FROM playlist_tabs WHERE playlist_id = ? AND tab_id = ?",
+                )
+                .bind(playlist_id)
+                .bind(tab_id)
+                .fetch_one(pool)
+                .await?;
+                if existing > occurrence {
+                    continue;
+                }
+
                 sqlx::query(
-                    "INSERT OR IGNORE INTO playlist_tabs (playlist_id, tab_id, order_index) VALUES (?, ?, ?)"
+                    "INSERT INTO playlist_tabs (id, playlist_id, tab_id, order_index) VALUES (?, ?, ?, ?)"
                 )
+                .bind(uuid::Uuid::new_v4().to_string())
                 .bind(playlist_id)
                 .bind(tab_id)
                 .bind(index as i64)
@@ -243,3 +443,136 @@ pub async fn import_config_data(
     info!("Config data imported successfully");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    use super::*;
+
+    /// Database as created before playlist entries had their own id
+    async fn old_database() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        for statement in [
+            "CREATE TABLE playlists (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                interval_seconds INTEGER NOT NULL DEFAULT 30,
+                is_active BOOLEAN NOT NULL DEFAULT FALSE,
+                created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+            )",
+            "CREATE TABLE tabs (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                url TEXT NOT NULL,
+                persist BOOLEAN NOT NULL DEFAULT TRUE,
+                created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+            )",
+            "CREATE TABLE playlist_tabs (
+                playlist_id TEXT NOT NULL,
+                tab_id TEXT NOT NULL,
+                order_index INTEGER NOT NULL,
+                duration_seconds INTEGER,
+                enabled BOOLEAN NOT NULL DEFAULT TRUE,
+                PRIMARY KEY (playlist_id, tab_id),
+                FOREIGN KEY (playlist_id) REFERENCES playlists(id) ON DELETE CASCADE,
+                FOREIGN KEY (tab_id) REFERENCES tabs(id) ON DELETE CASCADE
+            )",
+            "INSERT INTO playlists (id, name) VALUES ('lobby', 'Lobby'), ('office', 'Office')",
+            "INSERT INTO tabs (id, name, url) VALUES
+                ('news', 'News', 'https://example.com/news'),
+                ('weather', 'Weather', 'https://example.com/weather'),
+                ('menu', 'Menu', 'https://example.com/menu')",
+            "INSERT INTO playlist_tabs (playlist_id, tab_id, order_index, duration_seconds, enabled)
+             VALUES ('lobby', 'weather', 0, 20, TRUE),
+                    ('lobby', 'news', 1, NULL, FALSE),
+                    ('lobby', 'menu', 2, 45, TRUE),
+                    ('office', 'news', 0, 10, TRUE)",
+        ] {
+            sqlx::query(statement).execute(&pool).await.unwrap();
+        }
+        pool
+    }
+
+    async fn entries(
+        pool: &SqlitePool,
+    ) -> Vec<(String, String, String, i64, Option<i64>, bool, i64)> {
+        sqlx::query(
+            "SELECT id, playlist_id, tab_id, order_index, duration_seconds, enabled, weight
+             FROM playlist_tabs ORDER BY playlist_id, order_index",
+        )
+        .fetch_all(pool)
+        .await
+        .unwrap()
+        .iter()
+        .map(|row| {
+            (
+                row.get("id"),
+                row.get("playlist_id"),
+                row.get("tab_id"),
+                row.get("order_index"),
+                row.get("duration_seconds"),
+                row.get("enabled"),
+                row.get("weight"),
+            )
+        })
+        .collect()
+    }
+
+    #[async_std::test]
+    async fn migrates_playlist_entries_to_their_own_ids() {
+        let pool = old_database().await;
+        run_migrations(&pool).await.unwrap();
+
+        let migrated = entries(&pool).await;
+        let rows: Vec<_> = migrated
+            .iter()
+            .map(
+                |(_, playlist_id, tab_id, order_index, duration, enabled, weight)| {
+                    (
+                        playlist_id.as_str(),
+                        tab_id.as_str(),
+                        *order_index,
+                        *duration,
+                        *enabled,
+                        *weight,
+                    )
+                },
+            )
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                ("lobby", "weather", 0, Some(20), true, 1),
+                ("lobby", "news", 1, None, false, 1),
+                ("lobby", "menu", 2, Some(45), true, 1),
+                ("office", "news", 0, Some(10), true, 1),
+            ]
+        );
+        let ids: HashSet<_> = migrated.iter().map(|entry| entry.0.as_str()).collect();
+        assert_eq!(ids.len(), migrated.len());
+        assert!(ids.iter().all(|id| uuid::Uuid::parse_str(id).is_ok()));
+
+        // A tab can now be listed twice in the same playlist
+        sqlx::query(
+            "INSERT INTO playlist_tabs (id, playlist_id, tab_id, order_index) VALUES ('again', 'lobby', 'news', 3)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        // Running the migrations again keeps the entry ids
+        run_migrations(&pool).await.unwrap();
+        let rerun = entries(&pool).await;
+        assert_eq!(rerun.len(), migrated.len() + 1);
+        assert!(migrated.iter().all(|entry| rerun.contains(entry)));
+    }
+}
@@ -1,18 +1,77 @@
 use chrono::{DateTime, Utc};
-use poem_openapi::Object;
+use poem_openapi::{types::MaybeUndefined, Enum, Object};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
+/// Order in which a playlist rotates through its tabs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Enum, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[oai(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum RotationMode {
+    /// Follow the order of the tabs
+    #[default]
+    Sequential,
+    /// Random order, every tab is shown once before any repeats
+    Shuffle,
+    /// Random tab on every turn, picked proportionally to its weight
+    Weighted,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Playlist {
     pub id: String,
     pub name: String,
     pub interval_seconds: i64,
     pub is_active: bool,
+    pub rotation_mode: RotationMode,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// How a tab's target viewport is mapped onto the physical display
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Enum, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[oai(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum ScalingMode {
+    /// Scale the target viewport to fit inside the display, letterboxing if needed
+    #[default]
+    Fit,
+    /// Scale the target viewport to cover the whole display, cropping if needed
+    Fill,
+    /// Render the target viewport 1:1 without scaling
+    Native,
+}
+
+/// What a tab displays
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Enum, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[oai(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum TabContentType {
+    /// The web page at `url`
+    #[default]
+    Url,
+    /// The image at `url`, scaled to fit the screen
+    Image,
+    /// The video at `url`, muted and looping
+    Video,
+    /// The PDF document at `url`
+    Pdf,
+    /// Markdown from `content`, rendered to HTML
+    Markdown,
+    /// Raw HTML from `content`
+    Html,
+}
+
+impl TabContentType {
+    /// Whether the tab body comes from `content` instead of `url`
+    pub fn uses_content(self) -> bool {
+        matches!(self, Self::Markdown | Self::Html)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Tab {
     pub id: String,
@@ -21,17 +80,26 @@ pub struct Tab {
     pub persist: bool,
     pub viewport_width: Option<i32>,
     pub viewport_height: Option<i32>,
+    pub target_width: Option<i32>,
+    pub target_height: Option<i32>,
+    pub scaling_mode: Option<ScalingMode>,
+    pub content_type: TabContentType,
+    pub content: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct PlaylistTab {
+    /// Id of the entry, a tab may be part of a playlist more than once
+    pub id: String,
     pub playlist_id: String,
     pub tab_id: String,
     pub order_index: i64,
     pub duration_seconds: Option<i64>,
     pub enabled: bool,
+    /// Relative chance of being picked in weighted rotation
+    pub weight: i64,
     pub last_manual_activation: Option<DateTime<Utc>>,
 }
 
@@ -42,6 +110,106 @@ pub struct Setting {
     pub updated_at: DateTime<Utc>,
 }
 
+/// Delivery state of a webhook call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[oai(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum DeliveryStatus {
+    /// Not delivered yet, retries are pending
+    Pending,
+    /// Target responded with a 2xx status
+    Delivered,
+    /// All attempts failed
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct WebhookDelivery {
+    pub id: String,
+    pub url: String,
+    pub event_type: String,
+    pub payload: String,
+    pub status: DeliveryStatus,
+    pub attempts: i64,
+    pub response_status: Option<i64>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// What caused a tab to be shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Enum, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[oai(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum PlayTrigger {
+    /// Automatic rotation, startup or the end of a takeover
+    Rotation,
+    /// The management API, web UI or a local keypad or remote
+    #[default]
+    Manual,
+    /// A Home Assistant / MQTT command
+    Mqtt,
+    /// A scheduled change
+    Schedule,
+}
+
+/// Time span a tab was visible on the display
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PlayRecord {
+    pub id: String,
+    pub tab_id: String,
+    pub playlist_id: String,
+    pub trigger: PlayTrigger,
+    pub started_at: DateTime<Utc>,
+    /// `None` while the tab is still visible
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+/// Dimension display time is aggregated by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[serde(rename_all = "lowercase")]
+#[oai(rename_all = "lowercase")]
+pub enum PlayReportGrouping {
+    Tab,
+    Playlist,
+    /// UTC calendar day the play started on
+    Day,
+}
+
+/// Plays included in a report
+#[derive(Debug, Clone, Default)]
+pub struct PlayReportFilter {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub tab_id: Option<String>,
+    pub playlist_id: Option<String>,
+}
+
+/// Aggregated display time of one tab, playlist or day
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PlayReportRow {
+    pub key: String,
+    pub plays: i64,
+    pub total_seconds: f64,
+}
+
+/// Uploaded file stored in the assets directory
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Asset {
+    pub id: String,
+    /// Original name of the uploaded file
+    pub filename: String,
+    /// Name of the file inside the assets directory
+    pub stored_name: String,
+    pub mime_type: String,
+    pub size: i64,
+    /// Hex encoded SHA-256 of the content
+    pub sha256: String,
+    pub created_at: DateTime<Utc>,
+}
+
 // Combined model for playlist with tabs
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlaylistWithTabs {
@@ -49,6 +217,7 @@ pub struct PlaylistWithTabs {
     pub name: String,
     pub interval_seconds: i64,
     pub is_active: bool,
+    pub rotation_mode: RotationMode,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub tabs: Vec<TabWithOrder>,
@@ -57,14 +226,23 @@ pub struct PlaylistWithTabs {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TabWithOrder {
     pub id: String,
+    /// Playlist entry this tab is shown as
+    pub entry_id: String,
     pub name: String,
     pub url: String,
     pub persist: bool,
     pub viewport_width: Option<i32>,
     pub viewport_height: Option<i32>,
+    pub target_width: Option<i32>,
+    pub target_height: Option<i32>,
+    pub scaling_mode: Option<ScalingMode>,
+    pub content_type: TabContentType,
+    pub content: Option<String>,
     pub order_index: i64,
     pub duration_seconds: Option<i64>,
     pub enabled: bool,
+    /// Relative chance of being picked in weighted rotation
+    pub weight: i64,
     pub last_manual_activation: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -76,6 +254,7 @@ pub struct CreatePlaylistRequest {
     pub id: String,
     pub name: String,
     pub interval_seconds: i64,
+    pub rotation_mode: Option<RotationMode>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Object)]
@@ -83,14 +262,27 @@ pub struct UpdatePlaylistRequest {
     pub name: Option<String>,
     pub interval_seconds: Option<i64>,
     pub is_active: Option<bool>,
+    pub rotation_mode: Option<RotationMode>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Object)]
 pub struct CreateTabRequest {
     pub id: String,
     pub name: String,
+    /// Page or media URL, not needed for `markdown` and `html` tabs
+    #[oai(default)]
+    #[serde(default)]
     pub url: String,
     pub persist: Option<bool>,
+    /// Emulated viewport width in CSS pixels
+    pub target_width: Option<i32>,
+    /// Emulated viewport height in CSS pixels
+    pub target_height: Option<i32>,
+    pub scaling_mode: Option<ScalingMode>,
+    /// What the tab displays, defaults to a web page
+    pub content_type: Option<TabContentType>,
+    /// Markdown or HTML body for `markdown` and `html` tabs
+    pub content: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Object)]
@@ -98,6 +290,28 @@ pub struct UpdateTabRequest {
     pub name: Option<String>,
     pub url: Option<String>,
     pub persist: Option<bool>,
+    /// Emulated viewport width in CSS pixels, `null` removes it
+    #[serde(default)]
+    pub target_width: MaybeUndefined<i32>,
+    /// Emulated viewport height in CSS pixels, `null` removes it
+    #[serde(default)]
+    pub target_height: MaybeUndefined<i32>,
+    /// `null` goes back to the default
+    #[serde(default)]
+    pub scaling_mode: MaybeUndefined<ScalingMode>,
+    /// What the tab displays, defaults to a web page
+    pub content_type: Option<TabContentType>,
+    /// Markdown or HTML body for `markdown` and `html` tabs
+    pub content: Option<String>,
+}
+
+impl UpdateTabRequest {
+    /// Whether the update changes how the page is emulated
+    pub fn changes_viewport(&self) -> bool {
+        !self.target_width.is_undefined()
+            || !self.target_height.is_undefined()
+            || !self.scaling_mode.is_undefined()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Object)]
@@ -106,6 +320,8 @@ pub struct AddTabToPlaylistRequest {
     pub order_index: i64,
     pub duration_seconds: Option<i64>,
     pub enabled: Option<bool>,
+    /// Relative chance of being picked in weighted rotation (default 1)
+    pub weight: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Object)]
@@ -115,7 +331,7 @@ pub struct ReorderTabsRequest {
 
 #[derive(Debug, Clone, Serialize, Deserialize, Object)]
 pub struct TabOrder {
-    pub tab_id: String,
+    pub entry_id: String,
     pub order_index: i64,
 }
 
@@ -123,3 +339,8 @@ pub struct TabOrder {
 pub struct ToggleTabEnabledRequest {
     pub enabled: bool,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct SetTabWeightRequest {
+    pub weight: i64,
+}
@@ -0,0 +1,102 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::{Row, SqlitePool};
+
+use super::AssetRepository;
+use crate::db::models::*;
+
+pub struct SqliteAssetRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteAssetRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+fn asset_from_row(row: &sqlx::sqlite::SqliteRow) -> Asset {
+    Asset {
+        id: row.get("id"),
+        filename: row.get("filename"),
+        stored_name: row.get("stored_name"),
+        mime_type: row.get("mime_type"),
+        size: row.get("size"),
+        sha256: row.get("sha256"),
+        created_at: row.get("created_at"),
+    }
+}
+
+#[async_trait]
+impl AssetRepository for SqliteAssetRepository {
+    async fn create(&self, asset: &Asset) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO assets (id, filename, stored_name, mime_type, size, sha256, created_at) 
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&asset.id)
+        .bind(&asset.filename)
+        .bind(&asset.stored_name)
+        .bind(&asset.mime_type)
+        .bind(asset.size)
+        .bind(&asset.sha256)
+        .bind(asset.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<Option<Asset>> {
+        let row = sqlx::query(
+            "SELECT id, filename, stored_name, mime_type, size, sha256, created_at
+             FROM assets WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.as_ref().map(asset_from_row))
+    }
+
+    async fn get_by_hash(&self, sha256: &str) -> Result<Option<Asset>> {
+        let row = sqlx::query(
+            "SELECT id, filename, stored_name, mime_type, size, sha256, created_at
+             FROM assets WHERE sha256 = ? LIMIT 1",
+        )
+        .bind(sha256)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.as_ref().map(asset_from_row))
+    }
+
+    async fn get_all(&self) -> Result<Vec<Asset>> {
+        let rows = sqlx::query(
+            "SELECT id, filename, stored_name, mime_type, size, sha256, created_at
+             FROM assets ORDER BY created_at DESC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(asset_from_row).collect())
+    }
+
+    async fn delete(&self, id: &str) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM assets WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn total_size(&self) -> Result<i64> {
+        let total: i64 = sqlx::query("SELECT COALESCE(SUM(size), 0) FROM assets")
+            .fetch_one(&self.pool)
+            .await?
+            .get(0);
+
+        Ok(total)
+    }
+}
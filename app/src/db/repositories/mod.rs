@@ -1,9 +1,13 @@
+pub mod asset;
+pub mod play_log;
 pub mod playlist;
 pub mod playlist_tab;
 pub mod tab;
+pub mod webhook_delivery;
 
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 use crate::db::models::*;
 
@@ -39,15 +43,72 @@ pub trait PlaylistTabRepository {
         &self,
         playlist_id: &str,
         request: AddTabToPlaylistRequest,
-    ) -> Result<()>;
-    async fn remove_tab_from_playlist(&self, playlist_id: &str, tab_id: &str) -> Result<bool>;
+    ) -> Result<String>;
+    async fn remove_tab_from_playlist(&self, playlist_id: &str, entry_id: &str) -> Result<bool>;
     async fn get_playlist_tabs(&self, playlist_id: &str) -> Result<Vec<TabWithOrder>>;
+    async fn get_tab_playlists(&self, tab_id: &str) -> Result<Vec<PlaylistTab>>;
     async fn reorder_tabs(&self, playlist_id: &str, request: ReorderTabsRequest) -> Result<()>;
     async fn toggle_tab_enabled(
         &self,
         playlist_id: &str,
-        tab_id: &str,
+        entry_id: &str,
         enabled: bool,
     ) -> Result<bool>;
+    async fn set_tab_weight(&self, playlist_id: &str, entry_id: &str, weight: i64) -> Result<bool>;
     async fn update_manual_activation(&self, playlist_id: &str, tab_id: &str) -> Result<()>;
 }
+
+#[async_trait]
+pub trait WebhookDeliveryRepository {
+    async fn create(&self, delivery: &WebhookDelivery) -> Result<()>;
+    async fn record_attempt(
+        &self,
+        id: &str,
+        status: DeliveryStatus,
+        attempts: i64,
+        response_status: Option<i64>,
+        last_error: Option<&str>,
+    ) -> Result<()>;
+    async fn get_by_id(&self, id: &str) -> Result<Option<WebhookDelivery>>;
+    async fn list(
+        &self,
+        status: Option<DeliveryStatus>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>>;
+    /// Delete finished deliveries created before `before`, returns how many were removed
+    async fn prune(&self, before: DateTime<Utc>) -> Result<u64>;
+    /// Mark deliveries created before `before` that are still pending, i.e. left
+    /// over by an unclean shutdown, as failed, returns how many there were
+    async fn fail_pending(&self, before: DateTime<Utc>, last_error: &str) -> Result<u64>;
+}
+
+#[async_trait]
+pub trait AssetRepository {
+    async fn create(&self, asset: &Asset) -> Result<()>;
+    async fn get_by_id(&self, id: &str) -> Result<Option<Asset>>;
+    async fn get_by_hash(&self, sha256: &str) -> Result<Option<Asset>>;
+    async fn get_all(&self) -> Result<Vec<Asset>>;
+    async fn delete(&self, id: &str) -> Result<bool>;
+    /// Combined size of all assets in bytes
+    async fn total_size(&self) -> Result<i64>;
+}
+
+#[async_trait]
+pub trait PlayLogRepository {
+    async fn start(&self, record: &PlayRecord) -> Result<()>;
+    async fn end(&self, id: &str, ended_at: DateTime<Utc>) -> Result<()>;
+    /// Close plays left open by an unclean shutdown, returns how many there were
+    async fn close_dangling(&self) -> Result<u64>;
+    async fn list(
+        &self,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<Vec<PlayRecord>>;
+    async fn report(
+        &self,
+        group_by: PlayReportGrouping,
+        filter: &PlayReportFilter,
+    ) -> Result<Vec<PlayReportRow>>;
+    /// Delete plays that started before `before`, returns how many were removed
+    async fn prune(&self, before: DateTime<Utc>) -> Result<u64>;
+}
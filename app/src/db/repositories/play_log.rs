@@ -0,0 +1,166 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Row, SqlitePool};
+
+use super::PlayLogRepository;
+use crate::db::models::*;
+
+pub struct SqlitePlayLogRepository {
+    pool: SqlitePool,
+}
+
+impl SqlitePlayLogRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+fn record_from_row(row: &sqlx::sqlite::SqliteRow) -> PlayRecord {
+    PlayRecord {
+        id: row.get("id"),
+        tab_id: row.get("tab_id"),
+        playlist_id: row.get("playlist_id"),
+        trigger: row.get("trigger"),
+        started_at: row.get("started_at"),
+        ended_at: row.get("ended_at"),
+    }
+}
+
+#[async_trait]
+impl PlayLogRepository for SqlitePlayLogRepository {
+    async fn start(&self, record: &PlayRecord) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO play_log (id, tab_id, playlist_id, trigger, started_at, ended_at)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&record.id)
+        .bind(&record.tab_id)
+        .bind(&record.playlist_id)
+        .bind(record.trigger)
+        .bind(record.started_at)
+        .bind(record.ended_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn end(&self, id: &str, ended_at: DateTime<Utc>) -> Result<()> {
+        sqlx::query("UPDATE play_log SET ended_at = ? WHERE id = ? AND ended_at IS NULL")
+            .bind(ended_at)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn close_dangling(&self) -> Result<u64> {
+        // The real end is unknown, so these plays are counted without duration
+        let result =
+            sqlx::query("UPDATE play_log SET ended_at = started_at WHERE ended_at IS NULL")
+                .execute(&self.pool)
+                .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn list(
+        &self,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<Vec<PlayRecord>> {
+        let mut query_builder = sqlx::QueryBuilder::new(
+            "SELECT id, tab_id, playlist_id, trigger, started_at, ended_at
+             FROM play_log WHERE 1 = 1",
+        );
+
+        if let Some(from) = from {
+            query_builder.push(" AND julianday(started_at) >= julianday(");
+            query_builder.push_bind(from);
+            query_builder.push(")");
+        }
+
+        if let Some(to) = to {
+            query_builder.push(" AND julianday(started_at) < julianday(");
+            query_builder.push_bind(to);
+            query_builder.push(")");
+        }
+
+        query_builder.push(" ORDER BY started_at");
+
+        let rows = query_builder.build().fetch_all(&self.pool).await?;
+        Ok(rows.iter().map(record_from_row).collect())
+    }
+
+    async fn report(
+        &self,
+        group_by: PlayReportGrouping,
+        filter: &PlayReportFilter,
+    ) -> Result<Vec<PlayReportRow>> {
+        let key = match group_by {
+            PlayReportGrouping::Tab => "tab_id",
+            PlayReportGrouping::Playlist => "playlist_id",
+            PlayReportGrouping::Day => "date(started_at)",
+        };
+
+        // The play that is still running counts up to now. julianday() is not
+        // exact, so the sum is rounded to milliseconds.
+        let mut query_builder = sqlx::QueryBuilder::new(format!(
+            "SELECT {} AS key, COUNT(*) AS plays,
+                    ROUND(COALESCE(SUM((julianday(COALESCE(ended_at, ",
+            key
+        ));
+        query_builder.push_bind(Utc::now());
+        query_builder.push(
+            ")) - julianday(started_at)) * 86400.0), 0.0), 3) AS total_seconds
+             FROM play_log WHERE 1 = 1",
+        );
+
+        if let Some(from) = filter.from {
+            query_builder.push(" AND julianday(started_at) >= julianday(");
+            query_builder.push_bind(from);
+            query_builder.push(")");
+        }
+
+        if let Some(to) = filter.to {
+            query_builder.push(" AND julianday(started_at) < julianday(");
+            query_builder.push_bind(to);
+            query_builder.push(")");
+        }
+
+        if let Some(tab_id) = &filter.tab_id {
+            query_builder.push(" AND tab_id = ");
+            query_builder.push_bind(tab_id);
+        }
+
+        if let Some(playlist_id) = &filter.playlist_id {
+            query_builder.push(" AND playlist_id = ");
+            query_builder.push_bind(playlist_id);
+        }
+
+        query_builder.push(" GROUP BY key ORDER BY key");
+
+        let rows = query_builder.build().fetch_all(&self.pool).await?;
+        Ok(rows
+            .iter()
+            .map(|row| PlayReportRow {
+                key: row.get("key"),
+                plays: row.get("plays"),
+                total_seconds: row.get("total_seconds"),
+            })
+            .collect())
+    }
+
+    async fn prune(&self, before: DateTime<Utc>) -> Result<u64> {
+        let result = sqlx::query(
+            "DELETE FROM play_log WHERE ended_at IS NOT NULL AND julianday(started_at) < julianday(?)",
+        )
+        .bind(before)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
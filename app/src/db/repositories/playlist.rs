@@ -21,15 +21,17 @@ impl SqlitePlaylistRepository {
 impl PlaylistRepository for SqlitePlaylistRepository {
     async fn create(&self, request: CreatePlaylistRequest) -> Result<Playlist> {
         let now = Utc::now();
+        let rotation_mode = request.rotation_mode.unwrap_or_default();
 
         sqlx::query(
-            "INSERT INTO playlists (id, name, interval_seconds, is_active, created_at, updated_at) 
-             VALUES (?, ?, ?, ?, ?, ?)",
+            "INSERT INTO playlists (id, name, interval_seconds, is_active, rotation_mode, created_at, updated_at) 
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
         )
         .bind(&request.id)
         .bind(&request.name)
         .bind(request.interval_seconds)
         .bind(false)
+        .bind(rotation_mode)
         .bind(now)
         .bind(now)
         .execute(&self.pool)
@@ -40,6 +42,7 @@ impl PlaylistRepository for SqlitePlaylistRepository {
             name: request.name,
             interval_seconds: request.interval_seconds,
             is_active: false,
+            rotation_mode,
             created_at: now,
             updated_at: now,
         })
@@ -47,7 +50,7 @@ impl PlaylistRepository for SqlitePlaylistRepository {
 
     async fn get_by_id(&self, id: &str) -> Result<Option<Playlist>> {
         let row = sqlx::query(
-            "SELECT id, name, interval_seconds, is_active, created_at, updated_at 
+            "SELECT id, name, interval_seconds, is_active, rotation_mode, created_at, updated_at 
              FROM playlists WHERE id = ?",
         )
         .bind(id)
@@ -60,6 +63,7 @@ impl PlaylistRepository for SqlitePlaylistRepository {
                 name: row.get("name"),
                 interval_seconds: row.get("interval_seconds"),
                 is_active: row.get("is_active"),
+                rotation_mode: row.get("rotation_mode"),
                 created_at: row.get("created_at"),
                 updated_at: row.get("updated_at"),
             }))
@@ -70,7 +74,7 @@ impl PlaylistRepository for SqlitePlaylistRepository {
 
     async fn get_all(&self) -> Result<Vec<Playlist>> {
         let rows = sqlx::query(
-            "SELECT id, name, interval_seconds, is_active, created_at, updated_at 
+            "SELECT id, name, interval_seconds, is_active, rotation_mode, created_at, updated_at 
              FROM playlists ORDER BY created_at DESC",
         )
         .fetch_all(&self.pool)
@@ -83,6 +87,7 @@ impl PlaylistRepository for SqlitePlaylistRepository {
                 name: row.get("name"),
                 interval_seconds: row.get("interval_seconds"),
                 is_active: row.get("is_active"),
+                rotation_mode: row.get("rotation_mode"),
                 created_at: row.get("created_at"),
                 updated_at: row.get("updated_at"),
             });
@@ -100,13 +105,14 @@ impl PlaylistRepository for SqlitePlaylistRepository {
 
         if let Some(playlist) = playlist {
             let rows = sqlx::query(
-                "SELECT t.id, t.name, t.url, t.persist, t.viewport_width, t.viewport_height, 
-                        pt.order_index, pt.duration_seconds, pt.enabled, pt.last_manual_activation,
+                "SELECT t.id, pt.id AS entry_id, t.name, t.url, t.persist, t.viewport_width, t.viewport_height,
+                        t.target_width, t.target_height, t.scaling_mode, t.content_type, t.content,
+                        pt.order_index, pt.duration_seconds, pt.enabled, pt.weight, pt.last_manual_activation,
                         t.created_at, t.updated_at
                  FROM tabs t
                  JOIN playlist_tabs pt ON t.id = pt.tab_id
                  WHERE pt.playlist_id = ?
-                 ORDER BY pt.order_index",
+                 ORDER BY pt.order_index, pt.rowid",
             )
             .bind(id)
             .fetch_all(&self.pool)
@@ -116,14 +122,21 @@ impl PlaylistRepository for SqlitePlaylistRepository {
             for row in rows {
                 tabs.push(TabWithOrder {
                     id: row.get("id"),
+                    entry_id: row.get("entry_id"),
                     name: row.get("name"),
                     url: row.get("url"),
                     persist: row.get("persist"),
                     viewport_width: row.get("viewport_width"),
                     viewport_height: row.get("viewport_height"),
+                    target_width: row.get("target_width"),
+                    target_height: row.get("target_height"),
+                    scaling_mode: row.get("scaling_mode"),
+                    content_type: row.get("content_type"),
+                    content: row.get("content"),
                     order_index: row.get("order_index"),
                     duration_seconds: row.get("duration_seconds"),
                     enabled: row.get("enabled"),
+                    weight: row.get("weight"),
                     last_manual_activation: row.get("last_manual_activation"),
                     created_at: row.get("created_at"),
                     updated_at: row.get("updated_at"),
@@ -135,6 +148,7 @@ impl PlaylistRepository for SqlitePlaylistRepository {
                 name: playlist.name,
                 interval_seconds: playlist.interval_seconds,
                 is_active: playlist.is_active,
+                rotation_mode: playlist.rotation_mode,
                 created_at: playlist.created_at,
                 updated_at: playlist.updated_at,
                 tabs,
@@ -191,6 +205,15 @@ impl PlaylistRepository for SqlitePlaylistRepository {
             first = false;
         }
 
+        if let Some(rotation_mode) = request.rotation_mode {
+            if !first {
+                query_builder.push(", ");
+            }
+            query_builder.push("rotation_mode = ");
+            query_builder.push_bind(rotation_mode);
+            first = false;
+        }
+
         if first {
             return self.get_by_id(id).await;
         }
@@ -237,7 +260,7 @@ impl PlaylistRepository for SqlitePlaylistRepository {
 
     async fn get_active(&self) -> Result<Option<Playlist>> {
         let row = sqlx::query(
-            "SELECT id, name, interval_seconds, is_active, created_at, updated_at 
+            "SELECT id, name, interval_seconds, is_active, rotation_mode, created_at, updated_at 
              FROM playlists WHERE is_active = true LIMIT 1",
         )
         .fetch_optional(&self.pool)
@@ -249,6 +272,7 @@ impl PlaylistRepository for SqlitePlaylistRepository {
                 name: row.get("name"),
                 interval_seconds: row.get("interval_seconds"),
                 is_active: row.get("is_active"),
+                rotation_mode: row.get("rotation_mode"),
                 created_at: row.get("created_at"),
                 updated_at: row.get("updated_at"),
             }))
@@ -259,11 +283,12 @@ impl PlaylistRepository for SqlitePlaylistRepository {
 
     async fn get_tabs(&self, playlist_id: &str) -> Result<Vec<Tab>> {
         let rows = sqlx::query(
-            "SELECT t.id, t.name, t.url, t.persist, t.viewport_width, t.viewport_height, t.created_at, t.updated_at
+            "SELECT t.id, t.name, t.url, t.persist, t.viewport_width, t.viewport_height,
+                    t.target_width, t.target_height, t.scaling_mode, t.content_type, t.content, t.created_at, t.updated_at
              FROM tabs t
              JOIN playlist_tabs pt ON t.id = pt.tab_id
              WHERE pt.playlist_id = ?
-             ORDER BY pt.order_index"
+             ORDER BY pt.order_index",
         )
         .bind(playlist_id)
         .fetch_all(&self.pool)
@@ -278,6 +303,11 @@ impl PlaylistRepository for SqlitePlaylistRepository {
                 persist: row.get("persist"),
                 viewport_width: row.get("viewport_width"),
                 viewport_height: row.get("viewport_height"),
+                target_width: row.get("target_width"),
+                target_height: row.get("target_height"),
+                scaling_mode: row.get("scaling_mode"),
+                content_type: row.get("content_type"),
+                content: row.get("content"),
                 created_at: row.get("created_at"),
                 updated_at: row.get("updated_at"),
             });
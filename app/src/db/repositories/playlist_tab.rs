@@ -21,7 +21,7 @@ impl PlaylistTabRepository for SqlitePlaylistTabRepository {
         &self,
         playlist_id: &str,
         request: AddTabToPlaylistRequest,
-    ) -> Result<()> {
+    ) -> Result<String> {
         // First check if the tab exists
         let tab_count: i64 = sqlx::query("SELECT COUNT(*) FROM tabs WHERE id = ?")
             .bind(&request.tab_id)
@@ -50,26 +50,29 @@ impl PlaylistTabRepository for SqlitePlaylistTabRepository {
             ));
         }
 
-        // Insert or update the playlist-tab relationship
+        // Every call adds a new entry, the tab may already be in the playlist
+        let id = uuid::Uuid::new_v4().to_string();
         sqlx::query(
-            "INSERT OR REPLACE INTO playlist_tabs (playlist_id, tab_id, order_index, duration_seconds, enabled) 
-             VALUES (?, ?, ?, ?, ?)"
+            "INSERT INTO playlist_tabs (id, playlist_id, tab_id, order_index, duration_seconds, enabled, weight) 
+             VALUES (?, ?, ?, ?, ?, ?, ?)"
         )
+        .bind(&id)
         .bind(playlist_id)
         .bind(&request.tab_id)
         .bind(request.order_index)
         .bind(request.duration_seconds)
         .bind(request.enabled.unwrap_or(true))
+        .bind(request.weight.unwrap_or(1))
         .execute(&self.pool)
         .await?;
 
-        Ok(())
+        Ok(id)
     }
 
-    async fn remove_tab_from_playlist(&self, playlist_id: &str, tab_id: &str) -> Result<bool> {
-        let result = sqlx::query("DELETE FROM playlist_tabs WHERE playlist_id = ? AND tab_id = ?")
+    async fn remove_tab_from_playlist(&self, playlist_id: &str, entry_id: &str) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM playlist_tabs WHERE playlist_id = ? AND id = ?")
             .bind(playlist_id)
-            .bind(tab_id)
+            .bind(entry_id)
             .execute(&self.pool)
             .await?;
 
@@ -78,13 +81,14 @@ impl PlaylistTabRepository for SqlitePlaylistTabRepository {
 
     async fn get_playlist_tabs(&self, playlist_id: &str) -> Result<Vec<TabWithOrder>> {
         let rows = sqlx::query(
-            "SELECT t.id, t.name, t.url, t.persist, t.viewport_width, t.viewport_height, 
-                    pt.order_index, pt.duration_seconds, pt.enabled, pt.last_manual_activation,
+            "SELECT t.id, pt.id AS entry_id, t.name, t.url, t.persist, t.viewport_width, t.viewport_height,
+                    t.target_width, t.target_height, t.scaling_mode, t.content_type, t.content,
+                    pt.order_index, pt.duration_seconds, pt.enabled, pt.weight, pt.last_manual_activation,
                     t.created_at, t.updated_at
              FROM tabs t
              JOIN playlist_tabs pt ON t.id = pt.tab_id
              WHERE pt.playlist_id = ?
-             ORDER BY pt.order_index",
+             ORDER BY pt.order_index, pt.rowid",
         )
         .bind(playlist_id)
         .fetch_all(&self.pool)
@@ -94,14 +98,21 @@ impl PlaylistTabRepository for SqlitePlaylistTabRepository {
         for row in rows {
             tabs.push(TabWithOrder {
                 id: row.get("id"),
+                entry_id: row.get("entry_id"),
                 name: row.get("name"),
                 url: row.get("url"),
                 persist: row.get("persist"),
                 viewport_width: row.get("viewport_width"),
                 viewport_height: row.get("viewport_height"),
+                target_width: row.get("target_width"),
+                target_height: row.get("target_height"),
+                scaling_mode: row.get("scaling_mode"),
+                content_type: row.get("content_type"),
+                content: row.get("content"),
                 order_index: row.get("order_index"),
                 duration_seconds: row.get("duration_seconds"),
                 enabled: row.get("enabled"),
+                weight: row.get("weight"),
                 last_manual_activation: row.get("last_manual_activation"),
                 created_at: row.get("created_at"),
                 updated_at: row.get("updated_at"),
@@ -111,18 +122,46 @@ impl PlaylistTabRepository for SqlitePlaylistTabRepository {
         Ok(tabs)
     }
 
+    async fn get_tab_playlists(&self, tab_id: &str) -> Result<Vec<PlaylistTab>> {
+        let rows = sqlx::query(
+            "SELECT id, playlist_id, tab_id, order_index, duration_seconds, enabled, weight, last_manual_activation
+             FROM playlist_tabs
+             WHERE tab_id = ?
+             ORDER BY playlist_id, order_index",
+        )
+        .bind(tab_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut entries = Vec::new();
+        for row in rows {
+            entries.push(PlaylistTab {
+                id: row.get("id"),
+                playlist_id: row.get("playlist_id"),
+                tab_id: row.get("tab_id"),
+                order_index: row.get("order_index"),
+                duration_seconds: row.get("duration_seconds"),
+                enabled: row.get("enabled"),
+                weight: row.get("weight"),
+                last_manual_activation: row.get("last_manual_activation"),
+            });
+        }
+
+        Ok(entries)
+    }
+
     async fn reorder_tabs(&self, playlist_id: &str, request: ReorderTabsRequest) -> Result<()> {
         // Start a transaction
         let mut tx = self.pool.begin().await?;
 
-        // Update the order for each tab
+        // Update the order for each entry
         for tab_order in request.tab_orders {
             sqlx::query(
-                "UPDATE playlist_tabs SET order_index = ? WHERE playlist_id = ? AND tab_id = ?",
+                "UPDATE playlist_tabs SET order_index = ? WHERE playlist_id = ? AND id = ?",
             )
             .bind(tab_order.order_index)
             .bind(playlist_id)
-            .bind(tab_order.tab_id)
+            .bind(tab_order.entry_id)
             .execute(&mut *tx)
             .await?;
         }
@@ -136,17 +175,28 @@ impl PlaylistTabRepository for SqlitePlaylistTabRepository {
     async fn toggle_tab_enabled(
         &self,
         playlist_id: &str,
-        tab_id: &str,
+        entry_id: &str,
         enabled: bool,
     ) -> Result<bool> {
-        let result = sqlx::query(
-            "UPDATE playlist_tabs SET enabled = ? WHERE playlist_id = ? AND tab_id = ?",
-        )
-        .bind(enabled)
-        .bind(playlist_id)
-        .bind(tab_id)
-        .execute(&self.pool)
-        .await?;
+        let result =
+            sqlx::query("UPDATE playlist_tabs SET enabled = ? WHERE playlist_id = ? AND id = ?")
+                .bind(enabled)
+                .bind(playlist_id)
+                .bind(entry_id)
+                .execute(&self.pool)
+                .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn set_tab_weight(&self, playlist_id: &str, entry_id: &str, weight: i64) -> Result<bool> {
+        let result =
+            sqlx::query("UPDATE playlist_tabs SET weight = ? WHERE playlist_id = ? AND id = ?")
+                .bind(weight)
+                .bind(playlist_id)
+                .bind(entry_id)
+                .execute(&self.pool)
+                .await?;
 
         Ok(result.rows_affected() > 0)
     }
@@ -21,17 +21,25 @@ impl TabRepository for SqliteTabRepository {
     async fn create(&self, request: CreateTabRequest) -> Result<Tab> {
         let now = Utc::now();
         let persist = request.persist.unwrap_or(true);
+        let content_type = request.content_type.unwrap_or_default();
 
         sqlx::query(
-            "INSERT INTO tabs (id, name, url, persist, viewport_width, viewport_height, created_at, updated_at) 
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?)"
+            "INSERT INTO tabs (id, name, url, persist, viewport_width, viewport_height,
+                               target_width, target_height, scaling_mode, content_type, content,
+                               created_at, updated_at) 
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         )
         .bind(&request.id)
         .bind(&request.name)
         .bind(&request.url)
         .bind(persist)
-        .bind(None::<i32>)  // viewport_width
-        .bind(None::<i32>)  // viewport_height
+        .bind(None::<i32>) // viewport_width
+        .bind(None::<i32>) // viewport_height
+        .bind(request.target_width)
+        .bind(request.target_height)
+        .bind(request.scaling_mode)
+        .bind(content_type)
+        .bind(&request.content)
         .bind(now)
         .bind(now)
         .execute(&self.pool)
@@ -44,6 +52,11 @@ impl TabRepository for SqliteTabRepository {
             persist,
             viewport_width: None,
             viewport_height: None,
+            target_width: request.target_width,
+            target_height: request.target_height,
+            scaling_mode: request.scaling_mode,
+            content_type,
+            content: request.content,
             created_at: now,
             updated_at: now,
         })
@@ -51,8 +64,10 @@ impl TabRepository for SqliteTabRepository {
 
     async fn get_by_id(&self, id: &str) -> Result<Option<Tab>> {
         let row = sqlx::query(
-            "SELECT id, name, url, persist, viewport_width, viewport_height, created_at, updated_at 
-             FROM tabs WHERE id = ?"
+            "SELECT id, name, url, persist, viewport_width, viewport_height,
+                    target_width, target_height, scaling_mode, content_type, content,
+                    created_at, updated_at
+             FROM tabs WHERE id = ?",
         )
         .bind(id)
         .fetch_optional(&self.pool)
@@ -66,6 +81,11 @@ impl TabRepository for SqliteTabRepository {
                 persist: row.get("persist"),
                 viewport_width: row.get("viewport_width"),
                 viewport_height: row.get("viewport_height"),
+                target_width: row.get("target_width"),
+                target_height: row.get("target_height"),
+                scaling_mode: row.get("scaling_mode"),
+                content_type: row.get("content_type"),
+                content: row.get("content"),
                 created_at: row.get("created_at"),
                 updated_at: row.get("updated_at"),
             }))
@@ -76,8 +96,10 @@ impl TabRepository for SqliteTabRepository {
 
     async fn get_all(&self) -> Result<Vec<Tab>> {
         let rows = sqlx::query(
-            "SELECT id, name, url, persist, viewport_width, viewport_height, created_at, updated_at 
-             FROM tabs ORDER BY created_at DESC"
+            "SELECT id, name, url, persist, viewport_width, viewport_height,
+                    target_width, target_height, scaling_mode, content_type, content,
+                    created_at, updated_at
+             FROM tabs ORDER BY created_at DESC",
         )
         .fetch_all(&self.pool)
         .await?;
@@ -91,6 +113,11 @@ impl TabRepository for SqliteTabRepository {
                 persist: row.get("persist"),
                 viewport_width: row.get("viewport_width"),
                 viewport_height: row.get("viewport_height"),
+                target_width: row.get("target_width"),
+                target_height: row.get("target_height"),
+                scaling_mode: row.get("scaling_mode"),
+                content_type: row.get("content_type"),
+                content: row.get("content"),
                 created_at: row.get("created_at"),
                 updated_at: row.get("updated_at"),
             });
@@ -133,6 +160,51 @@ impl TabRepository for SqliteTabRepository {
             first = false;
         }
 
+        if let Some(target_width) = request.target_width.as_opt_ref() {
+            if !first {
+                query_builder.push(", ");
+            }
+            query_builder.push("target_width = ");
+            query_builder.push_bind(target_width.copied());
+            first = false;
+        }
+
+        if let Some(target_height) = request.target_height.as_opt_ref() {
+            if !first {
+                query_builder.push(", ");
+            }
+            query_builder.push("target_height = ");
+            query_builder.push_bind(target_height.copied());
+            first = false;
+        }
+
+        if let Some(scaling_mode) = request.scaling_mode.as_opt_ref() {
+            if !first {
+                query_builder.push(", ");
+            }
+            query_builder.push("scaling_mode = ");
+            query_builder.push_bind(scaling_mode.copied());
+            first = false;
+        }
+
+        if let Some(content_type) = request.content_type {
+            if !first {
+                query_builder.push(", ");
+            }
+            query_builder.push("content_type = ");
+            query_builder.push_bind(content_type);
+            first = false;
+        }
+
+        if let Some(content) = &request.content {
+            if !first {
+                query_builder.push(", ");
+            }
+            query_builder.push("content = ");
+            query_builder.push_bind(content);
+            first = false;
+        }
+
         if first {
             return self.get_by_id(id).await;
         }
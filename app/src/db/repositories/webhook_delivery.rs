@@ -0,0 +1,149 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Row, SqlitePool};
+
+use super::WebhookDeliveryRepository;
+use crate::db::models::*;
+
+pub struct SqliteWebhookDeliveryRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteWebhookDeliveryRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+fn delivery_from_row(row: &sqlx::sqlite::SqliteRow) -> WebhookDelivery {
+    WebhookDelivery {
+        id: row.get("id"),
+        url: row.get("url"),
+        event_type: row.get("event_type"),
+        payload: row.get("payload"),
+        status: row.get("status"),
+        attempts: row.get("attempts"),
+        response_status: row.get("response_status"),
+        last_error: row.get("last_error"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for SqliteWebhookDeliveryRepository {
+    async fn create(&self, delivery: &WebhookDelivery) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO webhook_deliveries (id, url, event_type, payload, status, attempts, 
+                                             response_status, last_error, created_at, updated_at) 
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&delivery.id)
+        .bind(&delivery.url)
+        .bind(&delivery.event_type)
+        .bind(&delivery.payload)
+        .bind(delivery.status)
+        .bind(delivery.attempts)
+        .bind(delivery.response_status)
+        .bind(&delivery.last_error)
+        .bind(delivery.created_at)
+        .bind(delivery.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn record_attempt(
+        &self,
+        id: &str,
+        status: DeliveryStatus,
+        attempts: i64,
+        response_status: Option<i64>,
+        last_error: Option<&str>,
+    ) -> Result<()> {
+        let now = Utc::now();
+
+        sqlx::query(
+            "UPDATE webhook_deliveries 
+             SET status = ?, attempts = ?, response_status = ?, last_error = ?, updated_at = ? 
+             WHERE id = ?",
+        )
+        .bind(status)
+        .bind(attempts)
+        .bind(response_status)
+        .bind(last_error)
+        .bind(now)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<Option<WebhookDelivery>> {
+        let row = sqlx::query(
+            "SELECT id, url, event_type, payload, status, attempts, response_status, last_error,
+                    created_at, updated_at
+             FROM webhook_deliveries WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.as_ref().map(delivery_from_row))
+    }
+
+    async fn list(
+        &self,
+        status: Option<DeliveryStatus>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>> {
+        let mut query_builder = sqlx::QueryBuilder::new(
+            "SELECT id, url, event_type, payload, status, attempts, response_status, last_error,
+                    created_at, updated_at
+             FROM webhook_deliveries",
+        );
+
+        if let Some(status) = status {
+            query_builder.push(" WHERE status = ");
+            query_builder.push_bind(status);
+        }
+
+        query_builder.push(" ORDER BY created_at DESC LIMIT ");
+        query_builder.push_bind(limit);
+
+        let rows = query_builder.build().fetch_all(&self.pool).await?;
+
+        Ok(rows.iter().map(delivery_from_row).collect())
+    }
+
+    async fn prune(&self, before: DateTime<Utc>) -> Result<u64> {
+        let result = sqlx::query(
+            "DELETE FROM webhook_deliveries WHERE status != ? AND julianday(created_at) < julianday(?)",
+        )
+        .bind(DeliveryStatus::Pending)
+        .bind(before)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn fail_pending(&self, before: DateTime<Utc>, last_error: &str) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE webhook_deliveries SET status = ?, last_error = ?, updated_at = ?
+             WHERE status = ? AND julianday(created_at) < julianday(?)",
+        )
+        .bind(DeliveryStatus::Failed)
+        .bind(last_error)
+        .bind(Utc::now())
+        .bind(DeliveryStatus::Pending)
+        .bind(before)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
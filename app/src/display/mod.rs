@@ -1,6 +1,12 @@
 use anyhow::{Context, Result};
 use std::process::Command;
 use std::time::Duration;
+use tracing::warn;
+
+use crate::{
+    events::{DisplayPowerChangedEvent, DomainEvent},
+    state::State,
+};
 
 fn run_command(cmd: &str, args: &[&str]) -> Result<()> {
     let status = Command::new(cmd)
@@ -40,6 +46,45 @@ pub fn set_dpms(on: bool, output: Option<&str>) -> Result<()> {
     )
 }
 
+/// Switch the display on or off, publish the change and report it on the
+/// Home Assistant backlight switch
+pub fn set_power(state: &State, on: bool) -> Result<()> {
+    let output = state.config.display.output.as_deref();
+    let result = set_dpms(on, output);
+    match &result {
+        Ok(()) => {
+            state
+                .events
+                .publish(DomainEvent::DisplayPowerChanged(DisplayPowerChangedEvent {
+                    on,
+                }))
+        }
+        Err(err) => warn!("Failed to set DPMS via swaymsg/wlr-randr: {}", err),
+    }
+
+    state
+        .hass
+        .backlight_entity
+        .update_state(&state.hass.mqtt_client, if on { "ON" } else { "OFF" });
+    result
+}
+
+/// Set the brightness (0.0 - 1.0) via DDC and report it on the Home
+/// Assistant brightness number
+pub fn set_brightness(state: &State, value: f32) -> Result<()> {
+    let display_target = state.config.display.ddcutil_display.as_deref();
+    let result = set_ddc_brightness(display_target, value);
+    if let Err(err) = &result {
+        warn!("Failed to set brightness via ddcutil: {}", err);
+    }
+
+    state
+        .hass
+        .brightness_entity
+        .update_state(&state.hass.mqtt_client, &value.to_string());
+    result
+}
+
 pub fn set_ddc_brightness(display: Option<&str>, value: f32) -> Result<()> {
     // DDC brightness VCP code 0x10 expects 0-100 integer
     let clamped = value.clamp(0.0, 1.0);
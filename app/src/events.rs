@@ -0,0 +1,196 @@
+use poem_openapi::{Enum, Object, Union};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use tracing::debug;
+
+const EVENT_BUS_CAPACITY: usize = 256;
+
+/// A tab was brought to front
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct TabActivatedEvent {
+    /// Tab that is now visible
+    pub tab_id: String,
+    /// Playlist the tab was activated in
+    pub playlist_id: String,
+    /// URL the tab displays
+    pub url: String,
+}
+
+/// A playlist was created, activated, edited or deleted
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct PlaylistChangedEvent {
+    /// Playlist that changed
+    pub playlist_id: String,
+    /// What happened to the playlist
+    pub change: PlaylistChange,
+}
+
+/// Kind of playlist change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[oai(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PlaylistChange {
+    /// The playlist was created
+    Created,
+    /// The playlist is now the active one
+    Activated,
+    /// Name, interval or rotation mode were changed
+    Updated,
+    /// Entries were added, removed, reordered, weighted or toggled
+    EntriesChanged,
+    /// The playlist was deleted
+    Deleted,
+}
+
+/// A tab was created, edited or deleted
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct TabChangedEvent {
+    /// Tab that changed
+    pub tab_id: String,
+    /// What happened to the tab
+    pub change: TabChange,
+}
+
+/// Kind of tab change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[oai(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum TabChange {
+    Created,
+    Updated,
+    Deleted,
+}
+
+/// The renderer of a tab crashed
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct TabCrashedEvent {
+    /// Tab whose page crashed
+    pub tab_id: String,
+}
+
+/// A tab failed to load or activate
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct TabFailedEvent {
+    /// Tab that failed
+    pub tab_id: String,
+    /// Error of the failed attempt
+    pub error: String,
+    /// Number of failed attempts in a row
+    pub consecutive_failures: u32,
+}
+
+/// Chromium was launched or exited
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct BrowserStatusChangedEvent {
+    /// Whether Chromium is now running
+    pub running: bool,
+}
+
+/// Chromium exited unexpectedly and a relaunch was attempted
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct BrowserCrashedEvent {
+    /// Whether Chromium is running again
+    pub restarted: bool,
+    /// Why the relaunch failed
+    pub error: Option<String>,
+}
+
+/// The display was switched on or off
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct DisplayPowerChangedEvent {
+    /// Whether the display is now on
+    pub on: bool,
+}
+
+/// An ad-hoc URL took over the display
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct TakeoverStartedEvent {
+    /// URL shown during the takeover
+    pub url: String,
+    /// How long the takeover lasts
+    pub duration_seconds: u64,
+}
+
+/// A takeover ended and the playlist was restored
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct TakeoverEndedEvent {
+    /// URL that was shown during the takeover
+    pub url: String,
+    /// True if the takeover ran out, false if it was cancelled or overridden
+    pub expired: bool,
+}
+
+/// Domain event published by the controller, discriminated by `type`
+// There is no config reloaded event, the config is only read at startup
+#[derive(Debug, Clone, Serialize, Deserialize, Union)]
+#[oai(discriminator_name = "type")]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DomainEvent {
+    #[oai(mapping = "tab_activated")]
+    TabActivated(TabActivatedEvent),
+    #[oai(mapping = "playlist_changed")]
+    PlaylistChanged(PlaylistChangedEvent),
+    #[oai(mapping = "tab_changed")]
+    TabChanged(TabChangedEvent),
+    #[oai(mapping = "tab_crashed")]
+    TabCrashed(TabCrashedEvent),
+    #[oai(mapping = "tab_failed")]
+    TabFailed(TabFailedEvent),
+    #[oai(mapping = "browser_status_changed")]
+    BrowserStatusChanged(BrowserStatusChangedEvent),
+    #[oai(mapping = "browser_crashed")]
+    BrowserCrashed(BrowserCrashedEvent),
+    #[oai(mapping = "display_power_changed")]
+    DisplayPowerChanged(DisplayPowerChangedEvent),
+    #[oai(mapping = "takeover_started")]
+    TakeoverStarted(TakeoverStartedEvent),
+    #[oai(mapping = "takeover_ended")]
+    TakeoverEnded(TakeoverEndedEvent),
+}
+
+impl DomainEvent {
+    /// Name of the event, matching the `type` discriminator
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            DomainEvent::TabActivated(_) => "tab_activated",
+            DomainEvent::PlaylistChanged(_) => "playlist_changed",
+            DomainEvent::TabChanged(_) => "tab_changed",
+            DomainEvent::TabCrashed(_) => "tab_crashed",
+            DomainEvent::TabFailed(_) => "tab_failed",
+            DomainEvent::BrowserStatusChanged(_) => "browser_status_changed",
+            DomainEvent::BrowserCrashed(_) => "browser_crashed",
+            DomainEvent::DisplayPowerChanged(_) => "display_power_changed",
+            DomainEvent::TakeoverStarted(_) => "takeover_started",
+            DomainEvent::TakeoverEnded(_) => "takeover_ended",
+        }
+    }
+}
+
+/// In-process broadcast of domain events to any number of subscribers
+#[derive(Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<DomainEvent>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(EVENT_BUS_CAPACITY);
+        Self { sender }
+    }
+
+    /// Publish an event; events are dropped if nobody is subscribed
+    pub fn publish(&self, event: DomainEvent) {
+        debug!("event: {:?}", event);
+        let _ = self.sender.send(event);
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<DomainEvent> {
+        self.sender.subscribe()
+    }
+}
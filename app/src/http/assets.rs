@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use poem::{
+    handler,
+    http::{header, HeaderValue, StatusCode},
+    web::{Data, Path, StaticFileRequest},
+    IntoResponse, Response,
+};
+use tracing::warn;
+
+use crate::{db::repositories::AssetRepository, state::AppState};
+
+/// Serve the file of an uploaded asset, with range and caching support
+#[handler]
+pub async fn asset_file(
+    state: Data<&Arc<AppState>>,
+    asset_id: Path<String>,
+    request: StaticFileRequest,
+) -> Response {
+    let asset = match state.asset_repository.get_by_id(&asset_id.0).await {
+        Ok(Some(asset)) => asset,
+        Ok(None) => {
+            return Response::builder()
+                .status(StatusCode::NOT_FOUND)
+                .body(format!("Asset {} not found", asset_id.0))
+        }
+        Err(e) => {
+            warn!("asset_file: failed to load asset {}: {}", asset_id.0, e);
+            return Response::builder()
+                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                .body("Failed to load asset".to_string());
+        }
+    };
+
+    let path = state.config.assets.file_path(&asset);
+    let mut response = match request.create_response(&path, false, false) {
+        Ok(response) => response.into_response(),
+        Err(e) => return poem::Error::from(e).into_response(),
+    };
+    if let Ok(mime_type) = HeaderValue::from_str(&asset.mime_type) {
+        response
+            .headers_mut()
+            .insert(header::CONTENT_TYPE, mime_type);
+    }
+    response
+}
@@ -0,0 +1,154 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use poem::{
+    handler,
+    http::{header, StatusCode},
+    web::{Data, Path},
+    Response,
+};
+use pulldown_cmark::{html, Options, Parser};
+use tracing::warn;
+
+use crate::{
+    config::DeviceConfig,
+    db::{
+        models::{Tab, TabContentType},
+        repositories::TabRepository,
+    },
+    state::AppState,
+    template,
+};
+
+use super::local_url;
+
+/// URL Chromium loads for a tab: the tab's own URL (with variables resolved)
+/// for web pages, the internal content route of this server for everything else.
+pub fn page_url(tab: &Tab, device: &DeviceConfig) -> Result<String> {
+    match tab.content_type {
+        TabContentType::Url => template::render(&tab.url, device),
+        _ => Ok(local_url(&format!("/content/{}", tab.id))),
+    }
+}
+
+#[handler]
+pub async fn tab_content(state: Data<&Arc<AppState>>, tab_id: Path<String>) -> Response {
+    let tab = match state.tab_repository.get_by_id(&tab_id.0).await {
+        Ok(Some(tab)) => tab,
+        Ok(None) => {
+            return Response::builder()
+                .status(StatusCode::NOT_FOUND)
+                .body(format!("Tab {} not found", tab_id.0))
+        }
+        Err(e) => {
+            warn!("tab_content: failed to load tab {}: {}", tab_id.0, e);
+            return Response::builder()
+                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                .body("Failed to load tab".to_string());
+        }
+    };
+
+    let url = match template::render(&tab.url, &state.config.device) {
+        Ok(url) => url,
+        Err(e) => {
+            warn!("tab_content: invalid url of tab {}: {}", tab.id, e);
+            return Response::builder()
+                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                .body(format!("Invalid url: {}", e));
+        }
+    };
+
+    let body = match tab.content_type {
+        TabContentType::Url => {
+            return Response::builder()
+                .status(StatusCode::FOUND)
+                .header(header::LOCATION, url)
+                .finish()
+        }
+        TabContentType::Html => tab.content.unwrap_or_default(),
+        TabContentType::Markdown => render_markdown(&tab),
+        TabContentType::Image => media_document(
+            &tab.name,
+            &format!(r#"<img src="{}" alt="">"#, escape(&url)),
+        ),
+        TabContentType::Video => media_document(
+            &tab.name,
+            &format!(
+                r#"<video src="{}" autoplay loop muted playsinline></video>"#,
+                escape(&url)
+            ),
+        ),
+        TabContentType::Pdf => media_document(
+            &tab.name,
+            &format!(
+                r#"<embed src="{}#toolbar=0&amp;navpanes=0&amp;view=Fit" type="application/pdf">"#,
+                escape(&url)
+            ),
+        ),
+    };
+
+    Response::builder()
+        .header(header::CACHE_CONTROL, "no-store")
+        .content_type("text/html; charset=utf-8")
+        .body(body)
+}
+
+/// Full screen document on black background with a single media element
+fn media_document(title: &str, element: &str) -> String {
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+html, body {{ margin: 0; width: 100%; height: 100%; background: #000; overflow: hidden; }}
+img, video, embed {{ display: block; width: 100%; height: 100%; object-fit: contain; border: 0; }}
+</style>
+</head>
+<body>{element}</body>
+</html>"#,
+        title = escape(title),
+        element = element,
+    )
+}
+
+fn render_markdown(tab: &Tab) -> String {
+    let markdown = tab.content.as_deref().unwrap_or_default();
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_FOOTNOTES;
+    let mut body = String::new();
+    html::push_html(&mut body, Parser::new_ext(markdown, options));
+
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+html {{ background: #fff; color: #1a1a1a; font-family: sans-serif; font-size: 2.4vh; line-height: 1.5; }}
+body {{ margin: 0 auto; padding: 4vh 5vw; max-width: 90vw; }}
+img {{ max-width: 100%; }}
+table {{ border-collapse: collapse; }}
+th, td {{ border: 1px solid #ccc; padding: .3em .6em; }}
+pre, code {{ background: #f4f4f4; }}
+</style>
+</head>
+<body>{body}</body>
+</html>"#,
+        title = escape(&tab.name),
+        body = body,
+    )
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
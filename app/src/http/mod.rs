@@ -17,12 +17,23 @@ use tracing::info;
 
 use crate::{api, state::AppState};
 
+pub mod assets;
+pub mod content;
+
+/// Port of the HTTP server, also used by Chromium to load internal content
+pub const PORT: u16 = 3000;
+
+/// Absolute URL of a path on this server as seen from Chromium
+pub fn local_url(path: &str) -> String {
+    format!("http://127.0.0.1:{}{}", PORT, path)
+}
+
 #[derive(RustEmbed)]
 #[folder = "src/web"]
 struct WebAssets;
 
 pub async fn start_http(state: Arc<AppState>) -> Result<()> {
-    info!("Starting HTTP server on port 3000");
+    info!("Starting HTTP server on port {}", PORT);
 
     // Create OpenAPI service and Swagger UI
     let api_service: OpenApiService<api::ManagementApi, ()> =
@@ -36,13 +47,21 @@ pub async fn start_http(state: Arc<AppState>) -> Result<()> {
             "/api/preview_live/:tab_id",
             get(preview_live).data(state.clone()),
         )
+        .at(
+            "/content/:tab_id",
+            get(content::tab_content).data(state.clone()),
+        )
+        .at(
+            "/assets/:asset_id",
+            get(assets::asset_file).data(state.clone()),
+        )
         .nest("/api", api_service)
         .nest("/docs", ui)
         .at("/docs/spec", spec)
         .nest("/", EmbeddedFilesEndpoint::<WebAssets>::new())
         .with(Cors::new());
 
-    let server = Server::new(TcpListener::bind("0.0.0.0:3000"));
+    let server = Server::new(TcpListener::bind(("0.0.0.0", PORT)));
     server.run(app).await?;
 
     Ok(())
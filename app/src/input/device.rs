@@ -0,0 +1,50 @@
+use std::{thread, time::Duration};
+
+use evdev::{Device, EventSummary};
+use futures::channel::mpsc::UnboundedSender;
+use tracing::{info, warn};
+
+use super::KeyPress;
+
+/// Wait before reopening a device that failed or was unplugged
+const REOPEN_DELAY: Duration = Duration::from_secs(5);
+
+/// Read key presses from an evdev device on its own thread, reopening it
+/// when it disappears
+pub fn spawn(path: String, grab: bool, sender: UnboundedSender<KeyPress>) {
+    thread::spawn(move || loop {
+        if let Err(e) = read(&path, grab, &sender) {
+            warn!("input: {}: {}", path, e);
+        }
+        if sender.is_closed() {
+            return;
+        }
+        thread::sleep(REOPEN_DELAY);
+    });
+}
+
+fn read(path: &str, grab: bool, sender: &UnboundedSender<KeyPress>) -> std::io::Result<()> {
+    let mut device = Device::open(path)?;
+    if grab {
+        device.grab()?;
+    }
+    info!(
+        "input: reading {} ({})",
+        path,
+        device.name().unwrap_or("unnamed")
+    );
+    loop {
+        for event in device.fetch_events()? {
+            // 1 is a press, 0 a release and 2 an auto-repeat
+            if let EventSummary::Key(_, code, 1) = event.destructure() {
+                let press = KeyPress {
+                    key: format!("{:?}", code),
+                    source: path.to_string(),
+                };
+                if sender.unbounded_send(press).is_err() {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
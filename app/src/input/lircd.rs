@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use async_std::{io::BufReader, os::unix::net::UnixStream, prelude::*, task};
+use futures::channel::mpsc::UnboundedSender;
+use tracing::{info, warn};
+
+use super::KeyPress;
+
+/// Wait before reconnecting to lircd
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Read button presses from the lircd socket, reconnecting when it goes away
+pub fn spawn(socket: String, sender: UnboundedSender<KeyPress>) {
+    task::spawn(async move {
+        loop {
+            if let Err(e) = read(&socket, &sender).await {
+                warn!("input: lircd {}: {}", socket, e);
+            }
+            if sender.is_closed() {
+                return;
+            }
+            task::sleep(RECONNECT_DELAY).await;
+        }
+    });
+}
+
+async fn read(socket: &str, sender: &UnboundedSender<KeyPress>) -> std::io::Result<()> {
+    let stream = UnixStream::connect(socket).await?;
+    info!("input: connected to lircd at {}", socket);
+    let mut lines = BufReader::new(stream).lines();
+    while let Some(line) = lines.next().await {
+        if let Some(press) = parse_line(&line?) {
+            if sender.unbounded_send(press).is_err() {
+                return Ok(());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Lines look like `<code> <repeat> <button> <remote>`; repeats of a held
+/// button are dropped
+fn parse_line(line: &str) -> Option<KeyPress> {
+    let mut fields = line.split_whitespace();
+    let _code = fields.next()?;
+    let repeat = u32::from_str_radix(fields.next()?, 16).ok()?;
+    let button = fields.next()?;
+    let remote = fields.next()?;
+    (repeat == 0).then(|| KeyPress {
+        key: button.to_string(),
+        source: format!("lircd:{}", remote),
+    })
+}
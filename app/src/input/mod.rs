@@ -0,0 +1,336 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use async_std::{sync::Mutex, task};
+use futures::{
+    channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender},
+    StreamExt,
+};
+use serde::Deserialize;
+use tracing::{debug, info, warn};
+
+use crate::{
+    chrome::{send_chrome_message, ChromeMessage},
+    display,
+    events::DomainEvent,
+    state::AppState,
+};
+
+mod device;
+mod lircd;
+
+/// Keypads and remotes that drive the display
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct InputConfig {
+    /// evdev devices to read, e.g. `/dev/input/by-id/usb-...-event-kbd`
+    #[serde(default)]
+    pub devices: Vec<String>,
+    /// Take exclusive access to the devices, so their keys do not reach Chromium
+    #[serde(default)]
+    pub grab: bool,
+    /// lircd socket to read IR remote buttons from, e.g. `/var/run/lirc/lircd`
+    pub lircd_socket: Option<String>,
+    /// Action per key name, e.g. `KEY_RIGHT = "next_tab"`
+    #[serde(default)]
+    pub keys: HashMap<String, InputAction>,
+}
+
+/// What a key press does
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum InputAction {
+    NextTab,
+    PreviousTab,
+    ReloadTab,
+    /// `playlist:<id>`
+    ActivatePlaylist(String),
+    DisplayOn,
+    DisplayOff,
+    ToggleDisplay,
+}
+
+impl TryFrom<String> for InputAction {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if let Some(playlist_id) = value.strip_prefix("playlist:") {
+            return Ok(Self::ActivatePlaylist(playlist_id.to_string()));
+        }
+        match value.as_str() {
+            "next_tab" => Ok(Self::NextTab),
+            "previous_tab" => Ok(Self::PreviousTab),
+            "reload_tab" => Ok(Self::ReloadTab),
+            "display_on" => Ok(Self::DisplayOn),
+            "display_off" => Ok(Self::DisplayOff),
+            "toggle_display" => Ok(Self::ToggleDisplay),
+            _ => Err(format!(
+                "unknown input action '{}', expected next_tab, previous_tab, reload_tab, \
+                 playlist:<id>, display_on, display_off or toggle_display",
+                value
+            )),
+        }
+    }
+}
+
+/// A key pressed on one of the input sources
+#[derive(Debug, Clone)]
+pub struct KeyPress {
+    /// Key name, e.g. `KEY_RIGHT`
+    pub key: String,
+    /// Where the key was pressed, for logging
+    pub source: String,
+}
+
+/// Collects key presses from all sources and runs the configured actions
+pub struct InputManager {
+    sender: UnboundedSender<KeyPress>,
+    receiver: Mutex<Option<UnboundedReceiver<KeyPress>>>,
+    /// Last known display power, flipped by `toggle_display`
+    display_on: Arc<AtomicBool>,
+}
+
+impl Default for InputManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InputManager {
+    pub fn new() -> Self {
+        let (sender, receiver) = unbounded();
+        Self {
+            sender,
+            receiver: Mutex::new(Some(receiver)),
+            display_on: Arc::new(AtomicBool::new(true)),
+        }
+    }
+
+    /// Feed a key press as if it came from a device. Returns false if the
+    /// key is not mapped to an action.
+    pub fn press(&self, config: &InputConfig, key: &str, source: &str) -> bool {
+        if !config.keys.contains_key(key) {
+            return false;
+        }
+        let _ = self.sender.unbounded_send(KeyPress {
+            key: key.to_string(),
+            source: source.to_string(),
+        });
+        true
+    }
+}
+
+/// Start reading the configured devices and run the actions of their keys
+pub async fn start(state: Arc<AppState>) {
+    let Some(mut presses) = state.input.receiver.lock().await.take() else {
+        return;
+    };
+    let config = &state.config.input;
+    for path in &config.devices {
+        device::spawn(path.clone(), config.grab, state.input.sender.clone());
+    }
+    if let Some(socket) = &config.lircd_socket {
+        lircd::spawn(socket.clone(), state.input.sender.clone());
+    }
+    if !config.keys.is_empty() {
+        info!("input: {} key(s) mapped", config.keys.len());
+    }
+
+    // Follow power changes made elsewhere, e.g. from Home Assistant
+    let display_on = state.input.display_on.clone();
+    let mut events = state.events.subscribe();
+    task::spawn(async move {
+        while let Ok(event) = events.recv().await {
+            if let DomainEvent::DisplayPowerChanged(event) = event {
+                display_on.store(event.on, Ordering::Relaxed);
+            }
+        }
+    });
+
+    task::spawn(async move {
+        while let Some(press) = presses.next().await {
+            let display_on = state.input.display_on.load(Ordering::Relaxed);
+            let Some(command) = command_for(&state.config.input, &press.key, display_on) else {
+                debug!("input: {} from {} is not mapped", press.key, press.source);
+                continue;
+            };
+            info!(
+                "input: {} from {} -> {:?}",
+                press.key, press.source, command
+            );
+            run_command(&state, command).await;
+        }
+    });
+}
+
+/// What a mapped key press amounts to
+#[derive(Debug)]
+enum InputCommand {
+    Chrome(ChromeMessage),
+    /// Switch the display on (true) or off
+    DisplayPower(bool),
+}
+
+/// Resolve a key to its command, `display_on` being the last known display power
+fn command_for(config: &InputConfig, key: &str, display_on: bool) -> Option<InputCommand> {
+    let command = match config.keys.get(key)? {
+        InputAction::NextTab => InputCommand::Chrome(ChromeMessage::NextTab),
+        InputAction::PreviousTab => InputCommand::Chrome(ChromeMessage::PreviousTab),
+        InputAction::ReloadTab => InputCommand::Chrome(ChromeMessage::ReloadTab),
+        InputAction::ActivatePlaylist(playlist_id) => {
+            InputCommand::Chrome(ChromeMessage::ActivatePlaylist {
+                playlist_id: playlist_id.clone(),
+            })
+        }
+        InputAction::DisplayOn => InputCommand::DisplayPower(true),
+        InputAction::DisplayOff => InputCommand::DisplayPower(false),
+        InputAction::ToggleDisplay => InputCommand::DisplayPower(!display_on),
+    };
+    Some(command)
+}
+
+async fn run_command(state: &Arc<AppState>, command: InputCommand) {
+    let message = match command {
+        InputCommand::Chrome(message) => message,
+        InputCommand::DisplayPower(on) => {
+            let state = state.clone();
+            // Switching the display runs external commands
+            let _ = task::spawn_blocking(move || display::set_power(&state, on)).await;
+            return;
+        }
+    };
+    if !state.chrome.is_ready().await {
+        warn!("input: browser not ready, ignoring {:?}", message);
+        return;
+    }
+    if let Err(e) = send_chrome_message(&state.chrome, message.clone()).await {
+        warn!("input: failed to send {:?}: {}", message, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(keys: &[(&str, &str)]) -> InputConfig {
+        InputConfig {
+            keys: keys
+                .iter()
+                .map(|(key, action)| {
+                    (
+                        key.to_string(),
+                        InputAction::try_from(action.to_string()).unwrap(),
+                    )
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parses_actions() {
+        assert_eq!(
+            InputAction::try_from("playlist:lobby".to_string()),
+            Ok(InputAction::ActivatePlaylist("lobby".to_string()))
+        );
+        assert_eq!(
+            InputAction::try_from("toggle_display".to_string()),
+            Ok(InputAction::ToggleDisplay)
+        );
+        assert!(InputAction::try_from("next".to_string()).is_err());
+    }
+
+    #[test]
+    fn maps_keys_to_commands() {
+        let config = config(&[
+            ("KEY_RIGHT", "next_tab"),
+            ("KEY_LEFT", "previous_tab"),
+            ("KEY_F5", "reload_tab"),
+            ("KEY_1", "playlist:lobby"),
+            ("KEY_ON", "display_on"),
+            ("KEY_OFF", "display_off"),
+            ("KEY_POWER", "toggle_display"),
+        ]);
+
+        assert!(matches!(
+            command_for(&config, "KEY_RIGHT", true),
+            Some(InputCommand::Chrome(ChromeMessage::NextTab))
+        ));
+        assert!(matches!(
+            command_for(&config, "KEY_LEFT", true),
+            Some(InputCommand::Chrome(ChromeMessage::PreviousTab))
+        ));
+        assert!(matches!(
+            command_for(&config, "KEY_F5", true),
+            Some(InputCommand::Chrome(ChromeMessage::ReloadTab))
+        ));
+        assert!(matches!(
+            command_for(&config, "KEY_1", true),
+            Some(InputCommand::Chrome(ChromeMessage::ActivatePlaylist { playlist_id }))
+                if playlist_id == "lobby"
+        ));
+        assert!(matches!(
+            command_for(&config, "KEY_ON", false),
+            Some(InputCommand::DisplayPower(true))
+        ));
+        assert!(matches!(
+            command_for(&config, "KEY_OFF", true),
+            Some(InputCommand::DisplayPower(false))
+        ));
+        assert!(matches!(
+            command_for(&config, "KEY_POWER", true),
+            Some(InputCommand::DisplayPower(false))
+        ));
+        assert!(matches!(
+            command_for(&config, "KEY_POWER", false),
+            Some(InputCommand::DisplayPower(true))
+        ));
+        assert!(command_for(&config, "KEY_ESC", true).is_none());
+    }
+
+    #[async_std::test]
+    async fn runs_presses_from_a_fake_source() {
+        let config = config(&[("KEY_RIGHT", "next_tab"), ("KEY_1", "playlist:lobby")]);
+        let manager = InputManager::new();
+        let presses = manager.receiver.lock().await.take().unwrap();
+
+        // Feed the channel the way device and lircd readers do
+        let source = manager.sender.clone();
+        for key in ["KEY_RIGHT", "KEY_ESC", "KEY_1"] {
+            source
+                .unbounded_send(KeyPress {
+                    key: key.to_string(),
+                    source: "fake".to_string(),
+                })
+                .unwrap();
+        }
+        assert!(manager.press(&config, "KEY_RIGHT", "api"));
+        assert!(!manager.press(&config, "KEY_ESC", "api"));
+        drop(source);
+        drop(manager);
+
+        let presses: Vec<KeyPress> = presses.collect().await;
+        let commands: Vec<_> = presses
+            .iter()
+            .filter_map(|press| command_for(&config, &press.key, true))
+            .collect();
+        assert_eq!(commands.len(), 3);
+        assert!(matches!(
+            commands[0],
+            InputCommand::Chrome(ChromeMessage::NextTab)
+        ));
+        assert!(matches!(
+            &commands[1],
+            InputCommand::Chrome(ChromeMessage::ActivatePlaylist { playlist_id })
+                if playlist_id == "lobby"
+        ));
+        assert!(matches!(
+            commands[2],
+            InputCommand::Chrome(ChromeMessage::NextTab)
+        ));
+    }
+}
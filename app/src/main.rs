@@ -6,13 +6,19 @@ use state::AppState;
 use tracing::info;
 
 pub mod api;
+pub mod assets;
 pub mod chrome;
 pub mod config;
 pub mod db;
 pub mod display;
+pub mod events;
 pub mod http;
+pub mod input;
 pub mod models;
+pub mod play_log;
 pub mod state;
+pub mod template;
+pub mod webhooks;
 
 #[async_std::main]
 async fn main() -> Result<()> {
@@ -33,14 +39,18 @@ async fn main() -> Result<()> {
     // Initialize database
     let db_pool = db::init_database().await?;
 
+    let (state, connection) = AppState::new(config, db_pool).await?;
+    let state = Arc::new(state);
+
+    webhooks::start(state.clone());
+    play_log::start(state.clone());
+    input::start(state.clone()).await;
+
     // Import config data if chromium config exists
-    if let Some(ref chromium_config) = config.chromium {
-        db::import_config_data(&db_pool, chromium_config).await?;
+    if let Some(ref chromium_config) = state.config.chromium {
+        db::import_config_data(&state.db_pool, chromium_config).await?;
     }
 
-    let (state, connection) = AppState::new(config, db_pool).await;
-    let state = Arc::new(state);
-
     if let Some(chromium_config) = &state.config.chromium {
         if chromium_config.enabled {
             let chromium_config_clone = chromium_config.clone();
@@ -61,9 +71,10 @@ async fn main() -> Result<()> {
         }
     }
 
-    // Initialize Home Assistant if configured
+    // Entities are announced once the MQTT connection is up
     if connection.is_some() {
-        state.hass.init().await;
+        models::hass::sensors::start(state.clone());
+        models::hass::preview::start(state.clone());
     }
 
     let http_state = state.clone();
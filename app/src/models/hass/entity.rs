@@ -1,7 +1,9 @@
+use std::sync::{Arc, Mutex};
+
 use bytes::Bytes;
 use rumqttc::{Client, QoS};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{json, Value};
 use tracing::info;
 
 use crate::state::State;
@@ -11,9 +13,12 @@ pub struct HassEntity {
     pub name: String,
     pub icon: String,
     pub unique_id: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub device_class: String,
     pub device: HassDevice,
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub command_topic: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub state_topic: String,
 
     // Availability
@@ -45,13 +50,35 @@ pub struct HassEntity {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub options: Option<Vec<String>>,
 
+    // Image
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_topic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+
     #[serde(skip)]
     pub config_topic: String,
     #[serde(skip)]
     pub on_change: Option<fn(state: &State, state: &str)>,
+    /// QoS of the publishes and the command subscription
+    #[serde(skip, default = "default_qos")]
+    pub qos: QoS,
 
     #[serde(flatten)]
     pub extra: Option<Value>,
+
+    /// Shared between clones, so options published from a clone are kept
+    #[serde(skip)]
+    published: Arc<Mutex<Published>>,
+}
+
+/// What an entity last published, to publish it again after the broker or
+/// Home Assistant lost it
+#[derive(Debug, Clone, Default)]
+struct Published {
+    config: Option<String>,
+    state: Option<String>,
+    image: Option<Vec<u8>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -103,8 +130,56 @@ impl HassEntity {
             max: None,
             step: None,
             options: None,
+            image_topic: None,
+            content_type: None,
             on_change,
             extra: None,
+            published: Default::default(),
+            qos: default_qos(),
+        }
+    }
+
+    pub fn new_rotation(name: String, unique_id: String, availability_topic: String) -> Self {
+        Self {
+            name: "Auto rotate".to_string(),
+            icon: "mdi:play-pause".to_string(),
+            unique_id: format!("{unique_id}_rotation", unique_id = unique_id),
+            device_class: "switch".to_string(),
+            device: HassDevice {
+                identifiers: vec![unique_id.clone()],
+                name,
+                configuration_url: "https://v3x.fyi/s1".to_string(),
+                serial_number: unique_id.clone(),
+            },
+            state_topic: format!(
+                "homeassistant/switch/{unique_id}_rotation/state",
+                unique_id = unique_id
+            ),
+            command_topic: format!(
+                "homeassistant/switch/{unique_id}_rotation/set",
+                unique_id = unique_id
+            ),
+            config_topic: format!(
+                "homeassistant/switch/{unique_id}_rotation/config",
+                unique_id = unique_id
+            ),
+            availability_topic,
+            state_on: Some("ON".to_string()),
+            state_off: Some("OFF".to_string()),
+            payload_on: Some("ON".to_string()),
+            payload_off: Some("OFF".to_string()),
+            payload_available: Some("online".to_string()),
+            payload_not_available: Some("offline".to_string()),
+            min: None,
+            max: None,
+            step: None,
+            options: None,
+            image_topic: None,
+            content_type: None,
+            on_change: None,
+            extra: None,
+            published: Default::default(),
+            qos: default_qos(),
         }
     }
 
@@ -112,7 +187,7 @@ impl HassEntity {
         name: String,
         unique_id: String,
         availability_topic: String,
-        on_change: Option<fn(state: &State, state: &str)>,
+        _on_change: Option<fn(state: &State, state: &str)>,
     ) -> Self {
         Self {
             name: "Brightness".to_string(),
@@ -148,8 +223,12 @@ impl HassEntity {
             max: Some(1.0),
             step: Some(0.01),
             options: None,
+            image_topic: None,
+            content_type: None,
             on_change: None,
             extra: None,
+            published: Default::default(),
+            qos: default_qos(),
         }
     }
 
@@ -194,8 +273,12 @@ impl HassEntity {
             max: None,
             step: None,
             options,
+            image_topic: None,
+            content_type: None,
             on_change,
             extra: None,
+            published: Default::default(),
+            qos: default_qos(),
         }
     }
 
@@ -234,8 +317,12 @@ impl HassEntity {
             max: None,
             step: None,
             options: Some(vec![]),
+            image_topic: None,
+            content_type: None,
             on_change: None,
             extra: None,
+            published: Default::default(),
+            qos: default_qos(),
         }
     }
 
@@ -274,22 +361,181 @@ impl HassEntity {
             max: None,
             step: None,
             options: None,
+            image_topic: None,
+            content_type: None,
             on_change: None,
             extra: None,
+            published: Default::default(),
+            qos: default_qos(),
         }
     }
 
+    /// Entity of any platform with both topics and no platform options
+    fn new_generic(
+        platform: &str,
+        key: &str,
+        label: &str,
+        icon: &str,
+        name: String,
+        unique_id: String,
+        availability_topic: String,
+    ) -> Self {
+        Self {
+            name: label.to_string(),
+            icon: icon.to_string(),
+            unique_id: format!("{unique_id}_{key}"),
+            device_class: String::new(),
+            device: HassDevice {
+                identifiers: vec![unique_id.clone()],
+                name,
+                configuration_url: "https://v3x.fyi/s1".to_string(),
+                serial_number: unique_id.clone(),
+            },
+            state_topic: format!("homeassistant/{platform}/{unique_id}_{key}/state"),
+            command_topic: format!("homeassistant/{platform}/{unique_id}_{key}/set"),
+            config_topic: format!("homeassistant/{platform}/{unique_id}_{key}/config"),
+            availability_topic,
+            state_on: None,
+            state_off: None,
+            payload_on: None,
+            payload_off: None,
+            payload_available: None,
+            payload_not_available: None,
+            min: None,
+            max: None,
+            step: None,
+            options: None,
+            image_topic: None,
+            content_type: None,
+            on_change: None,
+            extra: None,
+            published: Default::default(),
+            qos: default_qos(),
+        }
+    }
+
+    /// Stateless button, pressing it publishes `PRESS` to the command topic
+    pub fn new_button(
+        key: &str,
+        label: &str,
+        icon: &str,
+        name: String,
+        unique_id: String,
+        availability_topic: String,
+    ) -> Self {
+        let mut entity = Self::new_generic(
+            "button",
+            key,
+            label,
+            icon,
+            name,
+            unique_id,
+            availability_topic,
+        );
+        entity.state_topic = String::new();
+        entity
+    }
+
+    /// Read-only sensor; `device_class` and `options` follow the Home
+    /// Assistant sensor platform, e.g. `timestamp` or `enum`
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_sensor(
+        key: &str,
+        label: &str,
+        icon: &str,
+        device_class: &str,
+        options: Option<Vec<String>>,
+        name: String,
+        unique_id: String,
+        availability_topic: String,
+    ) -> Self {
+        let mut entity = Self::new_generic(
+            "sensor",
+            key,
+            label,
+            icon,
+            name,
+            unique_id,
+            availability_topic,
+        );
+        entity.command_topic = String::new();
+        entity.device_class = device_class.to_string();
+        entity.options = options;
+        entity.extra = Some(json!({ "entity_category": "diagnostic" }));
+        entity
+    }
+
+    /// Rotation interval of the active playlist in seconds
+    pub fn new_interval(name: String, unique_id: String, availability_topic: String) -> Self {
+        let mut entity = Self::new_generic(
+            "number",
+            "interval",
+            "Rotation interval",
+            "mdi:timer-outline",
+            name,
+            unique_id,
+            availability_topic,
+        );
+        entity.device_class = "duration".to_string();
+        entity.min = Some(0.0);
+        entity.max = Some(86400.0);
+        entity.step = Some(1.0);
+        entity.extra = Some(json!({ "unit_of_measurement": "s", "mode": "box" }));
+        entity
+    }
+
+    /// JPEG snapshot, published as raw bytes to the image topic
+    pub fn new_image(
+        key: &str,
+        label: &str,
+        icon: &str,
+        name: String,
+        unique_id: String,
+        availability_topic: String,
+    ) -> Self {
+        let mut entity = Self::new_generic(
+            "image",
+            key,
+            label,
+            icon,
+            name,
+            unique_id.clone(),
+            availability_topic,
+        );
+        entity.image_topic = Some(format!("homeassistant/image/{unique_id}_{key}/image"));
+        entity.content_type = Some("image/jpeg".to_string());
+        entity.command_topic = String::new();
+        entity.state_topic = String::new();
+        entity
+    }
+
     pub fn publish_config(&self, client: &Client) {
         let payload_str: String = serde_json::to_string(&self).unwrap();
-        client
-            .publish(&self.config_topic, QoS::AtLeastOnce, true, payload_str)
-            .unwrap();
+        self.published.lock().unwrap().config = Some(payload_str.clone());
+        publish_retained(client, self.qos, &self.config_topic, payload_str);
+    }
+
+    /// Publish the last config, state and image again, or the initial config
+    /// if nothing was published yet
+    pub fn republish(&self, client: &Client) {
+        let published = self.published.lock().unwrap().clone();
+        match published.config {
+            Some(config) => publish_retained(client, self.qos, &self.config_topic, config),
+            None => self.publish_config(client),
+        }
+        if let Some(state) = published.state {
+            publish_retained(client, self.qos, &self.state_topic, state);
+        }
+        if let (Some(topic), Some(image)) = (&self.image_topic, published.image) {
+            publish_retained(client, self.qos, topic, image);
+        }
     }
 
     pub fn subscribe(&self, client: &Client) {
-        client
-            .subscribe(&self.command_topic, QoS::AtMostOnce)
-            .unwrap();
+        if self.command_topic.is_empty() {
+            return;
+        }
+        client.subscribe(&self.command_topic, self.qos).unwrap();
     }
 
     pub fn handle_command(&self, client: &Client, state: &State, command: &Bytes) {
@@ -303,9 +549,7 @@ impl HassEntity {
             } else if command.eq(b"OFF") {
                 self.update_state(client, "OFF");
             }
-        } else if self.device_class == "number" {
-            self.update_state(client, command_str);
-        } else if self.device_class == "select" {
+        } else if self.device_class == "number" || self.device_class == "select" {
             self.update_state(client, command_str);
         }
 
@@ -320,13 +564,16 @@ impl HassEntity {
             return;
         }
 
-        if let Err(e) = client.publish(&self.state_topic, QoS::AtLeastOnce, true, state.to_string())
-        {
-            eprintln!(
-                "Failed to publish MQTT message to {}: {}",
-                self.state_topic, e
-            );
-        }
+        self.published.lock().unwrap().state = Some(state.to_string());
+        publish_retained(client, self.qos, &self.state_topic, state.to_string());
+    }
+
+    pub fn update_image(&self, client: &Client, jpeg: Vec<u8>) {
+        let Some(topic) = &self.image_topic else {
+            return;
+        };
+        self.published.lock().unwrap().image = Some(jpeg.clone());
+        publish_retained(client, self.qos, topic, jpeg);
     }
 
     pub fn update_options(&mut self, client: &Client, options: Vec<String>) {
@@ -334,3 +581,15 @@ impl HassEntity {
         self.publish_config(client);
     }
 }
+
+fn default_qos() -> QoS {
+    QoS::AtLeastOnce
+}
+
+/// Does not wait while the broker is unreachable, what was published is sent
+/// again by `republish` once connected
+fn publish_retained(client: &Client, qos: QoS, topic: &str, payload: impl Into<Vec<u8>>) {
+    if let Err(e) = client.try_publish(topic, qos, true, payload) {
+        eprintln!("Failed to publish MQTT message to {}: {}", topic, e);
+    }
+}
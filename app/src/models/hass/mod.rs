@@ -1,23 +1,59 @@
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
 
 use crate::{
-    chrome::{send_chrome_message, ChromeMessage},
+    api::models::OverlayRequest,
+    chrome::{
+        overlay::Overlay, send_chrome_message, send_chrome_message_with_response_and_trigger,
+        send_chrome_message_with_trigger, ChromeMessage, ChromeResponse,
+    },
     config::Config,
-    db::models::TabWithOrder,
+    db::models::{PlayTrigger, TabWithOrder},
     display,
     state::{AppState, State},
 };
 use entity::HassEntity;
 
-use reqwest::Url;
-use rumqttc::{Client, Connection, Event, LastWill, MqttOptions, Packet, QoS};
+use anyhow::{anyhow, Result};
+use async_std::task;
+use rumqttc::{Client, ConnectReturnCode, Connection, Event, LastWill, MqttOptions, Packet, QoS};
+use serde::{Deserialize, Serialize};
 use tracing::{info, warn};
 
 pub mod entity;
+pub mod preview;
+pub mod sensors;
+mod transport;
+
+/// Large enough for a preview JPEG, the rumqttc default is 10 KiB
+pub(crate) const MAX_PACKET_SIZE: usize = 4 * 1024 * 1024;
+/// Requests queued while the connection is busy, e.g. during `announce`
+const REQUEST_CAPACITY: usize = 64;
+/// Home Assistant publishes `online` here when it (re)starts
+const HASS_STATUS_TOPIC: &str = "homeassistant/status";
+/// First reconnect delay, doubled on every failed attempt
+const RECONNECT_BASE_SECS: u64 = 1;
+const RECONNECT_MAX_SECS: u64 = 60;
+
+/// State of the broker connection
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionStatus {
+    pub connected: bool,
+    /// When the connection was last established or lost
+    pub changed_at: Option<SystemTime>,
+    /// Why the connection was last lost or refused
+    pub last_error: Option<String>,
+    /// Failed attempts since the last successful connection
+    pub failed_attempts: u32,
+}
 
 pub struct HassManager {
     // connection: Connection,
     pub mqtt_client: Client,
+    /// QoS of the plain topics and the availability
+    qos: QoS,
     pub availability_topic: String,
 
     pub brightness_entity: HassEntity,
@@ -25,6 +61,171 @@ pub struct HassManager {
     pub playlist_entity: HassEntity,
     pub tab_entity: HassEntity,
     pub url_entity: HassEntity,
+    pub rotation_entity: HassEntity,
+    pub interval_entity: HassEntity,
+
+    pub next_button: HassEntity,
+    pub previous_button: HassEntity,
+    pub reload_button: HassEntity,
+    pub recreate_button: HassEntity,
+
+    /// When the service started
+    pub uptime_sensor: HassEntity,
+    /// `running` or `stopped`
+    pub chromium_sensor: HassEntity,
+    /// When the visible tab was shown
+    pub tab_opened_sensor: HassEntity,
+    /// `healthy`, `failing` or `crashed` for the visible tab
+    pub page_health_sensor: HassEntity,
+    /// Snapshot of the visible tab
+    pub preview_entity: HassEntity,
+
+    /// Plain MQTT topic for takeover commands (not a Home Assistant entity)
+    pub takeover_topic: String,
+    /// Plain MQTT topic for overlay commands
+    pub overlay_topic: String,
+    /// Plain MQTT topic for pause, resume and hold commands
+    pub rotation_topic: String,
+    /// Plain MQTT topic for JSON commands, see `DeviceCommand`
+    pub command_topic: String,
+    /// Responses to commands are published here
+    pub command_reply_topic: String,
+    /// The controller state as JSON, retained
+    pub device_state_topic: String,
+    /// Last payload of the device state topic
+    device_state: Mutex<Option<String>>,
+
+    pub connection_status: Mutex<ConnectionStatus>,
+}
+
+/// Payload of the takeover topic; an empty payload or `cancel` ends the takeover
+#[derive(Debug, Deserialize)]
+struct TakeoverCommand {
+    url: String,
+    duration_seconds: u64,
+}
+
+/// Payload of the command topic, tagged by `type`: a display control or
+/// a controller command, e.g. `{"type": "next_tab"}`
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum DeviceCommand {
+    DisplayPower {
+        on: bool,
+    },
+    /// Brightness from 0.0 to 1.0
+    Brightness {
+        value: f32,
+    },
+    #[serde(untagged)]
+    Chrome(ControllerCommand),
+}
+
+/// The `ChromeMessage`s that may be sent over MQTT; timers, page events and
+/// shutdown stay internal to the controller
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ControllerCommand {
+    ActivatePlaylist {
+        playlist_id: String,
+    },
+    ActivateTab {
+        tab_id: String,
+        playlist_id: String,
+    },
+    StartPlaylist,
+    StopPlaylist,
+    HoldTab {
+        duration_seconds: u64,
+    },
+    UpdateInterval {
+        playlist_id: String,
+        interval_seconds: i64,
+    },
+    NextTab,
+    PreviousTab,
+    ReloadTab,
+    RefreshTab {
+        tab_id: String,
+    },
+    RecreateTab {
+        tab_id: String,
+    },
+    StartTakeover {
+        url: String,
+        duration_seconds: u64,
+    },
+    CancelTakeover,
+    ShowOverlay {
+        overlay: Overlay,
+    },
+    HideOverlay {
+        overlay_id: String,
+    },
+    ClearOverlays,
+    GetStatus,
+}
+
+impl From<ControllerCommand> for ChromeMessage {
+    fn from(command: ControllerCommand) -> Self {
+        match command {
+            ControllerCommand::ActivatePlaylist { playlist_id } => {
+                ChromeMessage::ActivatePlaylist { playlist_id }
+            }
+            ControllerCommand::ActivateTab {
+                tab_id,
+                playlist_id,
+            } => ChromeMessage::ActivateTab {
+                tab_id,
+                playlist_id,
+            },
+            ControllerCommand::StartPlaylist => ChromeMessage::StartPlaylist,
+            ControllerCommand::StopPlaylist => ChromeMessage::StopPlaylist,
+            ControllerCommand::HoldTab { duration_seconds } => {
+                ChromeMessage::HoldTab { duration_seconds }
+            }
+            ControllerCommand::UpdateInterval {
+                playlist_id,
+                interval_seconds,
+            } => ChromeMessage::UpdateInterval {
+                playlist_id,
+                interval_seconds,
+            },
+            ControllerCommand::NextTab => ChromeMessage::NextTab,
+            ControllerCommand::PreviousTab => ChromeMessage::PreviousTab,
+            ControllerCommand::ReloadTab => ChromeMessage::ReloadTab,
+            ControllerCommand::RefreshTab { tab_id } => ChromeMessage::RefreshTab { tab_id },
+            ControllerCommand::RecreateTab { tab_id } => ChromeMessage::RecreateTab { tab_id },
+            ControllerCommand::StartTakeover {
+                url,
+                duration_seconds,
+            } => ChromeMessage::StartTakeover {
+                url,
+                duration_seconds,
+            },
+            ControllerCommand::CancelTakeover => ChromeMessage::CancelTakeover,
+            ControllerCommand::ShowOverlay { overlay } => ChromeMessage::ShowOverlay { overlay },
+            ControllerCommand::HideOverlay { overlay_id } => {
+                ChromeMessage::HideOverlay { overlay_id }
+            }
+            ControllerCommand::ClearOverlays => ChromeMessage::ClearOverlays,
+            ControllerCommand::GetStatus => ChromeMessage::GetStatus,
+        }
+    }
+}
+
+/// Published to the reply topic for every command
+#[derive(Debug, Serialize)]
+struct CommandReply {
+    /// `id` of the command, if it had one
+    id: Option<serde_json::Value>,
+    response: ChromeResponse,
+}
+
+/// JSON payload of the rotation topic holding the current tab
+#[derive(Debug, Deserialize)]
+struct HoldCommand {
+    hold_seconds: u64,
 }
 
 impl HassManager {
@@ -36,6 +237,7 @@ impl HassManager {
 
         Self {
             mqtt_client,
+            qos: QoS::AtLeastOnce,
             availability_topic: String::new(),
             brightness_entity: HassEntity::new_brightness(
                 String::new(),
@@ -58,6 +260,60 @@ impl HassManager {
             ),
             tab_entity: HassEntity::new_tab(String::new(), String::new(), String::new()),
             url_entity: HassEntity::new_url(String::new(), String::new(), String::new()),
+            rotation_entity: HassEntity::new_rotation(String::new(), String::new(), String::new()),
+            interval_entity: HassEntity::new_interval(String::new(), String::new(), String::new()),
+            next_button: HassEntity::new_button(
+                "next",
+                "Next tab",
+                "mdi:skip-next",
+                String::new(),
+                String::new(),
+                String::new(),
+            ),
+            previous_button: HassEntity::new_button(
+                "previous",
+                "Previous tab",
+                "mdi:skip-previous",
+                String::new(),
+                String::new(),
+                String::new(),
+            ),
+            reload_button: HassEntity::new_button(
+                "reload",
+                "Reload tab",
+                "mdi:reload",
+                String::new(),
+                String::new(),
+                String::new(),
+            ),
+            recreate_button: HassEntity::new_button(
+                "recreate",
+                "Recreate tab",
+                "mdi:tab-plus",
+                String::new(),
+                String::new(),
+                String::new(),
+            ),
+            uptime_sensor: sensors::uptime(String::new(), String::new(), String::new()),
+            chromium_sensor: sensors::chromium(String::new(), String::new(), String::new()),
+            tab_opened_sensor: sensors::tab_opened(String::new(), String::new(), String::new()),
+            page_health_sensor: sensors::page_health(String::new(), String::new(), String::new()),
+            preview_entity: HassEntity::new_image(
+                "preview",
+                "Preview",
+                "mdi:monitor-screenshot",
+                String::new(),
+                String::new(),
+                String::new(),
+            ),
+            takeover_topic: String::new(),
+            overlay_topic: String::new(),
+            rotation_topic: String::new(),
+            command_topic: String::new(),
+            command_reply_topic: String::new(),
+            device_state_topic: String::new(),
+            device_state: Mutex::new(None),
+            connection_status: Mutex::new(ConnectionStatus::default()),
         }
     }
 
@@ -72,52 +328,72 @@ impl HassManager {
 
     pub fn publish_tab_options(&self, tabs: &[TabWithOrder], active_tab: Option<&str>) {
         let mut entity = self.tab_entity.clone();
-        entity.options = Some(tabs.iter().map(|t| t.id.clone()).collect());
+        // A tab listed more than once in the playlist is offered once
+        let mut options: Vec<String> = Vec::new();
+        for tab in tabs {
+            if !options.contains(&tab.id) {
+                options.push(tab.id.clone());
+            }
+        }
+        entity.options = Some(options);
         entity.publish_config(&self.mqtt_client);
         if let Some(active) = active_tab {
             entity.update_state(&self.mqtt_client, active);
         }
     }
 
-    pub async fn new(config: &Config) -> (Self, Connection) {
+    /// Publish the controller state to the retained state topic
+    pub fn publish_device_state(&self, json: &str) {
+        if self.device_state_topic.is_empty() {
+            return;
+        }
+        *self.device_state.lock().unwrap() = Some(json.to_string());
+        if let Err(e) = self
+            .mqtt_client
+            .try_publish(&self.device_state_topic, self.qos, true, json)
+        {
+            warn!("Failed to publish device state: {}", e);
+        }
+    }
+
+    pub async fn new(config: &Config) -> Result<(Self, Connection)> {
         let hass_config = config
             .homeassistant
             .as_ref()
             .expect("HomeAssistant config required");
-        let mqtt_url = hass_config.mqtt.url.parse::<Url>().unwrap();
-        let mqtt_port = mqtt_url.port().unwrap_or(1883);
-
-        let mqtt_url = mqtt_url.host_str().unwrap();
+        let mqtt_config = &hass_config.mqtt;
+        let (broker, port, transport) = transport::broker(mqtt_config)?;
+        let qos = rumqttc::qos(mqtt_config.qos)
+            .map_err(|_| anyhow!("invalid MQTT qos {}, expected 0, 1 or 2", mqtt_config.qos))?;
 
-        info!("MQTT URL: {}", mqtt_url);
+        info!("MQTT URL: {}", broker);
 
         let availability_topic = format!("homeassistant/device/{}/availability", config.device.id);
         let availability_lastwill = "offline";
 
-        let mut mqttoptions = MqttOptions::new(
-            format!("mission-control-{}", config.device.id),
-            mqtt_url,
-            mqtt_port,
-        );
-        mqttoptions.set_keep_alive(Duration::from_secs(15));
+        let client_id = mqtt_config
+            .client_id
+            .clone()
+            .unwrap_or_else(|| format!("mission-control-{}", config.device.id));
+        let mut mqttoptions = MqttOptions::new(client_id, broker, port);
+        mqttoptions.set_transport(transport);
+        mqttoptions.set_keep_alive(Duration::from_secs(mqtt_config.keep_alive_seconds));
+        mqttoptions.set_max_packet_size(MAX_PACKET_SIZE, MAX_PACKET_SIZE);
         mqttoptions.set_last_will(LastWill::new(
             &availability_topic,
             availability_lastwill,
-            QoS::AtMostOnce,
+            qos,
             true,
         ));
 
-        if let Some(username) = &hass_config.mqtt.username {
-            if let Some(password) = &hass_config.mqtt.password {
-                info!(
-                    "Setting credentials for MQTT connection {} {}",
-                    username, password
-                );
+        if let Some(username) = &mqtt_config.username {
+            if let Some(password) = &mqtt_config.password {
+                info!("Setting credentials for MQTT connection as {}", username);
                 mqttoptions.set_credentials(username, password);
             }
         }
 
-        let (client, connection) = Client::new(mqttoptions, 10);
+        let (client, connection) = Client::new(mqttoptions, REQUEST_CAPACITY);
 
         let brightness_entity = HassEntity::new_brightness(
             config.device.name.to_string(),
@@ -139,8 +415,8 @@ impl HassManager {
                 .iter()
                 .flat_map(|playlist| {
                     playlist
-                        .iter()
-                        .map(|(name, _)| name.to_string())
+                        .keys()
+                        .map(|name| name.to_string())
                         .collect::<Vec<String>>()
                 })
                 .collect::<Vec<String>>()
@@ -169,144 +445,562 @@ impl HassManager {
             availability_topic.to_string(),
         );
 
-        (
-            Self {
-                mqtt_client: client,
-                // connection,
-                availability_topic,
-                brightness_entity,
-                backlight_entity,
-                playlist_entity,
-                tab_entity,
-                url_entity,
-            },
-            connection,
-        )
+        let rotation_entity = HassEntity::new_rotation(
+            config.device.name.to_string(),
+            config.device.id.to_string(),
+            availability_topic.to_string(),
+        );
+
+        let interval_entity = HassEntity::new_interval(
+            config.device.name.to_string(),
+            config.device.id.to_string(),
+            availability_topic.to_string(),
+        );
+
+        let button = |key: &str, label: &str, icon: &str| {
+            HassEntity::new_button(
+                key,
+                label,
+                icon,
+                config.device.name.to_string(),
+                config.device.id.to_string(),
+                availability_topic.to_string(),
+            )
+        };
+        let next_button = button("next", "Next tab", "mdi:skip-next");
+        let previous_button = button("previous", "Previous tab", "mdi:skip-previous");
+        let reload_button = button("reload", "Reload tab", "mdi:reload");
+        let recreate_button = button("recreate", "Recreate tab", "mdi:tab-plus");
+
+        let sensor = |new: fn(String, String, String) -> HassEntity| {
+            new(
+                config.device.name.to_string(),
+                config.device.id.to_string(),
+                availability_topic.to_string(),
+            )
+        };
+        let preview_entity = HassEntity::new_image(
+            "preview",
+            "Preview",
+            "mdi:monitor-screenshot",
+            config.device.name.to_string(),
+            config.device.id.to_string(),
+            availability_topic.to_string(),
+        );
+
+        let uptime_sensor = sensor(sensors::uptime);
+        let chromium_sensor = sensor(sensors::chromium);
+        let tab_opened_sensor = sensor(sensors::tab_opened);
+        let page_health_sensor = sensor(sensors::page_health);
+
+        let mut manager = Self {
+            mqtt_client: client,
+            qos,
+            // connection,
+            availability_topic,
+            brightness_entity,
+            backlight_entity,
+            playlist_entity,
+            tab_entity,
+            url_entity,
+            rotation_entity,
+            interval_entity,
+            next_button,
+            previous_button,
+            reload_button,
+            recreate_button,
+            uptime_sensor,
+            chromium_sensor,
+            tab_opened_sensor,
+            page_health_sensor,
+            preview_entity,
+            takeover_topic: format!("mission-control/{}/takeover", config.device.id),
+            overlay_topic: format!("mission-control/{}/overlay", config.device.id),
+            rotation_topic: format!("mission-control/{}/rotation", config.device.id),
+            command_topic: format!("mission-control/{}/command", config.device.id),
+            command_reply_topic: format!("mission-control/{}/command/reply", config.device.id),
+            device_state_topic: format!("mission-control/{}/state", config.device.id),
+            device_state: Mutex::new(None),
+            connection_status: Mutex::new(ConnectionStatus::default()),
+        };
+        for entity in manager.entities_mut() {
+            entity.qos = qos;
+        }
+        Ok((manager, connection))
+    }
+
+    fn entities_mut(&mut self) -> [&mut HassEntity; 16] {
+        [
+            &mut self.brightness_entity,
+            &mut self.backlight_entity,
+            &mut self.playlist_entity,
+            &mut self.tab_entity,
+            &mut self.url_entity,
+            &mut self.rotation_entity,
+            &mut self.interval_entity,
+            &mut self.next_button,
+            &mut self.previous_button,
+            &mut self.reload_button,
+            &mut self.recreate_button,
+            &mut self.uptime_sensor,
+            &mut self.chromium_sensor,
+            &mut self.tab_opened_sensor,
+            &mut self.page_health_sensor,
+            &mut self.preview_entity,
+        ]
+    }
+
+    fn buttons(&self) -> [&HassEntity; 4] {
+        [
+            &self.next_button,
+            &self.previous_button,
+            &self.reload_button,
+            &self.recreate_button,
+        ]
+    }
+
+    fn sensors(&self) -> [&HassEntity; 4] {
+        [
+            &self.uptime_sensor,
+            &self.chromium_sensor,
+            &self.tab_opened_sensor,
+            &self.page_health_sensor,
+        ]
     }
 
-    pub async fn init(&self) {
-        // Skip initialization if MQTT is disabled
+    /// Publish availability, discovery configs and the last known states and
+    /// subscribe to the command topics. Runs on every (re)connect and when
+    /// Home Assistant comes online, as either may have lost them.
+    pub fn announce(&self) {
+        // Skip if MQTT is disabled
         if self.availability_topic.is_empty() {
             return;
         }
 
-        self.mqtt_client
-            .publish(&self.availability_topic, QoS::AtLeastOnce, true, "online")
-            .unwrap();
+        if let Err(e) = self
+            .mqtt_client
+            .publish(&self.availability_topic, self.qos, true, "online")
+        {
+            warn!("Failed to publish availability: {}", e);
+        }
 
-        self.brightness_entity.publish_config(&self.mqtt_client);
-        self.backlight_entity.publish_config(&self.mqtt_client);
-        self.playlist_entity.publish_config(&self.mqtt_client);
-        self.tab_entity.publish_config(&self.mqtt_client);
-        self.url_entity.publish_config(&self.mqtt_client);
+        self.brightness_entity.republish(&self.mqtt_client);
+        self.backlight_entity.republish(&self.mqtt_client);
+        self.playlist_entity.republish(&self.mqtt_client);
+        self.tab_entity.republish(&self.mqtt_client);
+        self.url_entity.republish(&self.mqtt_client);
+        self.rotation_entity.republish(&self.mqtt_client);
+        self.interval_entity.republish(&self.mqtt_client);
+        for entity in self.buttons().into_iter().chain(self.sensors()) {
+            entity.republish(&self.mqtt_client);
+        }
+        self.preview_entity.republish(&self.mqtt_client);
 
         self.brightness_entity.subscribe(&self.mqtt_client);
         self.backlight_entity.subscribe(&self.mqtt_client);
         self.playlist_entity.subscribe(&self.mqtt_client);
         self.tab_entity.subscribe(&self.mqtt_client);
+        self.rotation_entity.subscribe(&self.mqtt_client);
+        self.interval_entity.subscribe(&self.mqtt_client);
+        for entity in self.buttons() {
+            entity.subscribe(&self.mqtt_client);
+        }
         // self.url_entity.subscribe(&self.mqtt_client);
+
+        for topic in [
+            &self.takeover_topic,
+            &self.overlay_topic,
+            &self.rotation_topic,
+            &self.command_topic,
+        ] {
+            if let Err(e) = self.mqtt_client.subscribe(topic, self.qos) {
+                warn!("Failed to subscribe to {}: {}", topic, e);
+            }
+        }
+        if let Err(e) = self.mqtt_client.subscribe(HASS_STATUS_TOPIC, self.qos) {
+            warn!("Failed to subscribe to {}: {}", HASS_STATUS_TOPIC, e);
+        }
+
+        if let Some(json) = self.device_state.lock().unwrap().clone() {
+            self.publish_device_state(&json);
+        }
     }
 
-    pub async fn run(&self, connection: &mut Connection, state: &Arc<AppState>) {
-        let mut _error_count = 0;
+    /// Announce from another thread, the event loop has to keep running to
+    /// send the queued requests
+    fn spawn_announce(state: &Arc<AppState>) {
+        let state = state.clone();
+        task::spawn_blocking(move || state.hass.announce());
+    }
 
+    fn set_connected(&self, connected: bool, error: Option<String>) {
+        let mut status = self.connection_status.lock().unwrap();
+        if status.connected != connected {
+            status.changed_at = Some(SystemTime::now());
+        }
+        status.connected = connected;
+        if connected {
+            status.failed_attempts = 0;
+        } else {
+            status.failed_attempts += 1;
+        }
+        if error.is_some() {
+            status.last_error = error;
+        }
+    }
+
+    pub async fn run(&self, connection: &mut Connection, state: &Arc<AppState>) {
         for notification in connection.iter() {
             info!("Notification: {:?}", notification);
 
             match notification {
-                Ok(payload) => {
-                    if let Event::Incoming(event) = payload {
-                        if let Packet::Publish(publish) = event {
-                            info!("Publish: {:?}", &publish);
-
-                            if publish.topic.eq(&self.brightness_entity.command_topic) {
-                                info!("Command received: {:?}", &publish.payload);
-                                self.brightness_entity.handle_command(
-                                    &self.mqtt_client,
-                                    state,
-                                    &publish.payload,
-                                );
-                            }
+                Ok(Event::Incoming(Packet::ConnAck(connack))) => {
+                    if connack.code == ConnectReturnCode::Success {
+                        info!("Connected to MQTT broker");
+                        self.set_connected(true, None);
+                        Self::spawn_announce(state);
+                    } else {
+                        warn!("MQTT broker refused connection: {:?}", connack.code);
+                        self.set_connected(false, Some(format!("{:?}", connack.code)));
+                    }
+                }
+                Ok(Event::Incoming(Packet::Publish(publish))) => {
+                    info!("Publish: {:?}", &publish);
 
-                            if publish.topic.eq(&self.backlight_entity.command_topic) {
-                                info!("Command received: {:?}", &publish.payload);
-                                self.backlight_entity.handle_command(
-                                    &self.mqtt_client,
-                                    state,
-                                    &publish.payload,
-                                );
-                            }
+                    if publish.topic == HASS_STATUS_TOPIC {
+                        if publish.payload.as_ref() == b"online" {
+                            info!("Home Assistant came online, announcing entities");
+                            Self::spawn_announce(state);
+                        }
+                        continue;
+                    }
 
-                            if publish.topic.eq(&self.playlist_entity.command_topic) {
-                                info!("Command received: {:?}", &publish.payload);
-                                self.playlist_entity.handle_command(
-                                    &self.mqtt_client,
-                                    state,
-                                    &publish.payload,
-                                );
-                                let payload = String::from_utf8_lossy(&publish.payload).to_string();
-                                let _ = crate::chrome::send_chrome_message(
-                                    &state.chrome,
-                                    crate::chrome::ChromeMessage::ActivatePlaylist {
-                                        playlist_id: payload,
-                                    },
-                                )
-                                .await;
-                            }
+                    if publish.topic.eq(&self.brightness_entity.command_topic) {
+                        info!("Command received: {:?}", &publish.payload);
+                        self.brightness_entity.handle_command(
+                            &self.mqtt_client,
+                            state,
+                            &publish.payload,
+                        );
+                    }
+
+                    if publish.topic.eq(&self.backlight_entity.command_topic) {
+                        info!("Command received: {:?}", &publish.payload);
+                        self.backlight_entity.handle_command(
+                            &self.mqtt_client,
+                            state,
+                            &publish.payload,
+                        );
+                    }
+
+                    if publish.topic.eq(&self.playlist_entity.command_topic) {
+                        info!("Command received: {:?}", &publish.payload);
+                        self.playlist_entity.handle_command(
+                            &self.mqtt_client,
+                            state,
+                            &publish.payload,
+                        );
+                        let payload = String::from_utf8_lossy(&publish.payload).to_string();
+                        let _ = send_chrome_message_with_trigger(
+                            &state.chrome,
+                            ChromeMessage::ActivatePlaylist {
+                                playlist_id: payload,
+                            },
+                            PlayTrigger::Mqtt,
+                        )
+                        .await;
+                    }
+
+                    if publish.topic.eq(&self.overlay_topic) {
+                        info!("Overlay command received: {:?}", &publish.payload);
+                        handle_overlay_command(state, &publish.payload).await;
+                    }
+
+                    if publish.topic.eq(&self.rotation_entity.command_topic) {
+                        info!("Command received: {:?}", &publish.payload);
+                        // The controller reports the resulting state
+                        let message = if publish.payload.as_ref() == b"ON" {
+                            ChromeMessage::StartPlaylist
+                        } else {
+                            ChromeMessage::StopPlaylist
+                        };
+                        let _ = send_chrome_message_with_trigger(
+                            &state.chrome,
+                            message,
+                            PlayTrigger::Mqtt,
+                        )
+                        .await;
+                    }
+
+                    if publish.topic.eq(&self.interval_entity.command_topic) {
+                        info!("Command received: {:?}", &publish.payload);
+                        handle_interval_command(state, &publish.payload).await;
+                    }
 
-                            if publish.topic.eq(&self.tab_entity.command_topic) {
-                                info!("Command received: {:?}", &publish.payload);
-                                self.tab_entity.handle_command(
-                                    &self.mqtt_client,
-                                    state,
-                                    &publish.payload,
-                                );
-                                let payload = String::from_utf8_lossy(&publish.payload).to_string();
-                                if let Some(active_playlist) =
-                                    state.chrome.state.lock().await.current_playlist_id.clone()
-                                {
-                                    let _ = send_chrome_message(
-                                        &state.chrome,
-                                        ChromeMessage::ActivateTab {
-                                            tab_id: payload,
-                                            playlist_id: active_playlist,
-                                        },
-                                    )
-                                    .await;
+                    if self
+                        .buttons()
+                        .iter()
+                        .any(|b| publish.topic == b.command_topic)
+                    {
+                        info!("Button pressed: {}", &publish.topic);
+                        handle_button_press(self, state, &publish.topic).await;
+                    }
+
+                    if publish.topic.eq(&self.rotation_topic) {
+                        info!("Rotation command received: {:?}", &publish.payload);
+                        handle_rotation_command(state, &publish.payload).await;
+                    }
+
+                    if publish.topic.eq(&self.command_topic) {
+                        info!("Command received: {:?}", &publish.payload);
+                        // Commands can take a while, keep the event loop running meanwhile
+                        let state = state.clone();
+                        let client = self.mqtt_client.clone();
+                        let reply_topic = self.command_reply_topic.clone();
+                        let qos = self.qos;
+                        let payload = publish.payload.clone();
+                        task::spawn(async move {
+                            let reply = handle_device_command(&state, &payload).await;
+                            match serde_json::to_string(&reply) {
+                                Ok(json) => {
+                                    if let Err(e) =
+                                        client.try_publish(&reply_topic, qos, false, json)
+                                    {
+                                        warn!("Failed to publish command reply: {}", e);
+                                    }
                                 }
+                                Err(e) => warn!("Failed to serialize command reply: {}", e),
                             }
+                        });
+                    }
+
+                    if publish.topic.eq(&self.takeover_topic) {
+                        info!("Takeover command received: {:?}", &publish.payload);
+                        handle_takeover_command(state, &publish.payload).await;
+                    }
+
+                    if publish.topic.eq(&self.tab_entity.command_topic) {
+                        info!("Command received: {:?}", &publish.payload);
+                        self.tab_entity
+                            .handle_command(&self.mqtt_client, state, &publish.payload);
+                        let payload = String::from_utf8_lossy(&publish.payload).to_string();
+                        if let Some(active_playlist) =
+                            state.chrome.state.lock().await.current_playlist_id.clone()
+                        {
+                            let _ = send_chrome_message_with_trigger(
+                                &state.chrome,
+                                ChromeMessage::ActivateTab {
+                                    tab_id: payload,
+                                    playlist_id: active_playlist,
+                                },
+                                PlayTrigger::Mqtt,
+                            )
+                            .await;
                         }
                     }
                 }
+                Ok(_) => {}
                 Err(e) => {
-                    _error_count += 1;
-                    info!("Error: {:?}", e);
-                    std::thread::sleep(Duration::from_secs(2));
-
-                    // if error_count > 10 {
-                    // info!("Too many errors, exiting");
-                    // break;
-                    // }
+                    self.set_connected(false, Some(e.to_string()));
+                    let attempts = self.connection_status.lock().unwrap().failed_attempts;
+                    let delay = (RECONNECT_BASE_SECS << attempts.saturating_sub(1).min(6))
+                        .min(RECONNECT_MAX_SECS);
+                    warn!("MQTT connection error: {}, reconnecting in {}s", e, delay);
+                    task::sleep(Duration::from_secs(delay)).await;
                 }
             }
         }
     }
 }
 
-fn handle_backlight_change(state: &State, new_state: &str) {
-    info!("Backlight state changed: {}", new_state);
+async fn handle_takeover_command(state: &State, payload: &[u8]) {
+    let payload = String::from_utf8_lossy(payload);
+    let message = match payload.trim() {
+        "" | "cancel" => ChromeMessage::CancelTakeover,
+        json => match serde_json::from_str::<TakeoverCommand>(json) {
+            Ok(command) => ChromeMessage::StartTakeover {
+                url: command.url,
+                duration_seconds: command.duration_seconds,
+            },
+            Err(e) => {
+                warn!("Invalid takeover payload: {}", e);
+                return;
+            }
+        },
+    };
+    if let Err(e) =
+        send_chrome_message_with_trigger(&state.chrome, message, PlayTrigger::Mqtt).await
+    {
+        warn!("Failed to send takeover command: {}", e);
+    }
+}
 
-    let output = state.config.display.output.as_deref();
-    let turn_on = new_state.eq_ignore_ascii_case("ON");
+/// Run a JSON command; an `id` field is echoed in the reply
+async fn handle_device_command(state: &State, payload: &[u8]) -> CommandReply {
+    let mut value = match serde_json::from_slice::<serde_json::Value>(payload) {
+        Ok(value) => value,
+        Err(e) => {
+            return CommandReply {
+                id: None,
+                response: ChromeResponse::Error {
+                    message: format!("Invalid JSON: {}", e),
+                },
+            }
+        }
+    };
+    let id = value.as_object_mut().and_then(|object| object.remove("id"));
+    let command = match serde_json::from_value::<DeviceCommand>(value) {
+        Ok(command) => command,
+        Err(e) => {
+            return CommandReply {
+                id,
+                response: ChromeResponse::Error {
+                    message: format!("Invalid command: {}", e),
+                },
+            }
+        }
+    };
 
-    if let Err(err) = display::set_dpms(turn_on, output) {
-        warn!("Failed to set DPMS via swaymsg/wlr-randr: {}", err);
+    let result = match command {
+        DeviceCommand::DisplayPower { on } => {
+            display::set_power(state, on).map(|()| ChromeResponse::Success)
+        }
+        DeviceCommand::Brightness { value } => {
+            display::set_brightness(state, value).map(|()| ChromeResponse::Success)
+        }
+        DeviceCommand::Chrome(_) if !state.chrome.is_ready().await => {
+            Err(anyhow::anyhow!("Chromium is not running"))
+        }
+        DeviceCommand::Chrome(command) => {
+            send_chrome_message_with_response_and_trigger(
+                &state.chrome,
+                command.into(),
+                PlayTrigger::Mqtt,
+            )
+            .await
+        }
+    };
+    CommandReply {
+        id,
+        response: result.unwrap_or_else(|e| ChromeResponse::Error {
+            message: e.to_string(),
+        }),
+    }
+}
+
+async fn handle_button_press(hass: &HassManager, state: &State, topic: &str) {
+    let message = if topic == hass.next_button.command_topic {
+        ChromeMessage::NextTab
+    } else if topic == hass.previous_button.command_topic {
+        ChromeMessage::PreviousTab
+    } else if topic == hass.reload_button.command_topic {
+        ChromeMessage::ReloadTab
+    } else {
+        let Some(tab_id) = state.chrome.state.lock().await.current_tab_id.clone() else {
+            warn!("No visible tab to recreate");
+            return;
+        };
+        ChromeMessage::RecreateTab { tab_id }
+    };
+    if let Err(e) =
+        send_chrome_message_with_trigger(&state.chrome, message, PlayTrigger::Mqtt).await
+    {
+        warn!("Failed to send button command: {}", e);
+    }
+}
+
+/// Set the interval of the active playlist; the controller reports it back
+async fn handle_interval_command(state: &State, payload: &[u8]) {
+    let payload = String::from_utf8_lossy(payload);
+    // Home Assistant sends numbers as floats, e.g. `30.0`
+    let Some(interval_seconds) = payload
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|interval| *interval >= 0.0)
+        .map(|interval| interval.round() as i64)
+    else {
+        warn!("Invalid interval payload: {}", payload);
+        return;
+    };
+    let Some(playlist_id) = state.chrome.state.lock().await.current_playlist_id.clone() else {
+        warn!("No active playlist to set the interval of");
+        return;
+    };
+    if let Err(e) = send_chrome_message(
+        &state.chrome,
+        ChromeMessage::UpdateInterval {
+            playlist_id,
+            interval_seconds,
+        },
+    )
+    .await
+    {
+        warn!("Failed to update interval: {}", e);
+    }
+}
+
+/// `pause` and `resume` control rotation, `{"hold_seconds": <n>}` keeps the
+/// current tab for that long
+async fn handle_rotation_command(state: &State, payload: &[u8]) {
+    let payload = String::from_utf8_lossy(payload);
+    let message = match payload.trim() {
+        "pause" => ChromeMessage::StopPlaylist,
+        "resume" => ChromeMessage::StartPlaylist,
+        json => match serde_json::from_str::<HoldCommand>(json) {
+            Ok(command) => ChromeMessage::HoldTab {
+                duration_seconds: command.hold_seconds,
+            },
+            Err(e) => {
+                warn!("Invalid rotation payload: {}", e);
+                return;
+            }
+        },
+    };
+    if let Err(e) =
+        send_chrome_message_with_trigger(&state.chrome, message, PlayTrigger::Mqtt).await
+    {
+        warn!("Failed to send rotation command: {}", e);
+    }
+}
+
+/// An empty payload or `clear` removes all overlays, `{"hide": "<id>"}` removes
+/// one, anything else is parsed as an `OverlayRequest`
+async fn handle_overlay_command(state: &State, payload: &[u8]) {
+    #[derive(Deserialize)]
+    struct HideOverlay {
+        hide: String,
     }
 
-    state
-        .hass
-        .backlight_entity
-        .update_state(&state.hass.mqtt_client, new_state);
+    let payload = String::from_utf8_lossy(payload);
+    let message = match payload.trim() {
+        "" | "clear" => ChromeMessage::ClearOverlays,
+        json => {
+            if let Ok(hide) = serde_json::from_str::<HideOverlay>(json) {
+                ChromeMessage::HideOverlay {
+                    overlay_id: hide.hide,
+                }
+            } else {
+                match serde_json::from_str::<OverlayRequest>(json) {
+                    Ok(request) => ChromeMessage::ShowOverlay {
+                        overlay: request.into_overlay(),
+                    },
+                    Err(e) => {
+                        warn!("Invalid overlay payload: {}", e);
+                        return;
+                    }
+                }
+            }
+        }
+    };
+    if let Err(e) = send_chrome_message(&state.chrome, message).await {
+        warn!("Failed to send overlay command: {}", e);
+    }
+}
+
+fn handle_backlight_change(state: &State, new_state: &str) {
+    info!("Backlight state changed: {}", new_state);
+    let _ = display::set_power(state, new_state.eq_ignore_ascii_case("ON"));
 }
 
 fn handle_brightness_change(state: &State, new_state: &str) {
@@ -317,13 +1011,51 @@ fn handle_brightness_change(state: &State, new_state: &str) {
         return;
     };
 
-    let display_target = state.config.display.ddcutil_display.as_deref();
-    if let Err(err) = display::set_ddc_brightness(display_target, value) {
-        warn!("Failed to set brightness via ddcutil: {}", err);
+    let _ = display::set_brightness(state, value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> serde_json::Result<DeviceCommand> {
+        serde_json::from_str(json)
     }
 
-    state
-        .hass
-        .brightness_entity
-        .update_state(&state.hass.mqtt_client, new_state);
+    #[test]
+    fn parses_public_commands() {
+        assert!(matches!(
+            parse(r#"{"type": "display_power", "on": false}"#).unwrap(),
+            DeviceCommand::DisplayPower { on: false }
+        ));
+        assert!(matches!(
+            parse(r#"{"type": "next_tab"}"#).unwrap(),
+            DeviceCommand::Chrome(ControllerCommand::NextTab)
+        ));
+        let command =
+            parse(r#"{"type": "activate_tab", "tab_id": "news", "playlist_id": "lobby"}"#).unwrap();
+        let DeviceCommand::Chrome(command) = command else {
+            panic!("expected a controller command");
+        };
+        assert!(matches!(
+            ChromeMessage::from(command),
+            ChromeMessage::ActivateTab { tab_id, playlist_id }
+                if tab_id == "news" && playlist_id == "lobby"
+        ));
+    }
+
+    #[test]
+    fn rejects_internal_messages() {
+        for json in [
+            r#"{"type": "shutdown"}"#,
+            r#"{"type": "expire_hold"}"#,
+            r#"{"type": "expire_takeover", "takeover_id": "1"}"#,
+            r#"{"type": "expire_overlays"}"#,
+            r#"{"type": "user_interaction", "tab_id": "news"}"#,
+            r#"{"type": "tab_deleted", "tab_id": "news"}"#,
+            r#"{"type": "browser_exited"}"#,
+        ] {
+            assert!(parse(json).is_err(), "{} was accepted", json);
+        }
+    }
 }
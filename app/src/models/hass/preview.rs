@@ -0,0 +1,117 @@
+use std::{sync::Arc, time::Duration};
+
+use async_std::task;
+use base64::Engine;
+use serde::Deserialize;
+use tracing::{info, warn};
+
+use crate::state::AppState;
+
+use super::MAX_PACKET_SIZE;
+
+/// Snapshot of the visible tab for the Home Assistant image entity
+#[derive(Debug, Clone, Deserialize)]
+pub struct PreviewConfig {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Publish at most one snapshot per this many seconds
+    #[serde(default = "default_interval_seconds")]
+    pub interval_seconds: u64,
+}
+
+impl Default for PreviewConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            interval_seconds: default_interval_seconds(),
+        }
+    }
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_interval_seconds() -> u64 {
+    30
+}
+
+/// Publish the latest screencast frame of the visible tab whenever it
+/// changed, or a screenshot of the takeover while one is running
+pub fn start(state: Arc<AppState>) {
+    let Some(config) = state
+        .config
+        .homeassistant
+        .as_ref()
+        .map(|hass| hass.preview.clone())
+    else {
+        return;
+    };
+    if !config.enabled || state.hass.availability_topic.is_empty() {
+        return;
+    }
+    info!(
+        "Publishing tab previews every {} seconds",
+        config.interval_seconds
+    );
+
+    let interval = Duration::from_secs(config.interval_seconds.max(1));
+    task::spawn(async move {
+        let mut published = None;
+        loop {
+            task::sleep(interval).await;
+
+            let (takeover, tab_id) = {
+                let st = state.chrome.state.lock().await;
+                (st.takeover.is_some(), st.current_tab_id.clone())
+            };
+            if takeover {
+                // The takeover page has no screencast, capture it every time
+                if let Some(frame) = state.chrome.get_takeover_screenshot().await {
+                    publish(&state, "the takeover", &frame);
+                }
+                published = None;
+                continue;
+            }
+
+            let Some(tab_id) = tab_id else {
+                continue;
+            };
+            let Some(captured_at) = state.chrome.get_last_frame_time(&tab_id).await else {
+                continue;
+            };
+            if published.as_ref() == Some(&(tab_id.clone(), captured_at)) {
+                continue;
+            }
+            let Some(frame) = state.chrome.get_screenshot(&tab_id).await else {
+                continue;
+            };
+            if publish(&state, &tab_id, &frame) {
+                published = Some((tab_id, captured_at));
+            }
+        }
+    });
+}
+
+/// Publish a base64 JPEG frame, returns false if it could not be decoded
+fn publish(state: &AppState, label: &str, frame: &[u8]) -> bool {
+    // Frames are kept base64 encoded as Chromium sends them
+    match base64::engine::general_purpose::STANDARD.decode(frame) {
+        // Leave room for the topic and packet header
+        Ok(jpeg) if jpeg.len() > MAX_PACKET_SIZE - 1024 => {
+            warn!("Preview of {} too large to publish", label);
+            true
+        }
+        Ok(jpeg) => {
+            state
+                .hass
+                .preview_entity
+                .update_image(&state.hass.mqtt_client, jpeg);
+            true
+        }
+        Err(e) => {
+            warn!("Failed to decode preview frame: {}", e);
+            false
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use std::{sync::Arc, time::SystemTime};
+
+use async_std::task;
+use chrono::{DateTime, Utc};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::warn;
+
+use crate::{events::DomainEvent, state::AppState};
+
+use super::entity::HassEntity;
+
+pub fn uptime(name: String, unique_id: String, availability_topic: String) -> HassEntity {
+    HassEntity::new_sensor(
+        "uptime",
+        "Uptime",
+        "mdi:clock-start",
+        "timestamp",
+        None,
+        name,
+        unique_id,
+        availability_topic,
+    )
+}
+
+pub fn chromium(name: String, unique_id: String, availability_topic: String) -> HassEntity {
+    HassEntity::new_sensor(
+        "chromium",
+        "Chromium",
+        "mdi:google-chrome",
+        "enum",
+        Some(vec!["running".to_string(), "stopped".to_string()]),
+        name,
+        unique_id,
+        availability_topic,
+    )
+}
+
+pub fn tab_opened(name: String, unique_id: String, availability_topic: String) -> HassEntity {
+    HassEntity::new_sensor(
+        "tab_opened",
+        "Tab opened",
+        "mdi:tab",
+        "timestamp",
+        None,
+        name,
+        unique_id,
+        availability_topic,
+    )
+}
+
+pub fn page_health(name: String, unique_id: String, availability_topic: String) -> HassEntity {
+    HassEntity::new_sensor(
+        "page_health",
+        "Page health",
+        "mdi:heart-pulse",
+        "enum",
+        Some(vec![
+            "healthy".to_string(),
+            "failing".to_string(),
+            "crashed".to_string(),
+        ]),
+        name,
+        unique_id,
+        availability_topic,
+    )
+}
+
+fn timestamp(time: SystemTime) -> String {
+    DateTime::<Utc>::from(time).to_rfc3339()
+}
+
+/// Keep the sensors up to date from the domain events
+pub fn start(state: Arc<AppState>) {
+    if state.hass.availability_topic.is_empty() {
+        return;
+    }
+
+    let mut receiver = state.events.subscribe();
+    task::spawn(async move {
+        let hass = &state.hass;
+        let client = &hass.mqtt_client;
+        hass.uptime_sensor
+            .update_state(client, &timestamp(state.started_at));
+        let running = state.chrome.is_ready().await;
+        hass.chromium_sensor
+            .update_state(client, if running { "running" } else { "stopped" });
+
+        loop {
+            let event = match receiver.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("Home Assistant sensors lagged, skipped {} events", skipped);
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            };
+
+            match event {
+                DomainEvent::BrowserStatusChanged(event) => {
+                    let status = if event.running { "running" } else { "stopped" };
+                    hass.chromium_sensor.update_state(client, status);
+                }
+                DomainEvent::TabActivated(event) => {
+                    hass.tab_opened_sensor
+                        .update_state(client, &timestamp(SystemTime::now()));
+                    let failing = state
+                        .chrome
+                        .get_tab_health(&event.tab_id)
+                        .await
+                        .is_some_and(|health| health.consecutive_failures > 0);
+                    hass.page_health_sensor
+                        .update_state(client, if failing { "failing" } else { "healthy" });
+                }
+                DomainEvent::TabFailed(event) if is_visible(&state, &event.tab_id).await => {
+                    hass.page_health_sensor.update_state(client, "failing");
+                }
+                DomainEvent::TabCrashed(event) if is_visible(&state, &event.tab_id).await => {
+                    hass.page_health_sensor.update_state(client, "crashed");
+                }
+                _ => {}
+            }
+        }
+    });
+}
+
+async fn is_visible(state: &AppState, tab_id: &str) -> bool {
+    state.chrome.state.lock().await.current_tab_id.as_deref() == Some(tab_id)
+}
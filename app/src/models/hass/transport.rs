@@ -0,0 +1,88 @@
+use std::{fs::File, io::BufReader, sync::Arc};
+
+use anyhow::{anyhow, bail, Context, Result};
+use reqwest::Url;
+use rumqttc::{
+    tokio_rustls::rustls::{pki_types::CertificateDer, ClientConfig, RootCertStore},
+    TlsConfiguration, Transport,
+};
+
+use crate::config::HASSMQTTConfig;
+
+/// Broker address, port and transport for the configured URL
+pub fn broker(config: &HASSMQTTConfig) -> Result<(String, u16, Transport)> {
+    let url = config
+        .url
+        .parse::<Url>()
+        .with_context(|| format!("invalid MQTT url {}", config.url))?;
+    let host = url
+        .host_str()
+        .ok_or_else(|| anyhow!("MQTT url {} has no host", config.url))?
+        .to_string();
+
+    match url.scheme() {
+        "mqtt" | "tcp" => Ok((host, url.port().unwrap_or(1883), Transport::Tcp)),
+        "mqtts" | "ssl" => Ok((
+            host,
+            url.port().unwrap_or(8883),
+            Transport::tls_with_config(tls_configuration(config)?),
+        )),
+        // Websocket connections are made to the full URL
+        "ws" => Ok((url.to_string(), url.port().unwrap_or(80), Transport::Ws)),
+        "wss" => Ok((
+            url.to_string(),
+            url.port().unwrap_or(443),
+            Transport::wss_with_config(tls_configuration(config)?),
+        )),
+        scheme => bail!(
+            "unsupported MQTT url scheme '{}', expected mqtt, mqtts, ws or wss",
+            scheme
+        ),
+    }
+}
+
+fn tls_configuration(config: &HASSMQTTConfig) -> Result<TlsConfiguration> {
+    let mut roots = RootCertStore::empty();
+    match &config.ca_file {
+        Some(path) => {
+            for cert in read_certs(path)? {
+                roots.add(cert)?;
+            }
+            if roots.is_empty() {
+                bail!("no certificates in {}", path);
+            }
+        }
+        None => {
+            let certs = rustls_native_certs::load_native_certs()
+                .context("failed to load the system CA certificates")?;
+            roots.add_parsable_certificates(certs);
+        }
+    }
+
+    let builder = ClientConfig::builder().with_root_certificates(roots);
+    let tls_config = match (&config.client_cert_file, &config.client_key_file) {
+        (Some(cert_path), Some(key_path)) => {
+            let certs = read_certs(cert_path)?;
+            let key = rustls_pemfile::private_key(&mut open(key_path)?)
+                .with_context(|| format!("failed to read {}", key_path))?
+                .ok_or_else(|| anyhow!("no private key in {}", key_path))?;
+            builder
+                .with_client_auth_cert(certs, key)
+                .context("invalid MQTT client certificate")?
+        }
+        (None, None) => builder.with_no_client_auth(),
+        _ => bail!("client_cert_file and client_key_file must be set together"),
+    };
+    Ok(TlsConfiguration::Rustls(Arc::new(tls_config)))
+}
+
+fn open(path: &str) -> Result<BufReader<File>> {
+    let file = File::open(path).with_context(|| format!("failed to open {}", path))?;
+    Ok(BufReader::new(file))
+}
+
+fn read_certs(path: &str) -> Result<Vec<CertificateDer<'static>>> {
+    rustls_pemfile::certs(&mut open(path)?)
+        .collect::<std::io::Result<Vec<_>>>()
+        .with_context(|| format!("failed to read {}", path))
+}
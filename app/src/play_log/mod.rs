@@ -0,0 +1,214 @@
+use std::{fmt::Write, sync::Arc, time::Duration};
+
+use async_std::task;
+use chrono::Utc;
+use tracing::{info, warn};
+
+use crate::{
+    db::{models::PlayRecord, repositories::PlayLogRepository},
+    state::AppState,
+};
+
+const PRUNE_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Close plays left open by the previous run and prune old plays daily
+pub fn start(state: Arc<AppState>) {
+    task::spawn(async move {
+        match state.play_log_repository.close_dangling().await {
+            Ok(0) => {}
+            Ok(n) => info!("closed {} play(s) left open by the previous run", n),
+            Err(e) => warn!("failed to close open plays: {}", e),
+        }
+
+        let retention_days = state.config.play_log.retention_days;
+        if retention_days == 0 {
+            return;
+        }
+        loop {
+            let before = Utc::now() - chrono::Duration::days(retention_days.into());
+            match state.play_log_repository.prune(before).await {
+                Ok(0) => {}
+                Ok(n) => info!("pruned {} play(s) older than {} days", n, retention_days),
+                Err(e) => warn!("failed to prune play log: {}", e),
+            }
+            task::sleep(PRUNE_INTERVAL).await;
+        }
+    });
+}
+
+/// Plays as CSV with a header row, times in RFC 3339 and durations in seconds
+pub fn to_csv(records: &[PlayRecord]) -> String {
+    let mut csv =
+        String::from("id,tab_id,playlist_id,trigger,started_at,ended_at,duration_seconds\n");
+    for record in records {
+        let trigger = serde_json::to_value(record.trigger)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default();
+        let duration = record
+            .ended_at
+            .map(|end| (end - record.started_at).num_milliseconds() as f64 / 1000.0);
+        let _ = writeln!(
+            csv,
+            "{},{},{},{},{},{},{}",
+            field(&record.id),
+            field(&record.tab_id),
+            field(&record.playlist_id),
+            trigger,
+            record.started_at.to_rfc3339(),
+            record.ended_at.map(|t| t.to_rfc3339()).unwrap_or_default(),
+            duration.map(|d| d.to_string()).unwrap_or_default(),
+        );
+    }
+    csv
+}
+
+/// Quote a field if it contains separators, quotes or line breaks
+fn field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeZone};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    use super::*;
+    use crate::db::{
+        models::{PlayReportFilter, PlayReportGrouping, PlayTrigger},
+        repositories::play_log::SqlitePlayLogRepository,
+    };
+
+    fn play(id: &str, tab_id: &str, started_at: DateTime<Utc>, seconds: Option<i64>) -> PlayRecord {
+        PlayRecord {
+            id: id.to_string(),
+            tab_id: tab_id.to_string(),
+            playlist_id: "lobby".to_string(),
+            trigger: PlayTrigger::Rotation,
+            started_at,
+            ended_at: seconds.map(|s| started_at + chrono::Duration::seconds(s)),
+        }
+    }
+
+    async fn repository() -> SqlitePlayLogRepository {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::run_migrations(&pool).await.unwrap();
+        SqlitePlayLogRepository::new(pool)
+    }
+
+    #[test]
+    fn quotes_csv_fields() {
+        let started_at = Utc.with_ymd_and_hms(2026, 1, 2, 8, 0, 0).unwrap();
+        let mut quoted = play("1", "news, \"live\"", started_at, Some(90));
+        quoted.playlist_id = "two\nlines".to_string();
+        quoted.trigger = PlayTrigger::Mqtt;
+        let running = play("2", "weather", started_at, None);
+
+        assert_eq!(
+            to_csv(&[quoted, running]),
+            "id,tab_id,playlist_id,trigger,started_at,ended_at,duration_seconds\n\
+             1,\"news, \"\"live\"\"\",\"two\nlines\",mqtt,\
+             2026-01-02T08:00:00+00:00,2026-01-02T08:01:30+00:00,90\n\
+             2,weather,lobby,rotation,2026-01-02T08:00:00+00:00,,\n"
+        );
+    }
+
+    #[async_std::test]
+    async fn reports_days_including_the_running_play() {
+        let repository = repository().await;
+        let day = Utc.with_ymd_and_hms(2026, 1, 2, 8, 0, 0).unwrap();
+        let running_since = Utc::now() - chrono::Duration::seconds(120);
+        for record in [
+            play("1", "news", day, Some(60)),
+            play("2", "weather", day + chrono::Duration::hours(1), Some(30)),
+            play("3", "news", running_since, None),
+        ] {
+            repository.start(&record).await.unwrap();
+        }
+
+        let rows = repository
+            .report(PlayReportGrouping::Day, &PlayReportFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].key, "2026-01-02");
+        assert_eq!(rows[0].plays, 2);
+        assert_eq!(rows[0].total_seconds, 90.0);
+        assert_eq!(
+            rows[1].key,
+            running_since.date_naive().format("%Y-%m-%d").to_string()
+        );
+        assert_eq!(rows[1].plays, 1);
+        assert!((120.0..130.0).contains(&rows[1].total_seconds));
+
+        let filter = PlayReportFilter {
+            tab_id: Some("news".to_string()),
+            to: Some(day + chrono::Duration::days(1)),
+            ..Default::default()
+        };
+        let rows = repository
+            .report(PlayReportGrouping::Tab, &filter)
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].key, "news");
+        assert_eq!(rows[0].plays, 1);
+        assert_eq!(rows[0].total_seconds, 60.0);
+    }
+
+    #[async_std::test]
+    async fn closes_dangling_plays_without_duration() {
+        let repository = repository().await;
+        let started_at = Utc.with_ymd_and_hms(2026, 1, 2, 8, 0, 0).unwrap();
+        repository
+            .start(&play("1", "news", started_at, None))
+            .await
+            .unwrap();
+        repository
+            .start(&play("2", "news", started_at, Some(10)))
+            .await
+            .unwrap();
+
+        assert_eq!(repository.close_dangling().await.unwrap(), 1);
+        let records = repository.list(None, None).await.unwrap();
+        let closed = records.iter().find(|r| r.id == "1").unwrap();
+        assert_eq!(closed.ended_at, Some(started_at));
+    }
+
+    #[async_std::test]
+    async fn prune_keeps_open_plays() {
+        let repository = repository().await;
+        let old = Utc::now() - chrono::Duration::days(400);
+        for record in [
+            play("old", "news", old, Some(60)),
+            play("open", "news", old, None),
+            play(
+                "recent",
+                "news",
+                Utc::now() - chrono::Duration::days(1),
+                Some(60),
+            ),
+        ] {
+            repository.start(&record).await.unwrap();
+        }
+
+        let before = Utc::now() - chrono::Duration::days(365);
+        assert_eq!(repository.prune(before).await.unwrap(), 1);
+        let remaining: Vec<_> = repository
+            .list(None, None)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|r| r.id)
+            .collect();
+        assert_eq!(remaining, ["open", "recent"]);
+    }
+}
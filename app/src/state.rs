@@ -1,5 +1,6 @@
+use anyhow::{Context, Result};
 use sqlx::SqlitePool;
-use std::sync::Arc;
+use std::{sync::Arc, time::SystemTime};
 
 use rumqttc::Connection;
 
@@ -7,9 +8,12 @@ use crate::{
     chrome::ChromeController,
     config::Config,
     db::repositories::{
+        asset::SqliteAssetRepository, play_log::SqlitePlayLogRepository,
         playlist::SqlitePlaylistRepository, playlist_tab::SqlitePlaylistTabRepository,
-        tab::SqliteTabRepository,
+        tab::SqliteTabRepository, webhook_delivery::SqliteWebhookDeliveryRepository,
     },
+    events::EventBus,
+    input::InputManager,
     models::hass::HassManager,
 };
 
@@ -18,17 +22,26 @@ pub type State = Arc<AppState>;
 pub struct AppState {
     pub chrome: Arc<ChromeController>,
     pub hass: Arc<HassManager>,
+    pub events: EventBus,
+    pub input: Arc<InputManager>,
     pub config: Config,
     pub db_pool: SqlitePool,
     pub playlist_repository: Arc<SqlitePlaylistRepository>,
     pub tab_repository: Arc<SqliteTabRepository>,
     pub playlist_tab_repository: Arc<SqlitePlaylistTabRepository>,
+    pub webhook_delivery_repository: Arc<SqliteWebhookDeliveryRepository>,
+    pub asset_repository: Arc<SqliteAssetRepository>,
+    pub play_log_repository: Arc<SqlitePlayLogRepository>,
+    /// When the service started
+    pub started_at: SystemTime,
 }
 
 impl AppState {
-    pub async fn new(config: Config, db_pool: SqlitePool) -> (Self, Option<Connection>) {
+    pub async fn new(config: Config, db_pool: SqlitePool) -> Result<(Self, Option<Connection>)> {
         let (hass, connection) = if config.homeassistant.is_some() {
-            let (h, c) = HassManager::new(&config).await;
+            let (h, c) = HassManager::new(&config)
+                .await
+                .context("failed to set up the MQTT connection")?;
             (Arc::new(h), Some(c))
         } else {
             (Arc::new(HassManager::disabled()), None)
@@ -39,18 +52,27 @@ impl AppState {
         let playlist_repo = Arc::new(SqlitePlaylistRepository::new(db_pool.clone()));
         let tab_repo = Arc::new(SqliteTabRepository::new(db_pool.clone()));
         let playlist_tab_repo = Arc::new(SqlitePlaylistTabRepository::new(db_pool.clone()));
+        let webhook_delivery_repo = Arc::new(SqliteWebhookDeliveryRepository::new(db_pool.clone()));
+        let asset_repo = Arc::new(SqliteAssetRepository::new(db_pool.clone()));
+        let play_log_repo = Arc::new(SqlitePlayLogRepository::new(db_pool.clone()));
 
-        (
+        Ok((
             Self {
                 chrome,
                 hass,
+                events: EventBus::new(),
+                input: Arc::new(InputManager::new()),
                 config,
                 db_pool,
                 playlist_repository: playlist_repo,
                 tab_repository: tab_repo,
                 playlist_tab_repository: playlist_tab_repo,
+                webhook_delivery_repository: webhook_delivery_repo,
+                asset_repository: asset_repo,
+                play_log_repository: play_log_repo,
+                started_at: SystemTime::now(),
             },
             connection,
-        )
+        ))
     }
 }
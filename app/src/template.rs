@@ -0,0 +1,185 @@
+//! Variables in tab URLs, resolved whenever a tab is loaded.
+//!
+//! `{device.id}`, `{device.name}` and `{device.<variable>}` for the custom
+//! variables of `[device.variables]`, `{date}`, `{time}`, `{datetime}`,
+//! `{timestamp}` (seconds) and `{now}`, `{now-6h}`, `{now+15m}` (milliseconds,
+//! as used by Grafana). `{{` and `}}` produce literal braces.
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{DateTime, Duration, Local};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+use crate::config::DeviceConfig;
+
+/// Characters of device variables that are escaped when inserted into a URL
+const VALUE_ESCAPE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Resolve all variables of `template` for this device at the current time
+pub fn render(template: &str, device: &DeviceConfig) -> Result<String> {
+    let now = Local::now();
+    let mut output = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                output.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                output.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => bail!("unclosed '{{'"),
+                    }
+                }
+                output.push_str(&resolve(name.trim(), device, now)?);
+            }
+            '}' => bail!("unmatched '}}'"),
+            c => output.push(c),
+        }
+    }
+
+    Ok(output)
+}
+
+fn resolve(name: &str, device: &DeviceConfig, now: DateTime<Local>) -> Result<String> {
+    if let Some(variable) = name.strip_prefix("device.") {
+        let value = match variable {
+            "id" => &device.id,
+            "name" => &device.name,
+            _ => device
+                .variables
+                .get(variable)
+                .ok_or_else(|| anyhow!("unknown device variable '{}'", variable))?,
+        };
+        return Ok(utf8_percent_encode(value, VALUE_ESCAPE).to_string());
+    }
+
+    match name {
+        "date" => Ok(now.format("%Y-%m-%d").to_string()),
+        "time" => Ok(now.format("%H:%M").to_string()),
+        "datetime" => Ok(now.format("%Y-%m-%dT%H:%M:%S").to_string()),
+        "timestamp" => Ok(now.timestamp().to_string()),
+        _ if name.starts_with("now") => {
+            let offset = relative_offset(&name["now".len()..])
+                .ok_or_else(|| anyhow!("invalid relative time '{}'", name))?;
+            Ok((now + offset).timestamp_millis().to_string())
+        }
+        _ => Err(anyhow!("unknown variable '{}'", name)),
+    }
+}
+
+/// Parse the `-6h` / `+15m` part of `now-6h`, empty means no offset
+fn relative_offset(offset: &str) -> Option<Duration> {
+    if offset.is_empty() {
+        return Some(Duration::zero());
+    }
+    let mut chars = offset.chars();
+    let sign = match chars.next() {
+        Some('-') => -1,
+        Some('+') => 1,
+        _ => return None,
+    };
+    let rest = chars.as_str();
+    let unit_at = rest.find(|c: char| !c.is_ascii_digit())?;
+    let (amount, unit) = rest.split_at(unit_at);
+    let amount: i64 = amount.parse().ok()?;
+    let duration = match unit {
+        "s" => Duration::try_seconds(amount),
+        "m" => Duration::try_minutes(amount),
+        "h" => Duration::try_hours(amount),
+        "d" => Duration::try_days(amount),
+        "w" => Duration::try_weeks(amount),
+        _ => None,
+    }?;
+    Some(duration * sign)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn device() -> DeviceConfig {
+        DeviceConfig {
+            name: "Lobby screen".to_string(),
+            id: "lobby".to_string(),
+            variables: HashMap::from([("floor".to_string(), "2/east".to_string())]),
+        }
+    }
+
+    #[test]
+    fn resolves_device_variables() {
+        assert_eq!(
+            render(
+                "https://example.com/?id={device.id}&floor={ device.floor }",
+                &device()
+            )
+            .unwrap(),
+            "https://example.com/?id=lobby&floor=2%2Feast"
+        );
+        assert_eq!(
+            render("/{device.name}", &device()).unwrap(),
+            "/Lobby%20screen"
+        );
+    }
+
+    #[test]
+    fn keeps_escaped_braces() {
+        assert_eq!(
+            render("{{\"a\": 1}} {device.id}", &device()).unwrap(),
+            "{\"a\": 1} lobby"
+        );
+        assert_eq!(render("no variables", &device()).unwrap(), "no variables");
+    }
+
+    #[test]
+    fn rejects_malformed_templates() {
+        assert!(render("{device.id", &device()).is_err());
+        assert!(render("device.id}", &device()).is_err());
+        assert!(render("{device.room}", &device()).is_err());
+        assert!(render("{weather}", &device()).is_err());
+        assert!(render("{now-6x}", &device()).is_err());
+        assert!(render("{now€}", &device()).is_err());
+        assert!(render("{now-6é}", &device()).is_err());
+    }
+
+    #[test]
+    fn resolves_times() {
+        let date = render("{date}", &device()).unwrap();
+        assert_eq!(date, Local::now().format("%Y-%m-%d").to_string());
+
+        let before = Local::now().timestamp_millis();
+        let now: i64 = render("{now}", &device()).unwrap().parse().unwrap();
+        let earlier: i64 = render("{now-6h}", &device()).unwrap().parse().unwrap();
+        let after = Local::now().timestamp_millis();
+        assert!((before..=after).contains(&now));
+        let six_hours = Duration::hours(6).num_milliseconds();
+        assert!((before - six_hours..=after - six_hours).contains(&earlier));
+    }
+
+    #[test]
+    fn parses_relative_offsets() {
+        assert_eq!(relative_offset(""), Some(Duration::zero()));
+        assert_eq!(relative_offset("-6h"), Some(Duration::hours(-6)));
+        assert_eq!(relative_offset("+15m"), Some(Duration::minutes(15)));
+        assert_eq!(relative_offset("+2w"), Some(Duration::weeks(2)));
+        assert_eq!(relative_offset("6h"), None);
+        assert_eq!(relative_offset("-h"), None);
+        assert_eq!(relative_offset("-6"), None);
+        assert_eq!(relative_offset("-6y"), None);
+        assert_eq!(relative_offset("é"), None);
+    }
+}
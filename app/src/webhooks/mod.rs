@@ -0,0 +1,538 @@
+use std::{sync::Arc, time::Duration};
+
+use anyhow::{anyhow, Result};
+use async_std::task;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use sha2::Sha256;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{info, warn};
+
+use crate::{
+    config::WebhookConfig,
+    db::{
+        models::{DeliveryStatus, WebhookDelivery},
+        repositories::{
+            webhook_delivery::SqliteWebhookDeliveryRepository, WebhookDeliveryRepository,
+        },
+    },
+    events::DomainEvent,
+    state::AppState,
+};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// First retry delay, doubled on every failed attempt
+const RETRY_BASE_SECS: u64 = 2;
+const RETRY_MAX_SECS: u64 = 5 * 60;
+const PRUNE_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+pub const SIGNATURE_HEADER: &str = "X-Mission-Control-Signature";
+pub const EVENT_HEADER: &str = "X-Mission-Control-Event";
+pub const DELIVERY_HEADER: &str = "X-Mission-Control-Delivery";
+
+/// Error recorded for deliveries that were still being retried at shutdown
+const INTERRUPTED_ERROR: &str = "interrupted by restart";
+
+/// Body POSTed to webhook targets
+#[derive(Debug, Serialize)]
+pub struct WebhookPayload<'a> {
+    /// Unique id of this delivery, also sent in the delivery header
+    pub id: &'a str,
+    pub device_id: &'a str,
+    pub timestamp: DateTime<Utc>,
+    pub event: &'a DomainEvent,
+}
+
+impl WebhookConfig {
+    /// Whether this target subscribed to the given event type
+    pub fn accepts(&self, event_type: &str) -> bool {
+        self.events.is_empty() || self.events.iter().any(|e| e == event_type)
+    }
+}
+
+/// Subscribe to domain events and deliver them to the configured webhooks
+pub fn start(state: Arc<AppState>) {
+    // Deliveries of removed targets are cleaned up as well
+    cleanup(state.clone());
+    if state.config.webhooks.is_empty() {
+        return;
+    }
+    info!(
+        "Delivering events to {} webhook(s)",
+        state.config.webhooks.len()
+    );
+
+    let client = match reqwest::Client::builder().timeout(REQUEST_TIMEOUT).build() {
+        Ok(client) => client,
+        Err(e) => {
+            warn!("Failed to create webhook HTTP client: {}", e);
+            return;
+        }
+    };
+
+    let mut receiver = state.events.subscribe();
+    task::spawn(async move {
+        loop {
+            let event = match receiver.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("Webhook dispatcher lagged, skipped {} events", skipped);
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            };
+
+            for target in &state.config.webhooks {
+                if !target.accepts(event.event_type()) {
+                    continue;
+                }
+                let state = state.clone();
+                let client = client.clone();
+                let target = target.clone();
+                let event = event.clone();
+                task::spawn(async move {
+                    let repository = &state.webhook_delivery_repository;
+                    let device_id = &state.config.device.id;
+                    if let Err(e) = deliver(repository, device_id, &client, &target, &event).await {
+                        warn!("Webhook delivery to {} failed: {}", target.url, e);
+                    }
+                });
+            }
+        }
+    });
+}
+
+/// Fail deliveries left pending by the previous run and delete finished
+/// deliveries past their retention daily
+fn cleanup(state: Arc<AppState>) {
+    // Deliveries of this run may be created before the task gets to run
+    let started_at = Utc::now();
+    task::spawn(async move {
+        let repository = &state.webhook_delivery_repository;
+        match repository.fail_pending(started_at, INTERRUPTED_ERROR).await {
+            Ok(0) => {}
+            Ok(n) => info!(
+                "failed {} webhook deliveries left pending by the previous run",
+                n
+            ),
+            Err(e) => warn!("failed to close pending webhook deliveries: {}", e),
+        }
+
+        let retention_days = state.config.webhook_deliveries.retention_days;
+        if retention_days == 0 {
+            return;
+        }
+        loop {
+            let before = Utc::now() - chrono::Duration::days(retention_days.into());
+            match state.webhook_delivery_repository.prune(before).await {
+                Ok(0) => {}
+                Ok(n) => info!(
+                    "pruned {} webhook deliveries older than {} days",
+                    n, retention_days
+                ),
+                Err(e) => warn!("failed to prune webhook deliveries: {}", e),
+            }
+            task::sleep(PRUNE_INTERVAL).await;
+        }
+    });
+}
+
+/// Deliver one event to one target, retrying with exponential backoff
+async fn deliver(
+    repository: &SqliteWebhookDeliveryRepository,
+    device_id: &str,
+    client: &reqwest::Client,
+    target: &WebhookConfig,
+    event: &DomainEvent,
+) -> Result<()> {
+    let id = uuid::Uuid::new_v4().to_string();
+    let now = Utc::now();
+    let body = serde_json::to_string(&WebhookPayload {
+        id: &id,
+        device_id,
+        timestamp: now,
+        event,
+    })?;
+
+    repository
+        .create(&WebhookDelivery {
+            id: id.clone(),
+            url: target.url.clone(),
+            event_type: event.event_type().to_string(),
+            payload: body.clone(),
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            response_status: None,
+            last_error: None,
+            created_at: now,
+            updated_at: now,
+        })
+        .await?;
+
+    let max_attempts = target.max_attempts.max(1);
+    for attempt in 1..=max_attempts {
+        let (response_status, error) = match send(client, target, &id, event, &body).await {
+            Ok(status) if status.is_success() => {
+                repository
+                    .record_attempt(
+                        &id,
+                        DeliveryStatus::Delivered,
+                        attempt as i64,
+                        Some(status.as_u16() as i64),
+                        None,
+                    )
+                    .await?;
+                return Ok(());
+            }
+            Ok(status) => (
+                Some(status.as_u16() as i64),
+                format!("Target responded with {}", status),
+            ),
+            Err(e) => (None, e.to_string()),
+        };
+
+        let status = if attempt == max_attempts {
+            DeliveryStatus::Failed
+        } else {
+            DeliveryStatus::Pending
+        };
+        repository
+            .record_attempt(&id, status, attempt as i64, response_status, Some(&error))
+            .await?;
+
+        if attempt == max_attempts {
+            return Err(anyhow!("giving up after {} attempts: {}", attempt, error));
+        }
+
+        let backoff = RETRY_BASE_SECS
+            .saturating_mul(1 << (attempt - 1).min(16))
+            .min(RETRY_MAX_SECS);
+        warn!(
+            "Webhook delivery {} to {} failed (attempt {}/{}), retrying in {}s: {}",
+            id, target.url, attempt, max_attempts, backoff, error
+        );
+        task::sleep(Duration::from_secs(backoff)).await;
+    }
+
+    Ok(())
+}
+
+async fn send(
+    client: &reqwest::Client,
+    target: &WebhookConfig,
+    id: &str,
+    event: &DomainEvent,
+    body: &str,
+) -> Result<reqwest::StatusCode> {
+    let mut request = client
+        .post(&target.url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header(EVENT_HEADER, event.event_type())
+        .header(DELIVERY_HEADER, id);
+
+    if let Some(secret) = &target.secret {
+        request = request.header(SIGNATURE_HEADER, sign(secret, body)?);
+    }
+
+    let response = request.body(body.to_string()).send().await?;
+    Ok(response.status())
+}
+
+/// Signature header value: `sha256=` followed by the hex HMAC-SHA256 of the body
+pub fn sign(secret: &str, body: &str) -> Result<String> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .map_err(|e| anyhow!("Invalid webhook secret: {}", e))?;
+    mac.update(body.as_bytes());
+    Ok(format!(
+        "sha256={}",
+        hex::encode(mac.finalize().into_bytes())
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    use super::*;
+    use crate::events::{TabCrashedEvent, TabFailedEvent};
+
+    /// Request as seen by the target
+    struct Received {
+        headers: Vec<(String, String)>,
+        body: String,
+    }
+
+    impl Received {
+        fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(n, _)| n.eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.as_str())
+        }
+    }
+
+    /// HTTP target answering each request with the next of `statuses`
+    fn serve(statuses: Vec<u16>) -> (String, mpsc::Receiver<Received>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for status in statuses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut headers = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        headers.push((name.trim().to_string(), value.trim().to_string()));
+                    }
+                }
+                let length = headers
+                    .iter()
+                    .find(|(n, _)| n.eq_ignore_ascii_case("content-length"))
+                    .map(|(_, v)| v.parse().unwrap())
+                    .unwrap_or(0);
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Status\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    status
+                )
+                .unwrap();
+                let _ = sender.send(Received {
+                    headers,
+                    body: String::from_utf8(body).unwrap(),
+                });
+            }
+        });
+        (url, receiver)
+    }
+
+    async fn repository() -> SqliteWebhookDeliveryRepository {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::run_migrations(&pool).await.unwrap();
+        SqliteWebhookDeliveryRepository::new(pool)
+    }
+
+    fn target(url: String, secret: Option<&str>, max_attempts: u32) -> WebhookConfig {
+        WebhookConfig {
+            url,
+            events: Vec::new(),
+            secret: secret.map(str::to_string),
+            max_attempts,
+        }
+    }
+
+    #[test]
+    fn sign_is_hex_hmac_sha256() {
+        // RFC 4231 test case 2
+        assert_eq!(
+            sign("Jefe", "what do ya want for nothing?").unwrap(),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn sign_depends_on_secret_and_body() {
+        let signature = sign("secret", "{}").unwrap();
+        assert_ne!(signature, sign("other", "{}").unwrap());
+        assert_ne!(signature, sign("secret", "{ }").unwrap());
+    }
+
+    #[test]
+    fn accepts_subscribed_events_only() {
+        let mut target = target("http://example.com".to_string(), None, 1);
+        assert!(target.accepts("tab_failed"));
+        target.events = vec!["tab_failed".to_string()];
+        assert!(target.accepts("tab_failed"));
+        assert!(!target.accepts("tab_crashed"));
+    }
+
+    #[async_std::test]
+    async fn delivers_signed_payload_after_retry() {
+        let (url, received) = serve(vec![500, 200]);
+        let repository = repository().await;
+        let event = DomainEvent::TabFailed(TabFailedEvent {
+            tab_id: "news".to_string(),
+            error: "timeout".to_string(),
+            consecutive_failures: 1,
+        });
+
+        deliver(
+            &repository,
+            "lobby-display",
+            &reqwest::Client::new(),
+            &target(url, Some("s3cret"), 3),
+            &event,
+        )
+        .await
+        .unwrap();
+
+        let first = received.recv().unwrap();
+        let second = received.recv().unwrap();
+        for request in [&first, &second] {
+            assert_eq!(
+                request.header(SIGNATURE_HEADER),
+                Some(sign("s3cret", &request.body).unwrap().as_str())
+            );
+            assert_eq!(request.header(EVENT_HEADER), Some("tab_failed"));
+        }
+        // The retry sends the same delivery
+        assert_eq!(first.body, second.body);
+        assert_eq!(
+            first.header(DELIVERY_HEADER),
+            second.header(DELIVERY_HEADER)
+        );
+
+        let payload: serde_json::Value = serde_json::from_str(&first.body).unwrap();
+        assert_eq!(payload["device_id"], "lobby-display");
+        assert_eq!(payload["event"]["type"], "tab_failed");
+        assert_eq!(payload["event"]["tab_id"], "news");
+
+        let delivery = repository
+            .get_by_id(first.header(DELIVERY_HEADER).unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(delivery.status, DeliveryStatus::Delivered);
+        assert_eq!(delivery.attempts, 2);
+        assert_eq!(delivery.response_status, Some(200));
+    }
+
+    #[async_std::test]
+    async fn gives_up_after_max_attempts() {
+        let (url, received) = serve(vec![503]);
+        let repository = repository().await;
+        let event = DomainEvent::TabCrashed(TabCrashedEvent {
+            tab_id: "news".to_string(),
+        });
+
+        let result = deliver(
+            &repository,
+            "lobby-display",
+            &reqwest::Client::new(),
+            &target(url, None, 1),
+            &event,
+        )
+        .await;
+        assert!(result.is_err());
+
+        let request = received.recv().unwrap();
+        assert_eq!(request.header(SIGNATURE_HEADER), None);
+        let delivery = repository
+            .get_by_id(request.header(DELIVERY_HEADER).unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(delivery.status, DeliveryStatus::Failed);
+        assert_eq!(delivery.attempts, 1);
+        assert_eq!(delivery.response_status, Some(503));
+    }
+
+    #[async_std::test]
+    async fn prunes_finished_deliveries_only() {
+        let repository = repository().await;
+        let old = Utc::now() - chrono::Duration::days(40);
+        for (id, status) in [
+            ("delivered", DeliveryStatus::Delivered),
+            ("failed", DeliveryStatus::Failed),
+            ("pending", DeliveryStatus::Pending),
+        ] {
+            repository
+                .create(&WebhookDelivery {
+                    id: id.to_string(),
+                    url: "http://example.com".to_string(),
+                    event_type: "tab_failed".to_string(),
+                    payload: "{}".to_string(),
+                    status,
+                    attempts: 1,
+                    response_status: None,
+                    last_error: None,
+                    created_at: old,
+                    updated_at: old,
+                })
+                .await
+                .unwrap();
+        }
+
+        let before = Utc::now() - chrono::Duration::days(30);
+        assert_eq!(repository.prune(before).await.unwrap(), 2);
+        let remaining = repository.list(None, 10).await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].id, "pending");
+    }
+
+    #[async_std::test]
+    async fn fails_deliveries_left_pending() {
+        let repository = repository().await;
+        let previous_run = Utc::now() - chrono::Duration::minutes(5);
+        for (id, status) in [
+            ("delivered", DeliveryStatus::Delivered),
+            ("pending", DeliveryStatus::Pending),
+        ] {
+            repository
+                .create(&WebhookDelivery {
+                    id: id.to_string(),
+                    url: "http://example.com".to_string(),
+                    event_type: "tab_failed".to_string(),
+                    payload: "{}".to_string(),
+                    status,
+                    attempts: 2,
+                    response_status: Some(500),
+                    last_error: None,
+                    created_at: previous_run,
+                    updated_at: previous_run,
+                })
+                .await
+                .unwrap();
+        }
+
+        let started_at = Utc::now();
+        assert_eq!(
+            repository
+                .fail_pending(started_at, INTERRUPTED_ERROR)
+                .await
+                .unwrap(),
+            1
+        );
+        let pending = repository.get_by_id("pending").await.unwrap().unwrap();
+        assert_eq!(pending.status, DeliveryStatus::Failed);
+        assert_eq!(pending.attempts, 2);
+        assert_eq!(pending.last_error.as_deref(), Some(INTERRUPTED_ERROR));
+        let delivered = repository.get_by_id("delivered").await.unwrap().unwrap();
+        assert_eq!(delivered.status, DeliveryStatus::Delivered);
+        assert_eq!(delivered.last_error, None);
+
+        // Deliveries of the current run are left alone
+        let mut current = pending.clone();
+        current.id = "current".to_string();
+        current.status = DeliveryStatus::Pending;
+        current.created_at = started_at + chrono::Duration::seconds(1);
+        repository.create(&current).await.unwrap();
+        assert_eq!(
+            repository
+                .fail_pending(started_at, INTERRUPTED_ERROR)
+                .await
+                .unwrap(),
+            0
+        );
+    }
+}
@@ -29,6 +29,14 @@ export interface paths {
                         "application/json; charset=utf-8": components["schemas"]["PlaylistInfo"][];
                     };
                 };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
             };
         };
         put?: never;
@@ -54,6 +62,31 @@ export interface paths {
                         "application/json; charset=utf-8": components["schemas"]["PlaylistInfo"];
                     };
                 };
+                400: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                /** @description A playlist with this id already exists */
+                409: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
             };
         };
         delete?: never;
@@ -89,10 +122,27 @@ export interface paths {
                         "application/json; charset=utf-8": components["schemas"]["TabInfo"][];
                     };
                 };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
             };
         };
         put?: never;
-        /** Add a tab to a playlist */
+        /** Add a tab to a playlist. Every call adds a new entry, so a tab can
+         *     be listed several times, e.g. with different durations. */
         post: {
             parameters: {
                 query?: never;
@@ -113,79 +163,31 @@ export interface paths {
                         [name: string]: unknown;
                     };
                     content: {
-                        "text/plain; charset=utf-8": string;
+                        "application/json; charset=utf-8": components["schemas"]["PlaylistEntryInfo"];
                     };
                 };
-            };
-        };
-        delete?: never;
-        options?: never;
-        head?: never;
-        patch?: never;
-        trace?: never;
-    };
-    "/status": {
-        parameters: {
-            query?: never;
-            header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        /** Retrieve basic device status information. */
-        get: {
-            parameters: {
-                query?: never;
-                header?: never;
-                path?: never;
-                cookie?: never;
-            };
-            requestBody?: never;
-            responses: {
-                200: {
+                400: {
                     headers: {
                         [name: string]: unknown;
                     };
                     content: {
-                        "application/json; charset=utf-8": components["schemas"]["DeviceStatus"];
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
                     };
                 };
-            };
-        };
-        put?: never;
-        post?: never;
-        delete?: never;
-        options?: never;
-        head?: never;
-        patch?: never;
-        trace?: never;
-    };
-    "/playlists/{playlist_id}/activate": {
-        parameters: {
-            query?: never;
-            header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        get?: never;
-        put?: never;
-        /** Activate a playlist */
-        post: {
-            parameters: {
-                query?: never;
-                header?: never;
-                path: {
-                    playlist_id: string;
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
                 };
-                cookie?: never;
-            };
-            requestBody?: never;
-            responses: {
-                200: {
+                500: {
                     headers: {
                         [name: string]: unknown;
                     };
                     content: {
-                        "text/plain; charset=utf-8": string;
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
                     };
                 };
             };
@@ -196,23 +198,20 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
-    "/playlists/{playlist_id}/tabs/{tab_id}/activate": {
+    "/playlists/{playlist_id}": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        get?: never;
-        put?: never;
-        /** Activate a tab immediately */
-        post: {
+        /** Get a single playlist with its tabs. */
+        get: {
             parameters: {
                 query?: never;
                 header?: never;
                 path: {
                     playlist_id: string;
-                    tab_id: string;
                 };
                 cookie?: never;
             };
@@ -223,25 +222,27 @@ export interface paths {
                         [name: string]: unknown;
                     };
                     content: {
-                        "text/plain; charset=utf-8": string;
+                        "application/json; charset=utf-8": components["schemas"]["PlaylistDetails"];
+                    };
+                };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
                     };
                 };
             };
         };
-        delete?: never;
-        options?: never;
-        head?: never;
-        patch?: never;
-        trace?: never;
-    };
-    "/playlists/{playlist_id}": {
-        parameters: {
-            query?: never;
-            header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        get?: never;
         /** Update an existing playlist */
         put: {
             parameters: {
@@ -266,6 +267,30 @@ export interface paths {
                         "application/json; charset=utf-8": components["schemas"]["PlaylistInfo"];
                     };
                 };
+                400: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
             };
         };
         post?: never;
@@ -289,6 +314,22 @@ export interface paths {
                         "text/plain; charset=utf-8": string;
                     };
                 };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
             };
         };
         options?: never;
@@ -303,7 +344,34 @@ export interface paths {
             path?: never;
             cookie?: never;
         };
-        get?: never;
+        /** Get all tabs, including tabs that are not part of any playlist. */
+        get: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path?: never;
+                cookie?: never;
+            };
+            requestBody?: never;
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["TabDetails"][];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
         put?: never;
         /** Create a new tab */
         post: {
@@ -327,6 +395,31 @@ export interface paths {
                         "application/json; charset=utf-8": components["schemas"]["TabInfo"];
                     };
                 };
+                400: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                /** @description A tab with this id already exists */
+                409: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
             };
         };
         delete?: never;
@@ -342,9 +435,8 @@ export interface paths {
             path?: never;
             cookie?: never;
         };
-        get?: never;
-        /** Update an existing tab */
-        put: {
+        /** Get a single tab with its playlist membership and runtime state. */
+        get: {
             parameters: {
                 query?: never;
                 header?: never;
@@ -353,25 +445,36 @@ export interface paths {
                 };
                 cookie?: never;
             };
-            requestBody: {
-                content: {
-                    "application/json; charset=utf-8": components["schemas"]["UpdateTabRequest"];
-                };
-            };
+            requestBody?: never;
             responses: {
                 200: {
                     headers: {
                         [name: string]: unknown;
                     };
                     content: {
-                        "application/json; charset=utf-8": components["schemas"]["TabInfo"];
+                        "application/json; charset=utf-8": components["schemas"]["TabDetails"];
+                    };
+                };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
                     };
                 };
             };
         };
-        post?: never;
-        /** Delete a tab */
-        delete: {
+        /** Update an existing tab */
+        put: {
             parameters: {
                 query?: never;
                 header?: never;
@@ -380,40 +483,53 @@ export interface paths {
                 };
                 cookie?: never;
             };
-            requestBody?: never;
+            requestBody: {
+                content: {
+                    "application/json; charset=utf-8": components["schemas"]["UpdateTabRequest"];
+                };
+            };
             responses: {
                 200: {
                     headers: {
                         [name: string]: unknown;
                     };
                     content: {
-                        "text/plain; charset=utf-8": string;
+                        "application/json; charset=utf-8": components["schemas"]["TabInfo"];
                     };
                 };
-            };
-        };
-        options?: never;
-        head?: never;
-        patch?: never;
-        trace?: never;
-    };
-    "/playlists/{playlist_id}/tabs/{tab_id}": {
-        parameters: {
-            query?: never;
-            header?: never;
-            path?: never;
-            cookie?: never;
+                400: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
         };
-        get?: never;
-        put?: never;
         post?: never;
-        /** Remove a tab from a playlist */
+        /** Delete a tab */
         delete: {
             parameters: {
                 query?: never;
                 header?: never;
                 path: {
-                    playlist_id: string;
                     tab_id: string;
                 };
                 cookie?: never;
@@ -428,6 +544,22 @@ export interface paths {
                         "text/plain; charset=utf-8": string;
                     };
                 };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
             };
         };
         options?: never;
@@ -435,40 +567,80 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
-    "/playlists/{playlist_id}/reorder": {
+    "/status": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        get?: never;
-        /** Reorder tabs in a playlist */
-        put: {
+        /** Retrieve basic device status information. */
+        get: {
             parameters: {
                 query?: never;
                 header?: never;
-                path: {
-                    playlist_id: string;
-                };
+                path?: never;
                 cookie?: never;
             };
-            requestBody: {
-                content: {
-                    "application/json; charset=utf-8": components["schemas"]["ReorderTabsRequest"];
+            requestBody?: never;
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["DeviceStatus"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
                 };
             };
+        };
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/events": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Subscribe to live controller events as Server-Sent Events.
+         * @description Each message is a JSON `DomainEvent`; the SSE event name matches its `type`.
+         */
+        get: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path?: never;
+                cookie?: never;
+            };
+            requestBody?: never;
             responses: {
                 200: {
                     headers: {
                         [name: string]: unknown;
                     };
                     content: {
-                        "text/plain; charset=utf-8": string;
+                        "text/event-stream": components["schemas"]["DomainEvent"][];
                     };
                 };
             };
         };
+        put?: never;
         post?: never;
         delete?: never;
         options?: never;
@@ -476,23 +648,19 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
-    "/tabs/{tab_id}/refresh": {
+    "/webhooks": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        get?: never;
-        put?: never;
-        /** Refresh a tab (reload page) */
-        post: {
+        /** List the configured webhook targets. */
+        get: {
             parameters: {
                 query?: never;
                 header?: never;
-                path: {
-                    tab_id: string;
-                };
+                path?: never;
                 cookie?: never;
             };
             requestBody?: never;
@@ -502,55 +670,197 @@ export interface paths {
                         [name: string]: unknown;
                     };
                     content: {
-                        "text/plain; charset=utf-8": string;
+                        "application/json; charset=utf-8": components["schemas"]["WebhookInfo"][];
                     };
                 };
             };
         };
+        put?: never;
+        post?: never;
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/tabs/{tab_id}/recreate": {
+    "/webhooks/deliveries": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        get?: never;
+        /** List recent webhook deliveries, newest first. */
+        get: {
+            parameters: {
+                query?: {
+                    /** @description Only return deliveries with this status */
+                    status?: components["schemas"]["DeliveryStatus"];
+                    /** @description Maximum number of deliveries to return (default 50) */
+                    limit?: number;
+                };
+                header?: never;
+                path?: never;
+                cookie?: never;
+            };
+            requestBody?: never;
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["WebhookDeliveryInfo"][];
+                    };
+                };
+                400: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
         put?: never;
-        /** Recreate a tab (close and reopen) */
-        post: {
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/reports/plays": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Number of plays and display time per tab, playlist or day.
+         *     Plays are included if they started within `from` and `to`. */
+        get: {
             parameters: {
-                query?: never;
+                query: {
+                    /** @description Dimension to aggregate by */
+                    group_by: components["schemas"]["PlayReportGrouping"];
+                    /** @description Start of the period (seconds since epoch, inclusive) */
+                    from?: number;
+                    /** @description End of the period (seconds since epoch, exclusive) */
+                    to?: number;
+                    /** @description Only include plays of this tab */
+                    tab_id?: string;
+                    /** @description Only include plays of this playlist */
+                    playlist_id?: string;
+                };
                 header?: never;
-                path: {
-                    tab_id: string;
+                path?: never;
+                cookie?: never;
+            };
+            requestBody?: never;
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["PlayReportEntry"][];
+                    };
+                };
+                400: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/reports/plays.csv": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Export every play that started within `from` and `to` as CSV */
+        get: {
+            parameters: {
+                query?: {
+                    /** @description Start of the period (seconds since epoch, inclusive) */
+                    from?: number;
+                    /** @description End of the period (seconds since epoch, exclusive) */
+                    to?: number;
                 };
+                header?: never;
+                path?: never;
                 cookie?: never;
             };
             requestBody?: never;
             responses: {
+                /** @description One row per play */
                 200: {
                     headers: {
+                        "CONTENT-DISPOSITION": string;
                         [name: string]: unknown;
                     };
                     content: {
-                        "text/plain; charset=utf-8": string;
+                        "text/csv": string;
+                    };
+                };
+                400: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
                     };
                 };
             };
         };
+        put?: never;
+        post?: never;
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/playlists/{playlist_id}/tabs/{tab_id}/toggle": {
+    "/playlists/{playlist_id}/activate": {
         parameters: {
             query?: never;
             header?: never;
@@ -558,22 +868,18 @@ export interface paths {
             cookie?: never;
         };
         get?: never;
-        /** Toggle tab enabled state in playlist */
-        put: {
+        put?: never;
+        /** Activate a playlist */
+        post: {
             parameters: {
                 query?: never;
                 header?: never;
                 path: {
                     playlist_id: string;
-                    tab_id: string;
                 };
                 cookie?: never;
             };
-            requestBody: {
-                content: {
-                    "application/json; charset=utf-8": components["schemas"]["ToggleTabEnabledRequest"];
-                };
-            };
+            requestBody?: never;
             responses: {
                 200: {
                     headers: {
@@ -583,65 +889,1600 @@ export interface paths {
                         "text/plain; charset=utf-8": string;
                     };
                 };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                /** @description Chromium is not running */
+                503: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
             };
         };
-        post?: never;
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-}
-export type webhooks = Record<string, never>;
-export interface components {
-    schemas: {
-        /** AddTabToPlaylistRequest */
-        AddTabToPlaylistRequest: {
-            tab_id: string;
-            /** Format: int64 */
-            order_index: number;
-            /** Format: int64 */
+    "/playlists/{playlist_id}/tabs/{tab_id}/activate": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /** Activate a tab immediately */
+        post: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path: {
+                    playlist_id: string;
+                    tab_id: string;
+                };
+                cookie?: never;
+            };
+            requestBody?: never;
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "text/plain; charset=utf-8": string;
+                    };
+                };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                /** @description Chromium is not running */
+                503: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/playlists/{playlist_id}/entries/{entry_id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        post?: never;
+        /** Remove an entry from a playlist, other entries of the same tab stay */
+        delete: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path: {
+                    playlist_id: string;
+                    entry_id: string;
+                };
+                cookie?: never;
+            };
+            requestBody?: never;
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "text/plain; charset=utf-8": string;
+                    };
+                };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/playlists/{playlist_id}/reorder": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        /** Reorder the entries of a playlist */
+        put: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path: {
+                    playlist_id: string;
+                };
+                cookie?: never;
+            };
+            requestBody: {
+                content: {
+                    "application/json; charset=utf-8": components["schemas"]["ReorderTabsRequest"];
+                };
+            };
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "text/plain; charset=utf-8": string;
+                    };
+                };
+                400: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/tabs/{tab_id}/refresh": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /** Refresh a tab (reload page) */
+        post: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path: {
+                    tab_id: string;
+                };
+                cookie?: never;
+            };
+            requestBody?: never;
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "text/plain; charset=utf-8": string;
+                    };
+                };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                /** @description Chromium is not running */
+                503: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/tabs/{tab_id}/recreate": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /** Recreate a tab (close and reopen) */
+        post: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path: {
+                    tab_id: string;
+                };
+                cookie?: never;
+            };
+            requestBody?: never;
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "text/plain; charset=utf-8": string;
+                    };
+                };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                /** @description Chromium is not running */
+                503: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/overlays": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** List the overlays currently shown on top of the display. */
+        get: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path?: never;
+                cookie?: never;
+            };
+            requestBody?: never;
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["OverlayInfo"][];
+                    };
+                };
+            };
+        };
+        put?: never;
+        /**
+         * Show a text banner or ticker on top of whatever is displayed.
+         * @description The overlay stays visible across tab switches until it expires or is removed.
+         */
+        post: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path?: never;
+                cookie?: never;
+            };
+            requestBody: {
+                content: {
+                    "application/json; charset=utf-8": components["schemas"]["OverlayRequest"];
+                };
+            };
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["OverlayInfo"];
+                    };
+                };
+                400: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                /** @description Chromium is not running */
+                503: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        /** Remove all overlays. */
+        delete: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path?: never;
+                cookie?: never;
+            };
+            requestBody?: never;
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "text/plain; charset=utf-8": string;
+                    };
+                };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                /** @description Chromium is not running */
+                503: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/overlays/{overlay_id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        post?: never;
+        /** Remove a single overlay. */
+        delete: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path: {
+                    overlay_id: string;
+                };
+                cookie?: never;
+            };
+            requestBody?: never;
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "text/plain; charset=utf-8": string;
+                    };
+                };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                /** @description Chromium is not running */
+                503: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/takeover": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /**
+         * Show a URL for a limited time, then restore the previous playlist and tab.
+         * @description Rotation is suspended during the takeover. Starting a new takeover
+         *     replaces the running one.
+         */
+        post: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path?: never;
+                cookie?: never;
+            };
+            requestBody: {
+                content: {
+                    "application/json; charset=utf-8": components["schemas"]["TakeoverRequest"];
+                };
+            };
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["TakeoverInfo"];
+                    };
+                };
+                400: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                /** @description Chromium is not running */
+                503: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        /** End the running takeover early and restore the previous playlist and tab. */
+        delete: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path?: never;
+                cookie?: never;
+            };
+            requestBody?: never;
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "text/plain; charset=utf-8": string;
+                    };
+                };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                /** @description Chromium is not running */
+                503: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/rotation/pause": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /** Pause rotation on the current tab until it is resumed. */
+        post: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path?: never;
+                cookie?: never;
+            };
+            requestBody?: never;
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "text/plain; charset=utf-8": string;
+                    };
+                };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                /** @description Chromium is not running */
+                503: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/rotation/resume": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /** Resume rotation of the active playlist, ending a hold. */
+        post: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path?: never;
+                cookie?: never;
+            };
+            requestBody?: never;
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "text/plain; charset=utf-8": string;
+                    };
+                };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                /** @description Chromium is not running */
+                503: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/rotation/hold": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /**
+         * Stay on the current tab for a while, then resume rotation.
+         * @description A new hold replaces the running one; pausing or resuming ends it.
+         */
+        post: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path?: never;
+                cookie?: never;
+            };
+            requestBody: {
+                content: {
+                    "application/json; charset=utf-8": components["schemas"]["HoldRequest"];
+                };
+            };
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["RotationInfo"];
+                    };
+                };
+                400: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                /** @description A takeover is running or no playlist is active */
+                409: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                /** @description Chromium is not running */
+                503: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/input/keys/{key}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /** Press a key as if it came from a keypad or remote, running the action
+         *     configured for it under `[input.keys]`. */
+        post: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path: {
+                    key: string;
+                };
+                cookie?: never;
+            };
+            requestBody?: never;
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "text/plain; charset=utf-8": string;
+                    };
+                };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                /** @description Chromium is not running */
+                503: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/assets": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** List uploaded assets and the used quota */
+        get: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path?: never;
+                cookie?: never;
+            };
+            requestBody?: never;
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["AssetLibraryInfo"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        put?: never;
+        /** Upload a file to the asset library.
+         *     Uploading content that already exists returns the existing asset. */
+        post: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path?: never;
+                cookie?: never;
+            };
+            requestBody: {
+                content: {
+                    "multipart/form-data": {
+                        /**
+                         * Format: binary
+                         * @description The file, its name determines the stored MIME type
+                         */
+                        file: string;
+                    };
+                };
+            };
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["AssetInfo"];
+                    };
+                };
+                400: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                /** @description The asset library has no room for the file */
+                413: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/assets/{asset_id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        post?: never;
+        /** Delete an asset and its file */
+        delete: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path: {
+                    asset_id: string;
+                };
+                cookie?: never;
+            };
+            requestBody?: never;
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "text/plain; charset=utf-8": string;
+                    };
+                };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/playlists/{playlist_id}/entries/{entry_id}/weight": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        /** Set how likely a tab is picked when the playlist rotates in weighted mode */
+        put: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path: {
+                    playlist_id: string;
+                    entry_id: string;
+                };
+                cookie?: never;
+            };
+            requestBody: {
+                content: {
+                    "application/json; charset=utf-8": components["schemas"]["SetTabWeightRequest"];
+                };
+            };
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "text/plain; charset=utf-8": string;
+                    };
+                };
+                400: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/playlists/{playlist_id}/entries/{entry_id}/toggle": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        /** Toggle whether a playlist entry takes part in rotation */
+        put: {
+            parameters: {
+                query?: never;
+                header?: never;
+                path: {
+                    playlist_id: string;
+                    entry_id: string;
+                };
+                cookie?: never;
+            };
+            requestBody: {
+                content: {
+                    "application/json; charset=utf-8": components["schemas"]["ToggleTabEnabledRequest"];
+                };
+            };
+            responses: {
+                200: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "text/plain; charset=utf-8": string;
+                    };
+                };
+                400: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                404: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+                500: {
+                    headers: {
+                        [name: string]: unknown;
+                    };
+                    content: {
+                        "application/json; charset=utf-8": components["schemas"]["ApiError"];
+                    };
+                };
+            };
+        };
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+}
+export type webhooks = Record<string, never>;
+export interface components {
+    schemas: {
+        /** AddTabToPlaylistRequest */
+        AddTabToPlaylistRequest: {
+            tab_id: string;
+            /** Format: int64 */
+            order_index: number;
+            /** Format: int64 */
             duration_seconds?: number;
             enabled?: boolean;
+            /**
+             * Format: int64
+             * @description Relative chance of being picked in weighted rotation (default 1)
+             */
+            weight?: number;
+        };
+        /**
+         * ApiError
+         * @description Error body returned by every failing endpoint
+         */
+        ApiError: {
+            /** @description Machine readable error code */
+            code: components["schemas"]["ErrorCode"] & unknown;
+            /** @description Human readable description */
+            message: string;
+            /** @description Additional context, e.g. the offending ids */
+            details?: unknown;
+        };
+        /**
+         * AssetInfo
+         * @description Uploaded file in the asset library
+         */
+        AssetInfo: {
+            /** @description Unique identifier of the asset */
+            id: string;
+            /** @description Original name of the uploaded file */
+            filename: string;
+            /** @description MIME type derived from the file name */
+            mime_type: string;
+            /**
+             * Format: uint64
+             * @description Size in bytes
+             */
+            size: number;
+            /** @description Hex encoded SHA-256 of the content */
+            sha256: string;
+            /** @description URL to use in tabs, served by this device */
+            url: string;
+            /**
+             * Format: int64
+             * @description When the asset was uploaded (seconds since epoch)
+             */
+            created_at: number;
+        };
+        /**
+         * AssetLibraryInfo
+         * @description Contents and usage of the asset library
+         */
+        AssetLibraryInfo: {
+            /**
+             * Format: uint64
+             * @description Combined size of all assets in bytes
+             */
+            used_bytes: number;
+            /**
+             * Format: uint64
+             * @description Maximum combined size in bytes
+             */
+            quota_bytes: number;
+            /** @description Assets, newest first */
+            assets: components["schemas"]["AssetInfo"][];
+        };
+        /**
+         * BrowserCrashedEvent
+         * @description Chromium exited unexpectedly and a relaunch was attempted
+         */
+        BrowserCrashedEvent: {
+            /** @description Whether Chromium is running again */
+            restarted: boolean;
+            /** @description Why the relaunch failed */
+            error?: string;
+        };
+        /**
+         * BrowserStatusChangedEvent
+         * @description Chromium was launched or exited
+         */
+        BrowserStatusChangedEvent: {
+            /** @description Whether Chromium is now running */
+            running: boolean;
+        };
+        /** CreatePlaylistRequest */
+        CreatePlaylistRequest: {
+            id: string;
+            name: string;
+            /** Format: int64 */
+            interval_seconds: number;
+            rotation_mode?: components["schemas"]["RotationMode"];
+        };
+        /** CreateTabRequest */
+        CreateTabRequest: {
+            id: string;
+            name: string;
+            /**
+             * @description Page or media URL, not needed for `markdown` and `html` tabs
+             * @default
+             */
+            url: string;
+            persist?: boolean;
+            /**
+             * Format: int32
+             * @description Emulated viewport width in CSS pixels
+             */
+            target_width?: number;
+            /**
+             * Format: int32
+             * @description Emulated viewport height in CSS pixels
+             */
+            target_height?: number;
+            scaling_mode?: components["schemas"]["ScalingMode"];
+            /** @description What the tab displays, defaults to a web page */
+            content_type?: components["schemas"]["TabContentType"] & unknown;
+            /** @description Markdown or HTML body for `markdown` and `html` tabs */
+            content?: string;
+        };
+        /**
+         * @description Delivery state of a webhook call
+         * @enum {string}
+         */
+        DeliveryStatus: "pending" | "delivered" | "failed";
+        /**
+         * DeviceStatus
+         * @description Current device status
+         */
+        DeviceStatus: {
+            /** @description Device unique identifier */
+            device_id: string;
+            /** @description Device display name */
+            device_name: string;
+            /** @description Currently active playlist ID (if any) */
+            current_playlist?: string;
+            /** @description Currently active tab ID (if any) */
+            current_tab?: string;
+            /**
+             * Format: uint64
+             * @description Uptime in seconds
+             */
+            uptime_seconds: number;
+            /**
+             * Format: uint64
+             * @description When the current tab was opened (seconds since epoch, if available)
+             */
+            current_tab_opened_at?: number;
+            /** @description Ad-hoc URL currently shown instead of the playlist (if any) */
+            takeover?: components["schemas"]["TakeoverInfo"] & unknown;
+            /** @description Whether the playlist rotates, is paused or held on the current tab */
+            rotation: components["schemas"]["RotationInfo"] & unknown;
+            /** @description Connection to the MQTT broker (if Home Assistant is configured) */
+            mqtt?: components["schemas"]["MqttStatus"] & unknown;
+        };
+        /**
+         * DisplayPowerChangedEvent
+         * @description The display was switched on or off
+         */
+        DisplayPowerChangedEvent: {
+            /** @description Whether the display is now on */
+            on: boolean;
+        };
+        /** @description Domain event published by the controller, discriminated by `type` */
+        DomainEvent: components["schemas"]["DomainEvent_TabActivatedEvent"] | components["schemas"]["DomainEvent_PlaylistChangedEvent"] | components["schemas"]["DomainEvent_TabChangedEvent"] | components["schemas"]["DomainEvent_TabCrashedEvent"] | components["schemas"]["DomainEvent_TabFailedEvent"] | components["schemas"]["DomainEvent_BrowserStatusChangedEvent"] | components["schemas"]["DomainEvent_BrowserCrashedEvent"] | components["schemas"]["DomainEvent_DisplayPowerChangedEvent"] | components["schemas"]["DomainEvent_TakeoverStartedEvent"] | components["schemas"]["DomainEvent_TakeoverEndedEvent"];
+        /** @description Domain event published by the controller, discriminated by `type` */
+        DomainEvent_BrowserCrashedEvent: {
+            /**
+             * @example browser_crashed
+             * @enum {string}
+             */
+            type: "browser_crashed";
+        } & components["schemas"]["BrowserCrashedEvent"];
+        /** @description Domain event published by the controller, discriminated by `type` */
+        DomainEvent_BrowserStatusChangedEvent: {
+            /**
+             * @example browser_status_changed
+             * @enum {string}
+             */
+            type: "browser_status_changed";
+        } & components["schemas"]["BrowserStatusChangedEvent"];
+        /** @description Domain event published by the controller, discriminated by `type` */
+        DomainEvent_DisplayPowerChangedEvent: {
+            /**
+             * @example display_power_changed
+             * @enum {string}
+             */
+            type: "display_power_changed";
+        } & components["schemas"]["DisplayPowerChangedEvent"];
+        /** @description Domain event published by the controller, discriminated by `type` */
+        DomainEvent_PlaylistChangedEvent: {
+            /**
+             * @example playlist_changed
+             * @enum {string}
+             */
+            type: "playlist_changed";
+        } & components["schemas"]["PlaylistChangedEvent"];
+        /** @description Domain event published by the controller, discriminated by `type` */
+        DomainEvent_TabActivatedEvent: {
+            /**
+             * @example tab_activated
+             * @enum {string}
+             */
+            type: "tab_activated";
+        } & components["schemas"]["TabActivatedEvent"];
+        /** @description Domain event published by the controller, discriminated by `type` */
+        DomainEvent_TabChangedEvent: {
+            /**
+             * @example tab_changed
+             * @enum {string}
+             */
+            type: "tab_changed";
+        } & components["schemas"]["TabChangedEvent"];
+        /** @description Domain event published by the controller, discriminated by `type` */
+        DomainEvent_TabCrashedEvent: {
+            /**
+             * @example tab_crashed
+             * @enum {string}
+             */
+            type: "tab_crashed";
+        } & components["schemas"]["TabCrashedEvent"];
+        /** @description Domain event published by the controller, discriminated by `type` */
+        DomainEvent_TabFailedEvent: {
+            /**
+             * @example tab_failed
+             * @enum {string}
+             */
+            type: "tab_failed";
+        } & components["schemas"]["TabFailedEvent"];
+        /** @description Domain event published by the controller, discriminated by `type` */
+        DomainEvent_TakeoverEndedEvent: {
+            /**
+             * @example takeover_ended
+             * @enum {string}
+             */
+            type: "takeover_ended";
+        } & components["schemas"]["TakeoverEndedEvent"];
+        /** @description Domain event published by the controller, discriminated by `type` */
+        DomainEvent_TakeoverStartedEvent: {
+            /**
+             * @example takeover_started
+             * @enum {string}
+             */
+            type: "takeover_started";
+        } & components["schemas"]["TakeoverStartedEvent"];
+        /**
+         * @description Machine readable reason of a failed request
+         * @enum {string}
+         */
+        ErrorCode: "bad_request" | "playlist_not_found" | "tab_not_found" | "tab_not_in_playlist" | "entry_not_found" | "already_exists" | "browser_not_ready" | "takeover_not_active" | "takeover_active" | "no_active_playlist" | "overlay_not_found" | "asset_not_found" | "quota_exceeded" | "key_not_mapped" | "command_failed" | "internal";
+        /**
+         * HoldRequest
+         * @description Hold the current tab, pausing rotation until the hold expires
+         */
+        HoldRequest: {
+            /**
+             * Format: uint64
+             * @description How long to stay on the current tab before rotation resumes
+             */
+            duration_seconds: number;
         };
-        /** CreatePlaylistRequest */
-        CreatePlaylistRequest: {
-            id: string;
-            name: string;
-            /** Format: int64 */
-            interval_seconds: number;
+        /**
+         * MqttStatus
+         * @description Connection to the MQTT broker
+         */
+        MqttStatus: {
+            /** @description Whether the broker accepted the connection */
+            connected: boolean;
+            /**
+             * Format: uint64
+             * @description When the connection was last established or lost (seconds since epoch)
+             */
+            changed_at?: number;
+            /** @description Why the connection was last lost or refused */
+            last_error?: string;
+            /**
+             * Format: uint32
+             * @description Failed attempts since the last successful connection
+             */
+            failed_attempts: number;
         };
-        /** CreateTabRequest */
-        CreateTabRequest: {
+        /**
+         * OverlayInfo
+         * @description An overlay currently shown
+         */
+        OverlayInfo: {
             id: string;
-            name: string;
-            url: string;
-            persist?: boolean;
+            text: string;
+            severity: components["schemas"]["OverlaySeverity"];
+            position: components["schemas"]["OverlayPosition"];
+            style: components["schemas"]["OverlayStyle"];
+            /**
+             * Format: uint64
+             * @description When the overlay is removed (seconds since epoch, if it expires)
+             */
+            expires_at?: number;
         };
         /**
-         * DeviceStatus
-         * @description Current device status
+         * @description Where on the screen an overlay is placed
+         * @enum {string}
          */
-        DeviceStatus: {
-            /** @description Device unique identifier */
-            device_id: string;
-            /** @description Device display name */
-            device_name: string;
-            /** @description Currently active playlist ID (if any) */
-            current_playlist?: string;
-            /** @description Currently active tab ID (if any) */
-            current_tab?: string;
+        OverlayPosition: "top" | "bottom" | "center";
+        /**
+         * OverlayRequest
+         * @description Show a text overlay on top of the visible page
+         */
+        OverlayRequest: {
+            /** @description Reuse an id to replace an existing overlay (generated if omitted) */
+            id?: string;
+            /** @description Text to display */
+            text: string;
+            /** @description Color scheme (default info) */
+            severity?: components["schemas"]["OverlaySeverity"] & unknown;
+            /** @description Placement on screen (default top) */
+            position?: components["schemas"]["OverlayPosition"] & unknown;
+            /** @description Static banner or scrolling ticker (default banner) */
+            style?: components["schemas"]["OverlayStyle"] & unknown;
             /**
              * Format: uint64
-             * @description Uptime in seconds
+             * @description Remove the overlay after this many seconds (kept until removed if omitted)
              */
-            uptime_seconds: number;
+            duration_seconds?: number;
+        };
+        /**
+         * @description How urgent an overlay is, determines its colors
+         * @enum {string}
+         */
+        OverlaySeverity: "info" | "warning" | "critical";
+        /**
+         * @description How the text of an overlay is presented
+         * @enum {string}
+         */
+        OverlayStyle: "banner" | "ticker";
+        /**
+         * PlayReportEntry
+         * @description Plays and display time of one tab, playlist or day
+         */
+        PlayReportEntry: {
+            /** @description Tab id, playlist id or day (YYYY-MM-DD, UTC) depending on the grouping */
+            key: string;
             /**
-             * Format: uint64
-             * @description When the current tab was opened (seconds since epoch, if available)
+             * Format: int64
+             * @description Number of times it was shown
              */
-            current_tab_opened_at?: number;
+            plays: number;
+            /**
+             * Format: double
+             * @description Total time it was visible
+             */
+            total_seconds: number;
+        };
+        /**
+         * @description Dimension display time is aggregated by
+         * @enum {string}
+         */
+        PlayReportGrouping: "tab" | "playlist" | "day";
+        /**
+         * @description Kind of playlist change
+         * @enum {string}
+         */
+        PlaylistChange: "created" | "activated" | "updated" | "entries_changed" | "deleted";
+        /**
+         * PlaylistChangedEvent
+         * @description A playlist was created, activated, edited or deleted
+         */
+        PlaylistChangedEvent: {
+            /** @description Playlist that changed */
+            playlist_id: string;
+            /** @description What happened to the playlist */
+            change: components["schemas"]["PlaylistChange"] & unknown;
+        };
+        /**
+         * PlaylistDetails
+         * @description A playlist with all of its tabs
+         */
+        PlaylistDetails: {
+            /** @description Unique identifier for the playlist */
+            id: string;
+            /** @description Display name of the playlist */
+            name: string;
+            /**
+             * Format: int64
+             * @description Interval between tab switches in seconds
+             */
+            interval_seconds: number;
+            /** @description Whether this playlist is currently active */
+            is_active: boolean;
+            /** @description Order in which the tabs are rotated */
+            rotation_mode: components["schemas"]["RotationMode"] & unknown;
+            /** @description Tabs of the playlist in playlist order */
+            tabs: components["schemas"]["TabInfo"][];
+        };
+        /**
+         * PlaylistEntryInfo
+         * @description A tab added to a playlist
+         */
+        PlaylistEntryInfo: {
+            /** @description Id addressing this entry in reorder, toggle, weight and remove requests */
+            entry_id: string;
+            /** @description Playlist the entry belongs to */
+            playlist_id: string;
+            /** @description Tab shown by the entry */
+            tab_id: string;
         };
         /**
          * PlaylistInfo
@@ -664,11 +2505,166 @@ export interface components {
             interval_seconds: number;
             /** @description Whether this playlist is currently active */
             is_active: boolean;
+            /** @description Order in which the tabs are rotated */
+            rotation_mode: components["schemas"]["RotationMode"] & unknown;
         };
         /** ReorderTabsRequest */
         ReorderTabsRequest: {
             tab_orders: components["schemas"]["TabOrder"][];
         };
+        /**
+         * RotationInfo
+         * @description Rotation state of the active playlist
+         */
+        RotationInfo: {
+            state: components["schemas"]["RotationState"];
+            /**
+             * Format: uint64
+             * @description When a hold expires (seconds since epoch)
+             */
+            hold_until?: number;
+            /**
+             * Format: uint64
+             * @description Seconds left until a hold expires
+             */
+            hold_remaining_seconds?: number;
+        };
+        /**
+         * @description Order in which a playlist rotates through its tabs
+         * @enum {string}
+         */
+        RotationMode: "sequential" | "shuffle" | "weighted";
+        /**
+         * @description Whether the playlist rotates
+         * @enum {string}
+         */
+        RotationState: "running" | "paused" | "held" | "interacting" | "suspended";
+        /**
+         * @description How a tab's target viewport is mapped onto the physical display
+         * @enum {string}
+         */
+        ScalingMode: "fit" | "fill" | "native";
+        /** SetTabWeightRequest */
+        SetTabWeightRequest: {
+            /** Format: int64 */
+            weight: number;
+        };
+        /**
+         * TabActivatedEvent
+         * @description A tab was brought to front
+         */
+        TabActivatedEvent: {
+            /** @description Tab that is now visible */
+            tab_id: string;
+            /** @description Playlist the tab was activated in */
+            playlist_id: string;
+            /** @description URL the tab displays */
+            url: string;
+        };
+        /**
+         * @description Kind of tab change
+         * @enum {string}
+         */
+        TabChange: "created" | "updated" | "deleted";
+        /**
+         * TabChangedEvent
+         * @description A tab was created, edited or deleted
+         */
+        TabChangedEvent: {
+            /** @description Tab that changed */
+            tab_id: string;
+            /** @description What happened to the tab */
+            change: components["schemas"]["TabChange"] & unknown;
+        };
+        /**
+         * @description What a tab displays
+         * @enum {string}
+         */
+        TabContentType: "url" | "image" | "video" | "pdf" | "markdown" | "html";
+        /**
+         * TabCrashedEvent
+         * @description The renderer of a tab crashed
+         */
+        TabCrashedEvent: {
+            /** @description Tab whose page crashed */
+            tab_id: string;
+        };
+        /**
+         * TabDetails
+         * @description A tab with the playlists it belongs to and its live state in the browser
+         */
+        TabDetails: {
+            /** @description Unique identifier for the tab */
+            id: string;
+            /** @description Display name of the tab */
+            name: string;
+            /** @description URL the tab displays, or the media source for image, video and pdf tabs */
+            url: string;
+            /** @description Whether this tab persists in browser memory */
+            persist: boolean;
+            /**
+             * Format: int32
+             * @description Emulated viewport width in CSS pixels (if configured)
+             */
+            target_width?: number;
+            /**
+             * Format: int32
+             * @description Emulated viewport height in CSS pixels (if configured)
+             */
+            target_height?: number;
+            /** @description How the emulated viewport is scaled onto the display */
+            scaling_mode?: components["schemas"]["ScalingMode"] & unknown;
+            /** @description What the tab displays */
+            content_type: components["schemas"]["TabContentType"] & unknown;
+            /** @description Markdown or HTML body for markdown and html tabs */
+            content?: string;
+            /** @description Whether the tab currently loads successfully */
+            health: components["schemas"]["TabHealthInfo"] & unknown;
+            /** @description Playlists containing this tab (empty for orphaned tabs) */
+            playlists: components["schemas"]["TabMembership"][];
+            /** @description Live state of the tab in the browser */
+            runtime: components["schemas"]["TabRuntimeInfo"] & unknown;
+        };
+        /**
+         * TabFailedEvent
+         * @description A tab failed to load or activate
+         */
+        TabFailedEvent: {
+            /** @description Tab that failed */
+            tab_id: string;
+            /** @description Error of the failed attempt */
+            error: string;
+            /**
+             * Format: uint32
+             * @description Number of failed attempts in a row
+             */
+            consecutive_failures: number;
+        };
+        /**
+         * TabHealthInfo
+         * @description Load health of a tab
+         */
+        TabHealthInfo: {
+            /** @description False if the last attempt to load the tab failed */
+            healthy: boolean;
+            /**
+             * Format: uint32
+             * @description Number of failed load attempts in a row
+             */
+            consecutive_failures: number;
+            /** @description Error of the last failed load attempt */
+            last_error?: string;
+            /**
+             * Format: uint64
+             * @description When the tab last failed to load (seconds since epoch)
+             */
+            last_failure_at?: number;
+            /**
+             * Format: uint64
+             * @description When rotation will retry the tab (seconds since epoch)
+             */
+            retry_at?: number;
+        };
         /**
          * TabInfo
          * @description Information about a tab
@@ -678,13 +2674,25 @@ export interface components {
             id: string;
             /** @description Display name of the tab */
             name: string;
-            /** @description URL the tab displays */
+            /** @description URL the tab displays, or the media source for image, video and pdf tabs */
             url: string;
+            /** @description Playlist entry the tab is listed as (none outside of a playlist) */
+            entry_id?: string;
             /**
              * Format: uint64
              * @description Order within the playlist (0-based index)
              */
             order_index: number;
+            /**
+             * Format: int64
+             * @description Display duration of this entry overriding the playlist interval (if set)
+             */
+            duration_seconds?: number;
+            /**
+             * Format: int64
+             * @description Relative chance of being picked in weighted rotation
+             */
+            weight: number;
             /** @description Whether this tab persists in browser memory */
             persist: boolean;
             /**
@@ -697,13 +2705,146 @@ export interface components {
              * @description Viewport height in pixels (if available)
              */
             viewport_height?: number;
+            /**
+             * Format: int32
+             * @description Emulated viewport width in CSS pixels (if configured)
+             */
+            target_width?: number;
+            /**
+             * Format: int32
+             * @description Emulated viewport height in CSS pixels (if configured)
+             */
+            target_height?: number;
+            /** @description How the emulated viewport is scaled onto the display */
+            scaling_mode?: components["schemas"]["ScalingMode"] & unknown;
+            /** @description What the tab displays */
+            content_type: components["schemas"]["TabContentType"] & unknown;
+            /** @description Markdown or HTML body for markdown and html tabs */
+            content?: string;
+            /** @description Whether the tab currently loads successfully */
+            health: components["schemas"]["TabHealthInfo"] & unknown;
+        };
+        /**
+         * TabMembership
+         * @description Membership of a tab in a playlist
+         */
+        TabMembership: {
+            /** @description Playlist entry, a tab may be listed in a playlist more than once */
+            entry_id: string;
+            /** @description Playlist containing the tab */
+            playlist_id: string;
+            /**
+             * Format: uint64
+             * @description Order within the playlist (0-based index)
+             */
+            order_index: number;
+            /** @description Whether the tab takes part in the playlist's rotation */
+            enabled: boolean;
+            /**
+             * Format: int64
+             * @description Display duration overriding the playlist interval (if set)
+             */
+            duration_seconds?: number;
+            /**
+             * Format: int64
+             * @description Relative chance of being picked in weighted rotation
+             */
+            weight: number;
         };
         /** TabOrder */
         TabOrder: {
-            tab_id: string;
+            entry_id: string;
             /** Format: int64 */
             order_index: number;
         };
+        /**
+         * TabRuntimeInfo
+         * @description Live state of a tab in the browser
+         */
+        TabRuntimeInfo: {
+            /** @description Whether a browser page is currently open for the tab */
+            page_open: boolean;
+            /** @description Whether the tab is the one currently shown */
+            visible: boolean;
+            /**
+             * Format: int32
+             * @description Measured viewport width in pixels (if the page was opened)
+             */
+            viewport_width?: number;
+            /**
+             * Format: int32
+             * @description Measured viewport height in pixels (if the page was opened)
+             */
+            viewport_height?: number;
+            /**
+             * Format: uint64
+             * @description When the last screencast frame was captured (seconds since epoch)
+             */
+            last_frame_at?: number;
+        };
+        /**
+         * TakeoverEndedEvent
+         * @description A takeover ended and the playlist was restored
+         */
+        TakeoverEndedEvent: {
+            /** @description URL that was shown during the takeover */
+            url: string;
+            /** @description True if the takeover ran out, false if it was cancelled or overridden */
+            expired: boolean;
+        };
+        /**
+         * TakeoverInfo
+         * @description A running takeover
+         */
+        TakeoverInfo: {
+            /** @description URL shown during the takeover */
+            url: string;
+            /**
+             * Format: uint64
+             * @description When the takeover started (seconds since epoch)
+             */
+            started_at: number;
+            /**
+             * Format: uint64
+             * @description When the playlist will be restored (seconds since epoch)
+             */
+            expires_at: number;
+            /**
+             * Format: uint64
+             * @description Seconds left until the playlist is restored
+             */
+            remaining_seconds: number;
+            /** @description Playlist restored afterwards */
+            previous_playlist_id?: string;
+            /** @description Tab restored afterwards */
+            previous_tab_id?: string;
+        };
+        /**
+         * TakeoverRequest
+         * @description Start a takeover of the display
+         */
+        TakeoverRequest: {
+            /** @description URL to show */
+            url: string;
+            /**
+             * Format: uint64
+             * @description How long to show the URL before restoring the playlist
+             */
+            duration_seconds: number;
+        };
+        /**
+         * TakeoverStartedEvent
+         * @description An ad-hoc URL took over the display
+         */
+        TakeoverStartedEvent: {
+            /** @description URL shown during the takeover */
+            url: string;
+            /**
+             * Format: uint64
+             * @description How long the takeover lasts
+             */
+            duration_seconds: number;
+        };
         /** ToggleTabEnabledRequest */
         ToggleTabEnabledRequest: {
             enabled: boolean;
@@ -714,12 +2855,79 @@ export interface components {
             /** Format: int64 */
             interval_seconds?: number;
             is_active?: boolean;
+            rotation_mode?: components["schemas"]["RotationMode"];
         };
         /** UpdateTabRequest */
         UpdateTabRequest: {
             name?: string;
             url?: string;
             persist?: boolean;
+            /**
+             * Format: int32
+             * @description Emulated viewport width in CSS pixels, `null` removes it
+             */
+            target_width?: number;
+            /**
+             * Format: int32
+             * @description Emulated viewport height in CSS pixels, `null` removes it
+             */
+            target_height?: number;
+            /** @description `null` goes back to the default */
+            scaling_mode?: components["schemas"]["ScalingMode"] & unknown;
+            /** @description What the tab displays, defaults to a web page */
+            content_type?: components["schemas"]["TabContentType"] & unknown;
+            /** @description Markdown or HTML body for `markdown` and `html` tabs */
+            content?: string;
+        };
+        /**
+         * WebhookDeliveryInfo
+         * @description A single webhook delivery and its outcome
+         */
+        WebhookDeliveryInfo: {
+            /** @description Unique identifier of the delivery */
+            id: string;
+            /** @description Target URL */
+            url: string;
+            /** @description Type of the delivered event */
+            event_type: string;
+            /** @description JSON body that was sent */
+            payload: string;
+            /** @description Current delivery status */
+            status: components["schemas"]["DeliveryStatus"] & unknown;
+            /**
+             * Format: int64
+             * @description Number of attempts made so far
+             */
+            attempts: number;
+            /**
+             * Format: int64
+             * @description HTTP status of the last response (if any)
+             */
+            response_status?: number;
+            /** @description Error of the last failed attempt (if any) */
+            last_error?: string;
+            /**
+             * Format: int64
+             * @description When the delivery was created (seconds since epoch)
+             */
+            created_at: number;
+            /**
+             * Format: int64
+             * @description When the delivery was last attempted (seconds since epoch)
+             */
+            updated_at: number;
+        };
+        /**
+         * WebhookInfo
+         * @description A configured webhook target
+         */
+        WebhookInfo: {
+            /** @description URL events are POSTed to */
+            url: string;
+            /** @description Event types delivered to this target (all events if empty) */
+            events: string[];
+            /** @description Whether payloads are signed */
+            signed: boolean;
         };
     };
     responses: never;
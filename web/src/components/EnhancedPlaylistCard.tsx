@@ -29,7 +29,7 @@ import {
 } from "@dnd-kit/sortable";
 import { restrictToHorizontalAxis } from "@dnd-kit/modifiers";
 import { usePlaylistTabs } from "../api/playlists";
-import { TabCard } from "./TabCard";
+import { TabCard, type PlaylistEntry } from "./TabCard";
 import type { components } from "../api/schema.gen";
 import { useStatus } from "../hooks/useStatus";
 import { useActivatePlaylist } from "../hooks/useActivatePlaylist";
@@ -78,7 +78,8 @@ export const EnhancedPlaylistCard: FC<EnhancedPlaylistCardProps> = ({ playlist }
   const [editingTab, setEditingTab] = useState<TabInfo | null>(null);
 
   const { data: tabs = [], isLoading: tabsLoading } = usePlaylistTabs(playlist.id);
-  const typedTabs = tabs as TabInfo[];
+  // Tabs of a playlist always carry their entry id, which the entry endpoints expect
+  const typedTabs = (tabs as TabInfo[]).filter((tab): tab is PlaylistEntry => tab.entry_id != null);
 
   const updatePlaylistMutation = useUpdatePlaylist({
     onSuccess: () => setEditDialogOpen(false)
@@ -97,14 +98,14 @@ export const EnhancedPlaylistCard: FC<EnhancedPlaylistCardProps> = ({ playlist }
     const { active, over } = event;
 
     if (active.id !== over.id) {
-      const oldIndex = typedTabs.findIndex((tab) => tab.id === active.id);
-      const newIndex = typedTabs.findIndex((tab) => tab.id === over.id);
+      const oldIndex = typedTabs.findIndex((tab) => tab.entry_id === active.id);
+      const newIndex = typedTabs.findIndex((tab) => tab.entry_id === over.id);
 
       const newTabs = arrayMove(typedTabs, oldIndex, newIndex);
 
       const reorderData = {
         tab_orders: newTabs.map((tab, index) => ({
-          tab_id: tab.id,
+          entry_id: tab.entry_id,
           order_index: index,
         })),
       };
@@ -208,10 +209,10 @@ export const EnhancedPlaylistCard: FC<EnhancedPlaylistCardProps> = ({ playlist }
                 onDragEnd={handleDragEnd}
                 modifiers={[restrictToHorizontalAxis]}
               >
-                <SortableContext items={typedTabs.map((t) => t.id)} strategy={horizontalListSortingStrategy}>
+                <SortableContext items={typedTabs.map((t) => t.entry_id)} strategy={horizontalListSortingStrategy}>
                   <div className="flex gap-3 overflow-x-auto pb-2">
                     {typedTabs.map((tab) => (
-                      <div key={tab.id} className="flex-shrink-0 w-80">
+                      <div key={tab.entry_id} className="flex-shrink-0 w-80">
                         <TabCard
                           tab={tab}
                           playlistId={playlist.id}
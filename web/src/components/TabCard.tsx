@@ -20,8 +20,11 @@ import { useQueryClient } from "@tanstack/react-query";
 
 type TabInfo = components["schemas"]["TabInfo"];
 
+/** A tab as listed in a playlist, which always carries its entry id */
+export type PlaylistEntry = TabInfo & { entry_id: string };
+
 interface TabCardProps {
-  tab: TabInfo;
+  tab: PlaylistEntry;
   playlistId: string;
   enabled?: boolean;
   onEdit?: (tab: TabInfo) => void;
@@ -51,7 +54,7 @@ export const TabCard: FC<TabCardProps> = ({ tab, playlistId, enabled = true, onE
     transform,
     transition,
     isDragging,
-  } = useSortable({ id: tab.id });
+  } = useSortable({ id: tab.entry_id });
 
   const style = {
     transform: CSS.Transform.toString(transform),
@@ -178,7 +181,7 @@ export const TabCard: FC<TabCardProps> = ({ tab, playlistId, enabled = true, onE
               <div className="flex items-center gap-2">
                 <Switch.Root
                   checked={enabled}
-                  onCheckedChange={(checked) => toggleEnabledMutation.mutate({ playlistId, tabId: tab.id, enabled: checked })}
+                  onCheckedChange={(checked) => toggleEnabledMutation.mutate({ playlistId, entryId: tab.entry_id, enabled: checked })}
                   disabled={toggleEnabledMutation.isPending}
                   className="w-11 h-6 bg-gray-600 rounded-full relative data-[state=checked]:bg-green-600 outline-none cursor-default"
                 >
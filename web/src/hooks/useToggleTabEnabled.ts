@@ -5,11 +5,11 @@ export const useToggleTabEnabled = () => {
   const qc = useQueryClient();
   
   return useMutation({
-    mutationFn: async ({ playlistId, tabId, enabled }: { playlistId: string; tabId: string; enabled: boolean }) => {
-      return apiRequest("/playlists/{playlist_id}/tabs/{tab_id}/toggle", "put", {
+    mutationFn: async ({ playlistId, entryId, enabled }: { playlistId: string; entryId: string; enabled: boolean }) => {
+      return apiRequest("/playlists/{playlist_id}/entries/{entry_id}/toggle", "put", {
         path: {
           playlist_id: playlistId,
-          tab_id: tabId,
+          entry_id: entryId,
         },
         contentType: "application/json; charset=utf-8",
         data: { enabled },